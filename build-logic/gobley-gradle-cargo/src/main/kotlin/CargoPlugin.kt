@@ -65,6 +65,7 @@ import org.jetbrains.kotlin.gradle.plugin.KotlinTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinAndroidTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinNativeTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinWithJavaTarget
+import org.jetbrains.kotlin.gradle.targets.js.KotlinWasmTargetType
 import org.jetbrains.kotlin.gradle.targets.js.ir.KotlinJsIrTarget
 import org.jetbrains.kotlin.gradle.targets.jvm.KotlinJvmTarget
 
@@ -194,7 +195,7 @@ class CargoPlugin : Plugin<Project> {
                 listOf(RustTarget((this as KotlinNativeTarget).konanTarget))
             }
 
-            KotlinPlatformType.js -> {
+            KotlinPlatformType.js, KotlinPlatformType.wasm -> {
                 RustWasmTarget.values().toList()
             }
 
@@ -204,10 +205,12 @@ class CargoPlugin : Plugin<Project> {
 
     @OptIn(InternalGobleyGradleApi::class)
     private fun Project.checkKotlinTargets() {
-        val hasWasmTargets =
-            kotlinExtensionDelegate.targets.any { it.platformType == KotlinPlatformType.wasm }
-        if (hasWasmTargets) {
-            project.logger.warn("WASM targets are added, but Gobley does not support WASM targets yet.")
+        val hasWasmWasiTargets = kotlinExtensionDelegate.targets.any {
+            it.platformType == KotlinPlatformType.wasm
+                    && (it as? KotlinJsIrTarget)?.wasmTargetType != KotlinWasmTargetType.JS
+        }
+        if (hasWasmWasiTargets) {
+            project.logger.warn("WASI targets are added, but Gobley does not support WASI targets yet.")
         }
 
         val hasAndroidJvmTargets =
@@ -355,7 +358,7 @@ class CargoPlugin : Plugin<Project> {
                         )
                     }
 
-                    KotlinPlatformType.js -> {
+                    KotlinPlatformType.js, KotlinPlatformType.wasm -> {
                         cargoBuild as CargoWasmBuild
                         configureWasmCompilation(
                             kotlinTarget as KotlinJsIrTarget,
@@ -594,7 +597,11 @@ class CargoPlugin : Plugin<Project> {
         val buildTask = cargoBuildVariant.buildTaskProvider
         val checkTask = cargoBuildVariant.checkTaskProvider
 
-        cargoBuildVariant.transformWasmProvider.configure {
+        val transformWasmProvider = when (kotlinTarget.platformType) {
+            KotlinPlatformType.wasm -> cargoBuildVariant.transformWasmJsProvider
+            else -> cargoBuildVariant.transformWasmProvider
+        }
+        transformWasmProvider.configure {
             wasmTransformer.set(wasmBindgenInstallTask.get().wasmTransformer)
         }
 
@@ -603,9 +610,10 @@ class CargoPlugin : Plugin<Project> {
 
         @OptIn(InternalGobleyGradleApi::class)
         kotlinExtensionDelegate.sourceSets.run {
-            jsMain.kotlin.srcDir(
-                cargoBuildVariant.transformWasmProvider.flatMap { it.outputDirectory }
-            )
+            when (kotlinTarget.platformType) {
+                KotlinPlatformType.wasm -> wasmJsMain
+                else -> jsMain
+            }.kotlin.srcDir(transformWasmProvider.flatMap { it.outputDirectory })
         }
 
         kotlinTarget.compilations.getByName("main") {
@@ -636,7 +644,7 @@ private fun KotlinPlatformType.requiredCrateType(): CrateType? = when (this) {
     KotlinPlatformType.js -> CrateType.SystemDynamicLibrary
     KotlinPlatformType.androidJvm -> CrateType.SystemDynamicLibrary
     KotlinPlatformType.native -> CrateType.SystemStaticLibrary
-    KotlinPlatformType.wasm -> CrateType.SystemStaticLibrary
+    KotlinPlatformType.wasm -> CrateType.SystemDynamicLibrary
 }
 
 private fun ProjectLayout.outputCacheFile(task: Task, propertyName: String): Provider<RegularFile> {
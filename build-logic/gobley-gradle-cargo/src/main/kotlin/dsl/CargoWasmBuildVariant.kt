@@ -26,7 +26,30 @@ abstract class CargoWasmBuildVariant @Inject constructor(
         embedRustLibrary.convention(build.embedRustLibrary)
     }
 
-    val transformWasmProvider = project.tasks.register<TransformWasmTask>({
+    val transformWasmProvider = registerTransformWasmTask(
+        project,
+        extension,
+        taskNameInfix = null,
+        kotlinTarget = "js",
+        outputDirectoryName = "cargo-wasm-transformation",
+    )
+
+    val transformWasmJsProvider = registerTransformWasmTask(
+        project,
+        extension,
+        taskNameInfix = "wasmJs",
+        kotlinTarget = "wasm-js",
+        outputDirectoryName = "cargo-wasm-js-transformation",
+    )
+
+    private fun registerTransformWasmTask(
+        project: Project,
+        extension: CargoExtension,
+        taskNameInfix: String?,
+        kotlinTarget: String,
+        outputDirectoryName: String,
+    ) = project.tasks.register<TransformWasmTask>({
+        taskNameInfix?.let { +it }
         +this@CargoWasmBuildVariant
     }) {
         input.convention(buildTaskProvider.flatMap { task ->
@@ -34,7 +57,7 @@ abstract class CargoWasmBuildVariant @Inject constructor(
         })
         outputDirectory.convention(
             projectLayout.buildDirectory
-                .dir("generated/cargo-wasm-transformation")
+                .dir("generated/$outputDirectoryName")
                 .zip(profile) { dir, profile ->
                     dir
                         .dir(rustTarget.rustTriple)
@@ -46,6 +69,7 @@ abstract class CargoWasmBuildVariant @Inject constructor(
                 pkg.libraryCrateName
             }
         )
+        this.kotlinTarget.set(kotlinTarget)
         dependsOn(buildTaskProvider)
     }
 }
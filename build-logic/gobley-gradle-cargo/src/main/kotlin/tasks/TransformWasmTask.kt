@@ -42,6 +42,13 @@ abstract class TransformWasmTask : CommandTask() {
     @get:Optional
     abstract val functionImportsFile: RegularFileProperty
 
+    /**
+     * The Kotlin target the generated .kt file is compiled for. Either `js` or `wasm-js`.
+     */
+    @get:Input
+    @get:Optional
+    abstract val kotlinTarget: Property<String>
+
     @TaskAction
     fun transformWasm() {
         @OptIn(InternalGobleyGradleApi::class)
@@ -63,6 +70,9 @@ abstract class TransformWasmTask : CommandTask() {
             if (functionImportsFile.isPresent) {
                 arguments("--function-imports-file", functionImportsFile.get())
             }
+            if (kotlinTarget.isPresent) {
+                arguments("--kotlin-target", kotlinTarget.get())
+            }
         }.get().apply {
             assertNormalExitValueUsingLogger()
         }
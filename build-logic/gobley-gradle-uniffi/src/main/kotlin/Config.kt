@@ -18,6 +18,9 @@ import java.io.File
  * explicitly set by users are generated when merged by the plugin.
  *
  * Properties start with `__gradle_` should be set and read by the Gradle plugins only.
+ *
+ * Unknown keys are ignored when decoding, but `MergeUniffiConfigTask` copies them from the original
+ * file to the merged one, so the bindgen can still report the misspelled ones.
  */
 @Serializable
 internal data class Config(
@@ -49,10 +52,47 @@ internal data class Config(
         @SerialName("jvm_dynamic_library_dependencies") val jvmDynamicLibraryDependencies: List<String>? = null,
         @SerialName("android_dynamic_library_dependencies") val androidDynamicLibraryDependencies: List<String>? = null,
         @SerialName("dynamic_library_dependencies") val dynamicLibraryDependencies: List<String>? = null,
+        @SerialName("jvm_backend") val jvmBackend: String? = null,
+        @SerialName("runtime_package") val runtimePackage: String? = null,
+        @SerialName("split_output") val splitOutput: Boolean? = null,
+        @SerialName("jvm_common_source_set") val jvmCommonSourceSet: String? = null,
+        @SerialName("visibility") val visibility: String? = null,
+        @SerialName("visibility_overrides") val visibilityOverrides: Map<String, String>? = null,
+        @SerialName("rename") val rename: Map<String, String>? = null,
+        @SerialName("async_iterators") val asyncIterators: List<String>? = null,
+        @SerialName("async_dispatcher") val asyncDispatcher: String? = null,
+        @SerialName("suspend_wrappers") val suspendWrappers: List<String>? = null,
+        @SerialName("blocking_dispatcher") val blockingDispatcher: String? = null,
+        @SerialName("java_interop") val javaInterop: Boolean? = null,
+        @SerialName("java_interop_futures") val javaInteropFutures: Boolean? = null,
+        @SerialName("abort_on_panic") val abortOnPanic: Boolean? = null,
+        @SerialName("panic_backtrace_function") val panicBacktraceFunction: String? = null,
+        @SerialName("debug_object_tracking") val debugObjectTracking: Boolean? = null,
+        @SerialName("generate_parcelable") val generateParcelable: Boolean? = null,
+        @SerialName("compose_stability") val composeStability: Boolean? = null,
+        @SerialName("compose_targets") val composeTargets: List<String>? = null,
+        @SerialName("compose_immutable_collections") val composeImmutableCollections: Boolean? = null,
+        @SerialName("include") val include: List<String>? = null,
+        @SerialName("exclude") val exclude: List<String>? = null,
     )
 
     @Serializable
     internal data class CustomType(
+        @SerialName("imports") val imports: List<String>? = null,
+        @SerialName("type_name") val typeName: String? = null,
+        @SerialName("into_custom") val intoCustom: String? = null,
+        @SerialName("lift") val lift: String? = null,
+        @SerialName("from_custom") val fromCustom: String? = null,
+        @SerialName("lower") val lower: String? = null,
+        @SerialName("value_class") val valueClass: Boolean? = null,
+        @SerialName("parcelable") val parcelable: Boolean? = null,
+        @SerialName("jvm") val jvm: CustomTypePlatform? = null,
+        @SerialName("android") val android: CustomTypePlatform? = null,
+        @SerialName("native") val native: CustomTypePlatform? = null,
+    )
+
+    @Serializable
+    internal data class CustomTypePlatform(
         @SerialName("imports") val imports: List<String>? = null,
         @SerialName("type_name") val typeName: String? = null,
         @SerialName("lift") val lift: String? = null,
@@ -11,9 +11,12 @@ import gobley.gradle.InternalGobleyGradleApi
 import gobley.gradle.PluginIds
 import gobley.gradle.Variant
 import gobley.gradle.android.GobleyAndroidExtensionDelegate
+import gobley.gradle.cargo.dsl.CargoAndroidBuildVariant
 import gobley.gradle.cargo.dsl.CargoExtension
 import gobley.gradle.cargo.dsl.CargoJvmBuild
+import gobley.gradle.cargo.dsl.CargoJvmBuildVariant
 import gobley.gradle.cargo.dsl.CargoNativeBuild
+import gobley.gradle.cargo.dsl.HasDynamicLibraries
 import gobley.gradle.kotlin.GobleyKotlinExtensionDelegate
 import gobley.gradle.rust.CrateType
 import gobley.gradle.rust.targets.RustTarget
@@ -23,6 +26,7 @@ import gobley.gradle.uniffi.dsl.BindingsGenerationFromLibrary
 import gobley.gradle.uniffi.dsl.BindingsGenerationFromUdl
 import gobley.gradle.uniffi.dsl.UniFfiExtension
 import gobley.gradle.uniffi.tasks.BuildUniffiBindingsTask
+import gobley.gradle.uniffi.tasks.BuildUniffiJniShimTask
 import gobley.gradle.uniffi.tasks.GenerateUniffiProguardRulesTask
 import gobley.gradle.uniffi.tasks.InstallUniffiBindgenTask
 import gobley.gradle.uniffi.tasks.MergeUniffiConfigTask
@@ -44,6 +48,7 @@ import org.gradle.kotlin.dsl.create
 import org.gradle.kotlin.dsl.getByType
 import org.gradle.kotlin.dsl.named
 import org.gradle.kotlin.dsl.register
+import org.gradle.kotlin.dsl.support.uppercaseFirstChar
 import org.gradle.kotlin.dsl.withType
 import org.jetbrains.kotlin.gradle.plugin.KotlinPlatformType
 import org.jetbrains.kotlin.gradle.plugin.KotlinSourceSet
@@ -52,6 +57,8 @@ import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinAndroidTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinMetadataTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinNativeTarget
 import org.jetbrains.kotlin.gradle.plugin.mpp.KotlinWithJavaTarget
+import org.jetbrains.kotlin.gradle.targets.js.KotlinWasmTargetType
+import org.jetbrains.kotlin.gradle.targets.js.ir.KotlinJsIrTarget
 import org.jetbrains.kotlin.gradle.targets.jvm.KotlinJvmTarget
 import org.jetbrains.kotlin.gradle.tasks.CInteropProcess
 import org.jetbrains.kotlin.gradle.tasks.KotlinCompilationTask
@@ -137,16 +144,11 @@ class UniFfiPlugin : Plugin<Project> {
 
     @OptIn(InternalGobleyGradleApi::class)
     private fun Project.checkKotlinTargets() {
-        val hasJsTargets =
-            kotlinExtensionDelegate.targets.any { it.platformType == KotlinPlatformType.js }
-        if (hasJsTargets) {
-            project.logger.warn("JS targets are added, but the UniFFI plugin does not support JS targets yet.")
+        val hasWasmWasiTargets = kotlinExtensionDelegate.targets.any {
+            it.platformType == KotlinPlatformType.wasm && !it.isWasmJsTarget
         }
-
-        val hasWasmTargets =
-            kotlinExtensionDelegate.targets.any { it.platformType == KotlinPlatformType.wasm }
-        if (hasWasmTargets) {
-            project.logger.warn("WASM targets are added, but the UniFFI plugin does not support WASM targets yet.")
+        if (hasWasmWasiTargets) {
+            project.logger.warn("WASI targets are added, but the UniFFI plugin does not support WASI targets yet.")
         }
     }
 
@@ -244,6 +246,28 @@ class UniFfiPlugin : Plugin<Project> {
             customTypes.set(bindingsGeneration.customTypes)
             disableJavaCleaner.set(bindingsGeneration.disableJavaCleaner)
             usePascalCaseEnumClass.set(bindingsGeneration.usePascalCaseEnumClass)
+            jvmBackend.set(bindingsGeneration.jvmBackend)
+            runtimePackage.set(bindingsGeneration.runtimePackage)
+            splitOutput.set(bindingsGeneration.splitOutput)
+            jvmCommonSourceSet.set(bindingsGeneration.jvmCommonSourceSet)
+            visibility.set(bindingsGeneration.visibility)
+            visibilityOverrides.set(bindingsGeneration.visibilityOverrides)
+            rename.set(bindingsGeneration.rename)
+            asyncIterators.set(bindingsGeneration.asyncIterators)
+            asyncDispatcher.set(bindingsGeneration.asyncDispatcher)
+            suspendWrappers.set(bindingsGeneration.suspendWrappers)
+            blockingDispatcher.set(bindingsGeneration.blockingDispatcher)
+            javaInterop.set(bindingsGeneration.javaInterop)
+            javaInteropFutures.set(bindingsGeneration.javaInteropFutures)
+            abortOnPanic.set(bindingsGeneration.abortOnPanic)
+            panicBacktraceFunction.set(bindingsGeneration.panicBacktraceFunction)
+            debugObjectTracking.set(bindingsGeneration.debugObjectTracking)
+            generateParcelable.set(bindingsGeneration.generateParcelable)
+            composeStability.set(bindingsGeneration.composeStability)
+            composeTargets.set(bindingsGeneration.composeTargets)
+            composeImmutableCollections.set(bindingsGeneration.composeImmutableCollections)
+            include.set(bindingsGeneration.include)
+            exclude.set(bindingsGeneration.exclude)
 
             @OptIn(InternalGobleyGradleApi::class)
             kotlinMultiplatform.set(kotlinExtensionDelegate.pluginId == PluginIds.KOTLIN_MULTIPLATFORM)
@@ -256,7 +280,11 @@ class UniFfiPlugin : Plugin<Project> {
                         is KotlinJvmTarget, is KotlinWithJavaTarget<*, *> -> "jvm"
                         is KotlinAndroidTarget -> "android"
                         is KotlinNativeTarget -> "native"
-                        else -> "stub"
+                        else -> when {
+                            it.platformType == KotlinPlatformType.js -> "js"
+                            it.isWasmJsTarget -> "wasmJs"
+                            else -> "stub"
+                        }
                     }
                 }
             )
@@ -337,6 +365,10 @@ class UniFfiPlugin : Plugin<Project> {
             dependsOn(buildBindings)
         }
 
+        if (bindingsGeneration.jvmBackend.orNull == "jni") {
+            configureJniShimTasks(buildBindings)
+        }
+
         @OptIn(InternalGobleyGradleApi::class)
         if (::androidDelegate.isInitialized) {
             val generateUniffiProguardRulesTask =
@@ -354,6 +386,49 @@ class UniFfiPlugin : Plugin<Project> {
         }
     }
 
+    // Builds the JNI shim crate for each JVM and Android build, and bundles the shim library
+    // together with the Rust library by adding it to the dynamic libraries of the build.
+    private fun Project.configureJniShimTasks(buildBindings: TaskProvider<BuildUniffiBindingsTask>) {
+        val namespace = bindingsGeneration.namespace.get()
+        val shimName = "uniffi_${namespace}_jni"
+        for (cargoBuild in cargoExtension.builds) {
+            for (cargoBuildVariant in cargoBuild.variants) {
+                val findDynamicLibrariesTask = when (cargoBuildVariant) {
+                    is CargoJvmBuildVariant<*> -> cargoBuildVariant.findDynamicLibrariesTaskProvider
+                    is CargoAndroidBuildVariant -> cargoBuildVariant.findDynamicLibrariesTaskProvider
+                    else -> continue
+                }
+                cargoBuildVariant as HasDynamicLibraries
+                if (!cargoBuildVariant.embedRustLibrary.get()) continue
+                val buildTask = cargoBuildVariant.buildTaskProvider
+                val rustTarget = cargoBuildVariant.rustTarget
+                val buildJniShim = tasks.register<BuildUniffiJniShimTask>(
+                    "buildUniffiJniShim${rustTarget.friendlyName.uppercaseFirstChar()}${cargoBuildVariant.variant}"
+                ) {
+                    group = TASK_GROUP
+                    shimDirectory.set(bindingsDirectory.map { it.dir("jniShim/$namespace") })
+                    this.shimName.set(shimName)
+                    rustLibraryFile.set(buildTask.flatMap { task ->
+                        task.libraryFileByCrateType.map { it[CrateType.SystemDynamicLibrary]!! }
+                    })
+                    profile.set(cargoBuildVariant.profile)
+                    target.set(rustTarget)
+                    targetDirectory.set(layout.buildDirectory.dir("intermediates/uniffi/jniShim"))
+                    // Use the same linker environment as the Rust library, e.g., the Android NDK.
+                    additionalEnvironment.putAll(buildTask.flatMap { it.additionalEnvironment })
+                    dependsOn(buildBindings, buildTask)
+                }
+                cargoBuildVariant.dynamicLibrarySearchPaths.add(buildJniShim.flatMap { task ->
+                    task.libraryFile.map { it.asFile.parentFile }
+                })
+                cargoBuildVariant.dynamicLibraries.add(shimName)
+                findDynamicLibrariesTask.configure {
+                    dependsOn(buildJniShim)
+                }
+            }
+        }
+    }
+
     private fun Project.configureCleanTasks() {
         val cleanBindings = tasks.register<Delete>("cleanBindings") {
             group = TASK_GROUP
@@ -407,7 +482,11 @@ class UniFfiPlugin : Plugin<Project> {
                     generateDummyDefFileTask,
                 )
 
-                else -> configureUnsupportedTarget(this)
+                else -> when {
+                    platformType == KotlinPlatformType.js -> configureKotlinJsTarget(this)
+                    isWasmJsTarget -> configureKotlinWasmJsTarget(this)
+                    else -> configureUnsupportedTarget(this)
+                }
             }
         }
     }
@@ -436,9 +515,22 @@ class UniFfiPlugin : Plugin<Project> {
                     implementation("org.jetbrains.kotlinx:kotlinx-coroutines-core") {
                         version { prefer(DependencyVersions.KOTLINX_COROUTINES) }
                     }
+                    if (bindingsGeneration.composeImmutableCollections.getOrElse(false)) {
+                        implementation("org.jetbrains.kotlinx:kotlinx-collections-immutable") {
+                            version { prefer(DependencyVersions.KOTLINX_COLLECTIONS_IMMUTABLE) }
+                        }
+                    }
                 }
             }
         }
+        val jvmCommonSourceSet = bindingsGeneration.jvmCommonSourceSet.orNull
+        if (jvmCommonSourceSet != null && kotlinExtensionDelegate.pluginId == PluginIds.KOTLIN_MULTIPLATFORM) {
+            kotlinExtensionDelegate.sourceSets
+                .matching { it.name == "${jvmCommonSourceSet}Main" }
+                .configureEach {
+                    kotlin.srcDir(bindingsDirectory.map { it.dir("${jvmCommonSourceSet}Main/kotlin") })
+                }
+        }
     }
 
     @OptIn(InternalGobleyGradleApi::class)
@@ -471,6 +563,14 @@ class UniFfiPlugin : Plugin<Project> {
                     implementation("androidx.annotation:annotation") {
                         version { prefer(DependencyVersions.KOTLINX_COROUTINES) }
                     }
+                    val composeTargets = bindingsGeneration.composeTargets.get()
+                    if (bindingsGeneration.composeStability.getOrElse(false)
+                        && (composeTargets.isEmpty() || "android" in composeTargets)
+                    ) {
+                        implementation("androidx.compose.runtime:runtime") {
+                            version { prefer(DependencyVersions.COMPOSE_RUNTIME) }
+                        }
+                    }
                 }
             }
         }
@@ -527,6 +627,18 @@ class UniFfiPlugin : Plugin<Project> {
         }
     }
 
+    private fun Project.configureKotlinJsTarget(kotlinTarget: KotlinTarget) {
+        kotlinTarget.compilations.getByName("main").defaultSourceSet {
+            kotlin.srcDir(jsBindingsDirectory)
+        }
+    }
+
+    private fun Project.configureKotlinWasmJsTarget(kotlinTarget: KotlinTarget) {
+        kotlinTarget.compilations.getByName("main").defaultSourceSet {
+            kotlin.srcDir(wasmJsBindingsDirectory)
+        }
+    }
+
     private fun Project.configureUnsupportedTarget(kotlinTarget: KotlinTarget) {
         kotlinTarget.compilations.getByName("main").defaultSourceSet {
             kotlin.srcDir(stubBindingsDirectory)
@@ -534,6 +646,10 @@ class UniFfiPlugin : Plugin<Project> {
     }
 }
 
+private val KotlinTarget.isWasmJsTarget: Boolean
+    get() = platformType == KotlinPlatformType.wasm
+            && (this as? KotlinJsIrTarget)?.wasmTargetType == KotlinWasmTargetType.JS
+
 private val Project.bindingsDirectory: Provider<Directory>
     get() = layout.buildDirectory.dir("generated/uniffi")
 
@@ -552,6 +668,12 @@ private val Project.androidBindingsDirectory: Provider<Directory>
 private val Project.nativeBindingsDirectory: Provider<Directory>
     get() = bindingsDirectory.map { it.dir("nativeMain/kotlin") }
 
+private val Project.jsBindingsDirectory: Provider<Directory>
+    get() = bindingsDirectory.map { it.dir("jsMain/kotlin") }
+
+private val Project.wasmJsBindingsDirectory: Provider<Directory>
+    get() = bindingsDirectory.map { it.dir("wasmJsMain/kotlin") }
+
 private val Project.stubBindingsDirectory: Provider<Directory>
     get() = bindingsDirectory.map { it.dir("stubMain/kotlin") }
 
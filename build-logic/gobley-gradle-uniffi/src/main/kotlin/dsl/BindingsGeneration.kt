@@ -11,6 +11,7 @@ import gobley.gradle.rust.targets.RustTarget
 import org.gradle.api.Action
 import org.gradle.api.Project
 import org.gradle.api.file.RegularFileProperty
+import org.gradle.api.provider.ListProperty
 import org.gradle.api.provider.MapProperty
 import org.gradle.api.provider.Property
 import org.gradle.kotlin.dsl.invoke
@@ -90,6 +91,160 @@ sealed class BindingsGeneration(internal val project: Project) {
      * When `true`, enum classes will use PascalCase instead of UPPER_SNAKE_CASE.
      */
     abstract val usePascalCaseEnumClass: Property<Boolean>
+
+    /**
+     * How the Kotlin/JVM and Android bindings call into Rust. One of `"jna"`, `"jni"`, or `"ffm"`.
+     * Defaults to `"jna"`. When `"jni"`, the bindgen also generates a JNI shim crate, which the
+     * plugin builds and bundles together with the Rust library for each JVM and Android target.
+     * `"jni"` is experimental and can't be used by components with callback interfaces, foreign
+     * traits, or async functions. `"ffm"`
+     * requires Java 22 and only applies to Kotlin/JVM; Android bindings keep using JNA.
+     */
+    abstract val jvmBackend: Property<String>
+
+    /**
+     * The package to generate the helpers shared across components in, such as `ByteBuffer` and
+     * `UniffiHandleMap`. When unset, each component gets its own copy of the helpers.
+     */
+    abstract val runtimePackage: Property<String>
+
+    /**
+     * When `true`, each record, enum, error, object, and callback interface is written into its own
+     * file. Defaults to `false`. Consider enabling this option for large crates to speed up
+     * incremental compilation.
+     */
+    abstract val splitOutput: Property<Boolean>
+
+    /**
+     * The name of the source set shared by Kotlin/JVM and Android without the `Main` suffix, e.g.,
+     * `"jvmCommon"`. When set, the code shared by the two platforms is generated in that source set
+     * instead of being duplicated in `jvmMain` and `androidMain`. The source set must already exist.
+     */
+    abstract val jvmCommonSourceSet: Property<String>
+
+    /**
+     * The visibility of the generated declarations. One of `"public"` or `"internal"`. Defaults to
+     * `"public"`.
+     */
+    abstract val visibility: Property<String>
+
+    /**
+     * The visibility of individual types and top-level functions, keyed by their names in Rust.
+     * Takes precedence over [visibility].
+     */
+    abstract val visibilityOverrides: MapProperty<String, String>
+
+    /**
+     * The Kotlin names of types, functions, methods, fields, and enum variants, keyed by their
+     * paths in Rust, e.g., `"MyRecord"`, `"MyObject.do_thing"`, or `"MyEnum::Variant"`.
+     */
+    abstract val rename: MapProperty<String, String>
+
+    /**
+     * Glob patterns of the objects to generate an `asFlow()` extension for, e.g., `"*Stream"`. The
+     * extension calls the `next` method, or the method after `.` in the pattern, until it returns
+     * `null`. The method must be async, take no arguments, and return an `Option`.
+     */
+    abstract val asyncIterators: ListProperty<String>
+
+    /**
+     * The coroutine dispatcher in which the async calls into Rust are polled. One of `"none"`, which
+     * polls in the caller's context, `"io"`, `"default"`, or a fully qualified Kotlin expression
+     * evaluating to a `CoroutineContext`. Defaults to `"io"`. Can be overridden at runtime with
+     * `UniffiAsyncDispatcher.context`.
+     */
+    abstract val asyncDispatcher: Property<String>
+
+    /**
+     * Glob patterns of the synchronous top-level functions and methods to generate a `suspend`
+     * variant for, e.g., `"hash_file"`, `"Database.*"`, or `"*"`. The variant has the `Async` suffix
+     * and calls the function in [blockingDispatcher].
+     */
+    abstract val suspendWrappers: ListProperty<String>
+
+    /**
+     * The coroutine dispatcher in which the suspend variants generated with [suspendWrappers] call
+     * into Rust. One of `"none"`, `"io"`, `"default"`, or a fully qualified Kotlin expression
+     * evaluating to a `CoroutineContext`. Defaults to `"io"`, which falls back to `"default"` on
+     * Kotlin/JS and Kotlin/Wasm. Can be overridden at runtime with
+     * `UniffiAsyncDispatcher.blockingContext`.
+     */
+    abstract val blockingDispatcher: Property<String>
+
+    /**
+     * When `true`, the Kotlin/JVM and Android bindings get APIs usable from Java: a
+     * `CompletableFuture` variant of each async function and method, `@JvmStatic` alternate
+     * constructors, `@JvmOverloads` for the arguments with default values, and builder classes for
+     * the records with fields with default values. Defaults to `false`.
+     */
+    abstract val javaInterop: Property<Boolean>
+
+    /**
+     * Whether to generate the `CompletableFuture` variants of async functions when [javaInterop] is
+     * `true`. When unset, only the Kotlin/JVM bindings get them, since `CompletableFuture` requires
+     * Android API level 24.
+     */
+    abstract val javaInteropFutures: Property<Boolean>
+
+    /**
+     * When `true`, the process is aborted when the Rust code panics, instead of throwing
+     * `RustPanicException`. Defaults to `false`. Kotlin/JS and Kotlin/Wasm always throw.
+     */
+    abstract val abortOnPanic: Property<Boolean>
+
+    /**
+     * The Rust function returning the backtrace of the last panic as `Option<String>`, which sets
+     * `RustPanicException.rustBacktrace`. The function must take no arguments.
+     */
+    abstract val panicBacktraceFunction: Property<String>
+
+    /**
+     * When `true`, the objects backed by Rust record their creation stack traces, and
+     * `UniffiLeakReport.dump()` lists the ones garbage collected without being closed or still
+     * alive. Defaults to `false`. Intended for debug builds, since it slows down object creation.
+     */
+    abstract val debugObjectTracking: Property<Boolean>
+
+    /**
+     * When `true`, the records and the enums consisting only of types supported by Parcelize
+     * implement `android.os.Parcelable` on Android. Requires the `org.jetbrains.kotlin.plugin.parcelize`
+     * plugin. Defaults to `false`.
+     */
+    abstract val generateParcelable: Property<Boolean>
+
+    /**
+     * When `true`, records are annotated with `@Immutable` when [generateImmutableRecords] is
+     * `true`, and enums with `@Stable`, so Jetpack Compose can skip recompositions. The annotations
+     * are the ones of Compose only on [composeTargets]. Defaults to `false`.
+     */
+    abstract val composeStability: Property<Boolean>
+
+    /**
+     * The targets compiled with the Compose runtime, e.g., `"android"` or `"jvm"`, on which
+     * [composeStability] uses the Compose annotations. Defaults to `["android"]`. The Compose
+     * runtime is added to Android automatically, but the other targets need the Compose
+     * Multiplatform runtime.
+     */
+    abstract val composeTargets: ListProperty<String>
+
+    /**
+     * When `true`, the `List` and `Map` fields of records use `ImmutableList` and `ImmutableMap`
+     * of `kotlinx.collections.immutable`. Defaults to `false`.
+     */
+    abstract val composeImmutableCollections: Property<Boolean>
+
+    /**
+     * Glob patterns of the top-level functions and types to generate, e.g., `"Session*"`. When
+     * empty, everything not matching [exclude] is generated. The types used by the generated items
+     * are always generated.
+     */
+    abstract val include: ListProperty<String>
+
+    /**
+     * Glob patterns of the top-level functions, types, and methods not to generate, e.g.,
+     * `"debug_*"` or `"MyObject.internal_*"`.
+     */
+    abstract val exclude: ListProperty<String>
 }
 
 abstract class BindingsGenerationFromLibrary @Inject internal constructor(project: Project) :
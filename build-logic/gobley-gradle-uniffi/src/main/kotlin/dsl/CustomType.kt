@@ -6,8 +6,10 @@
 
 package gobley.gradle.uniffi.dsl
 
+import org.gradle.api.Action
 import org.gradle.api.provider.ListProperty
 import org.gradle.api.provider.Property
+import org.gradle.api.tasks.Nested
 import javax.inject.Inject
 
 abstract class CustomType @Inject constructor() {
@@ -33,4 +35,74 @@ abstract class CustomType @Inject constructor() {
      * underlying type. The custom type expression will be assigned to the placeholder, `{}`.
      */
     abstract val lower: Property<String>
+
+    /**
+     * When `true`, the custom type is generated as an inline value class wrapping the underlying
+     * type, with the converters generated as well. Cannot be used with [typeName], [lift], or
+     * [lower].
+     */
+    abstract val valueClass: Property<Boolean>
+
+    /**
+     * When `true`, the Kotlin type of the custom type is treated as parcelable, so records and enums
+     * containing it can implement `android.os.Parcelable` with `generateParcelable`.
+     */
+    abstract val parcelable: Property<Boolean>
+
+    /**
+     * Overrides for the Kotlin/JVM bindings.
+     */
+    @get:Nested
+    abstract val jvm: CustomTypePlatform
+
+    /**
+     * Overrides for the Android bindings.
+     */
+    @get:Nested
+    abstract val android: CustomTypePlatform
+
+    /**
+     * Overrides for the Kotlin/Native bindings.
+     */
+    @get:Nested
+    abstract val native: CustomTypePlatform
+
+    /**
+     * Overrides the custom type for Kotlin/JVM. When [CustomTypePlatform.typeName] or
+     * [CustomTypePlatform.imports] is set for any platform, the common bindings declare the type
+     * with `expect class`.
+     */
+    fun jvm(configure: Action<CustomTypePlatform>) = configure.execute(jvm)
+
+    /**
+     * Overrides the custom type for Android. See [jvm].
+     */
+    fun android(configure: Action<CustomTypePlatform>) = configure.execute(android)
+
+    /**
+     * Overrides the custom type for Kotlin/Native. See [jvm].
+     */
+    fun native(configure: Action<CustomTypePlatform>) = configure.execute(native)
+}
+
+abstract class CustomTypePlatform @Inject constructor() {
+    /**
+     * The list of import directives needed to implement this custom type on the platform.
+     */
+    abstract val imports: ListProperty<String>
+
+    /**
+     * The name of the type on the platform.
+     */
+    abstract val typeName: Property<String>
+
+    /**
+     * The expression that converts the underlying type to the custom type on the platform.
+     */
+    abstract val lift: Property<String>
+
+    /**
+     * The expression that converts the custom type to the underlying type on the platform.
+     */
+    abstract val lower: Property<String>
 }
\ No newline at end of file
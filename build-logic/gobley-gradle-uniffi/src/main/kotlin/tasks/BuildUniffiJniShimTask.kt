@@ -0,0 +1,91 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+package gobley.gradle.uniffi.tasks
+
+import gobley.gradle.InternalGobleyGradleApi
+import gobley.gradle.cargo.profiles.CargoProfile
+import gobley.gradle.cargo.tasks.CargoTask
+import gobley.gradle.rust.CrateType
+import gobley.gradle.rust.targets.RustTarget
+import org.gradle.api.file.DirectoryProperty
+import org.gradle.api.file.RegularFile
+import org.gradle.api.file.RegularFileProperty
+import org.gradle.api.provider.Property
+import org.gradle.api.provider.Provider
+import org.gradle.api.tasks.CacheableTask
+import org.gradle.api.tasks.Input
+import org.gradle.api.tasks.InputDirectory
+import org.gradle.api.tasks.InputFile
+import org.gradle.api.tasks.Internal
+import org.gradle.api.tasks.OutputFile
+import org.gradle.api.tasks.PathSensitive
+import org.gradle.api.tasks.PathSensitivity
+import org.gradle.api.tasks.TaskAction
+
+/**
+ * Builds the JNI shim crate generated by the bindgen when `jvmBackend` is `"jni"`. The shim
+ * exports the `Java_...` symbols and links to the Rust library, so it is built for the same target
+ * and profile as the Rust library.
+ */
+@Suppress("LeakingThis")
+@CacheableTask
+abstract class BuildUniffiJniShimTask : CargoTask() {
+    /**
+     * The directory of the shim crate, i.e., `<bindings directory>/jniShim/<namespace>`.
+     */
+    @get:InputDirectory
+    @get:PathSensitive(PathSensitivity.RELATIVE)
+    abstract val shimDirectory: DirectoryProperty
+
+    /**
+     * The library name of the shim crate, i.e., `uniffi_<namespace>_jni`.
+     */
+    @get:Input
+    abstract val shimName: Property<String>
+
+    /**
+     * The Rust library the shim links to.
+     */
+    @get:InputFile
+    @get:PathSensitive(PathSensitivity.ABSOLUTE)
+    abstract val rustLibraryFile: RegularFileProperty
+
+    @get:Input
+    abstract val profile: Property<CargoProfile>
+
+    @get:Input
+    abstract val target: Property<RustTarget>
+
+    /**
+     * The Cargo target directory to build the shim in. The shim is built in a separate target
+     * directory, since it does not belong to the workspace of the Rust library.
+     */
+    @get:Internal
+    abstract val targetDirectory: DirectoryProperty
+
+    @get:OutputFile
+    val libraryFile: Provider<RegularFile> =
+        targetDirectory.zip(profile.zip(target, ::Pair)) { targetDirectory, (profile, target) ->
+            targetDirectory
+                .dir(target.rustTriple)
+                .dir(profile.targetChildDirectoryName)
+                .file(target.outputFileName(shimName.get(), CrateType.SystemDynamicLibrary)!!)
+        }
+
+    @TaskAction
+    @OptIn(InternalGobleyGradleApi::class)
+    fun build() {
+        cargo("build") {
+            arguments("--manifest-path", shimDirectory.get().file("Cargo.toml").asFile)
+            arguments("--profile", profile.get().profileName)
+            arguments("--target", target.get().rustTriple)
+            arguments("--target-dir", targetDirectory.get().asFile)
+            workingDirectory(shimDirectory)
+            additionalEnvironment("UNIFFI_JNI_LIB_DIR", rustLibraryFile.get().asFile.parentFile)
+        }.get().assertNormalExitValue()
+    }
+}
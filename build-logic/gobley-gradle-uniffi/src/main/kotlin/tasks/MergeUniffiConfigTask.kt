@@ -8,8 +8,11 @@ package gobley.gradle.uniffi.tasks
 
 import gobley.gradle.uniffi.Config
 import gobley.gradle.uniffi.dsl.CustomType
+import gobley.gradle.uniffi.dsl.CustomTypePlatform
 import kotlinx.serialization.encodeToString
 import net.peanuuutz.tomlkt.Toml
+import net.peanuuutz.tomlkt.TomlElement
+import net.peanuuutz.tomlkt.TomlTable
 import org.gradle.api.DefaultTask
 import org.gradle.api.file.RegularFileProperty
 import org.gradle.api.provider.ListProperty
@@ -102,12 +105,101 @@ abstract class MergeUniffiConfigTask : DefaultTask() {
     @get:Optional
     abstract val dynamicLibraryDependencies: ListProperty<String>
 
+    @get:Input
+    @get:Optional
+    abstract val jvmBackend: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val runtimePackage: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val splitOutput: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val jvmCommonSourceSet: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val visibility: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val visibilityOverrides: MapProperty<String, String>
+
+    @get:Input
+    @get:Optional
+    abstract val rename: MapProperty<String, String>
+
+    @get:Input
+    @get:Optional
+    abstract val asyncIterators: ListProperty<String>
+
+    @get:Input
+    @get:Optional
+    abstract val asyncDispatcher: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val suspendWrappers: ListProperty<String>
+
+    @get:Input
+    @get:Optional
+    abstract val blockingDispatcher: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val javaInterop: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val javaInteropFutures: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val abortOnPanic: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val panicBacktraceFunction: Property<String>
+
+    @get:Input
+    @get:Optional
+    abstract val debugObjectTracking: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val generateParcelable: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val composeStability: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val composeTargets: ListProperty<String>
+
+    @get:Input
+    @get:Optional
+    abstract val composeImmutableCollections: Property<Boolean>
+
+    @get:Input
+    @get:Optional
+    abstract val include: ListProperty<String>
+
+    @get:Input
+    @get:Optional
+    abstract val exclude: ListProperty<String>
+
     @get:OutputFile
     abstract val outputConfig: RegularFileProperty
 
     @TaskAction
     fun mergeConfig() {
-        val originalConfig = originalConfig.orNull?.asFile?.let(::Config)
+        val originalConfigFile = originalConfig.orNull?.asFile
+        val originalConfig = originalConfigFile?.let(::Config)
         val originalKotlinConfig = originalConfig?.kotlinConfig
         val kotlinConfig = Config.KotlinConfig(
             packageName = originalKotlinConfig?.packageName ?: packageName.orNull,
@@ -131,6 +223,11 @@ abstract class MergeUniffiConfigTask : DefaultTask() {
                             typeName = entry.value.typeName.orNull,
                             lift = entry.value.lift.orNull,
                             lower = entry.value.lower.orNull,
+                            valueClass = entry.value.valueClass.orNull,
+                            parcelable = entry.value.parcelable.orNull,
+                            jvm = entry.value.jvm.toConfig(),
+                            android = entry.value.android.toConfig(),
+                            native = entry.value.native.toConfig(),
                         )
                     }
                 }.orNull,
@@ -159,6 +256,48 @@ abstract class MergeUniffiConfigTask : DefaultTask() {
                 originalKotlinConfig?.dynamicLibraryDependencies,
                 dynamicLibraryDependencies.orNull,
             ),
+            jvmBackend = originalKotlinConfig?.jvmBackend ?: jvmBackend.orNull,
+            runtimePackage = originalKotlinConfig?.runtimePackage ?: runtimePackage.orNull,
+            splitOutput = originalKotlinConfig?.splitOutput ?: splitOutput.orNull,
+            jvmCommonSourceSet = originalKotlinConfig?.jvmCommonSourceSet
+                ?: jvmCommonSourceSet.orNull,
+            visibility = originalKotlinConfig?.visibility ?: visibility.orNull,
+            visibilityOverrides = mergeMap(
+                originalKotlinConfig?.visibilityOverrides,
+                visibilityOverrides.orNull,
+            ),
+            rename = mergeMap(
+                originalKotlinConfig?.rename,
+                rename.orNull,
+            ),
+            asyncIterators = mergeSet(
+                originalKotlinConfig?.asyncIterators,
+                asyncIterators.orNull,
+            ),
+            asyncDispatcher = originalKotlinConfig?.asyncDispatcher ?: asyncDispatcher.orNull,
+            suspendWrappers = mergeSet(
+                originalKotlinConfig?.suspendWrappers,
+                suspendWrappers.orNull,
+            ),
+            blockingDispatcher = originalKotlinConfig?.blockingDispatcher
+                ?: blockingDispatcher.orNull,
+            javaInterop = originalKotlinConfig?.javaInterop ?: javaInterop.orNull,
+            javaInteropFutures = originalKotlinConfig?.javaInteropFutures
+                ?: javaInteropFutures.orNull,
+            abortOnPanic = originalKotlinConfig?.abortOnPanic ?: abortOnPanic.orNull,
+            panicBacktraceFunction = originalKotlinConfig?.panicBacktraceFunction
+                ?: panicBacktraceFunction.orNull,
+            debugObjectTracking = originalKotlinConfig?.debugObjectTracking
+                ?: debugObjectTracking.orNull,
+            generateParcelable = originalKotlinConfig?.generateParcelable
+                ?: generateParcelable.orNull,
+            composeStability = originalKotlinConfig?.composeStability ?: composeStability.orNull,
+            composeTargets = originalKotlinConfig?.composeTargets
+                ?: composeTargets.orNull?.takeIf { it.isNotEmpty() },
+            composeImmutableCollections = originalKotlinConfig?.composeImmutableCollections
+                ?: composeImmutableCollections.orNull,
+            include = mergeSet(originalKotlinConfig?.include, include.orNull),
+            exclude = mergeSet(originalKotlinConfig?.exclude, exclude.orNull),
         )
         val result = (originalConfig ?: Config()).copy(
             // Properties read by the Gradle plugins
@@ -169,12 +308,39 @@ abstract class MergeUniffiConfigTask : DefaultTask() {
                 kotlinConfig = kotlinConfig,
             ),
         )
+        // Keep the keys Config doesn't know, such as the options of the other languages or
+        // misspelled options, so the bindgen sees and reports them.
+        val resultTable = toml.encodeToTomlElement(Config.serializer(), result) as TomlTable
+        val originalTable = originalConfigFile?.let {
+            toml.parseToTomlTable(it.readText(Charsets.UTF_8))
+        }
         outputConfig.get().asFile.writeText(
-            toml.encodeToString(result),
+            toml.encodeToString<TomlElement>(
+                originalTable?.let { keepUnknownKeys(resultTable, it) } ?: resultTable
+            ),
             Charsets.UTF_8,
         )
     }
 
+    /**
+     * Adds the keys of [original] that are missing in [merged], descending into the tables present
+     * in both.
+     */
+    private fun keepUnknownKeys(merged: TomlTable, original: TomlTable): TomlTable {
+        val result = merged.toMutableMap()
+        for ((key, originalValue) in original) {
+            val mergedValue = result[key]
+            result[key] = when {
+                mergedValue == null -> originalValue
+                mergedValue is TomlTable && originalValue is TomlTable -> {
+                    keepUnknownKeys(mergedValue, originalValue)
+                }
+                else -> mergedValue
+            }
+        }
+        return TomlTable(result)
+    }
+
     private fun retrieveExternalPackageNames(
         externalPackageConfigs: List<File>
     ): Map<String, String> {
@@ -216,6 +382,17 @@ abstract class MergeUniffiConfigTask : DefaultTask() {
         return newMap.toMap()
     }
 
+    private fun CustomTypePlatform.toConfig(): Config.CustomTypePlatform? {
+        val config = Config.CustomTypePlatform(
+            // An empty list would still make the bindgen declare the type with `expect class`.
+            imports = imports.orNull?.takeIf { it.isNotEmpty() },
+            typeName = typeName.orNull,
+            lift = lift.orNull,
+            lower = lower.orNull,
+        )
+        return config.takeIf { it != Config.CustomTypePlatform() }
+    }
+
     private fun mergeSet(lhs: List<String>?, rhs: List<String>?): List<String>? {
         if (lhs == null) return rhs
         if (rhs == null) return lhs
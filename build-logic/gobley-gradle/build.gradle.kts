@@ -65,8 +65,10 @@ buildConfig {
         buildConfigField("String", "KOTLINX_ATOMICFU", "\"${libs.versions.kotlinx.atomicfu.get()}\"")
         buildConfigField("String", "KOTLINX_DATETIME", "\"${libs.versions.kotlinx.datetime.get()}\"")
         buildConfigField("String", "KOTLINX_COROUTINES", "\"${libs.versions.kotlinx.coroutines.get()}\"")
+        buildConfigField("String", "KOTLINX_COLLECTIONS_IMMUTABLE", "\"${libs.versions.kotlinx.collections.immutable.get()}\"")
         buildConfigField("String", "JNA", "\"${libs.versions.jna.get()}\"")
         buildConfigField("String", "ANDROIDX_ANNOTATION", "\"${libs.versions.androidx.annotation.get()}\"")
+        buildConfigField("String", "COMPOSE_RUNTIME", "\"${libs.versions.compose.asProvider().get()}\"")
     }
 
     forClass("PluginIds") {
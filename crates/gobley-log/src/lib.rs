@@ -0,0 +1,181 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Forwards the records of the `log` crate, and of the `tracing` crate with the `tracing`
+//! feature, to a [`LogSink`] implemented in Kotlin.
+//!
+//! The bindgen adds Kotlin adapters to the bindings of this crate: a `GobleyLog` object installing
+//! a sink, a sink printing to the standard output, a Logcat sink on Android, and an SLF4J sink on
+//! Kotlin/JVM.
+
+#[cfg(feature = "tracing")]
+mod tracing_layer;
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Once, PoisonError, RwLock};
+
+uniffi::setup_scaffolding!("gobley_log");
+
+/// The severity of a [`LogRecord`], from the most severe to the least.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// A record logged by the Rust code.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    /// The target of the record, which is the module path of the caller by default.
+    pub target: String,
+    pub message: String,
+    pub module_path: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+}
+
+/// Receives the records logged by the Rust code. Implemented in Kotlin.
+#[uniffi::export(callback_interface)]
+pub trait LogSink: Send + Sync {
+    fn log(&self, record: LogRecord);
+}
+
+static SINK: RwLock<Option<Arc<dyn LogSink>>> = RwLock::new(None);
+
+// The most verbose level forwarded to the sink, as returned by `level_to_u8`. 0 disables logging.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(3);
+
+static INSTALL: Once = Once::new();
+
+thread_local! {
+    // Whether the current thread is calling the sink, so that the records logged while the sink
+    // calls back into Rust are dropped instead of recursing.
+    static IN_SINK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Forwards the records to `sink` from now on, replacing the previous sink.
+///
+/// The first call installs the `log` logger and, with the `tracing` feature, the global `tracing`
+/// subscriber. They are not installed if the application already installed its own.
+#[uniffi::export]
+pub fn set_log_sink(sink: Box<dyn LogSink>) {
+    *SINK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::from(sink));
+    INSTALL.call_once(|| {
+        if log::set_logger(&SinkLogger).is_ok() {
+            log::set_max_level(level_filter(MAX_LEVEL.load(Ordering::Relaxed)));
+        }
+        #[cfg(feature = "tracing")]
+        tracing_layer::install();
+    });
+}
+
+/// Forwards the records at `level` or more severe, or none when `level` is `None`. Defaults to
+/// [`LogLevel::Info`].
+#[uniffi::export]
+pub fn set_log_level(level: Option<LogLevel>) {
+    let level = level.map_or(0, level_to_u8);
+    MAX_LEVEL.store(level, Ordering::Relaxed);
+    if INSTALL.is_completed() {
+        log::set_max_level(level_filter(level));
+    }
+    #[cfg(feature = "tracing")]
+    tracing::callsite::rebuild_interest_cache();
+}
+
+fn level_to_u8(level: LogLevel) -> u8 {
+    match level {
+        LogLevel::Error => 1,
+        LogLevel::Warn => 2,
+        LogLevel::Info => 3,
+        LogLevel::Debug => 4,
+        LogLevel::Trace => 5,
+    }
+}
+
+fn level_filter(level: u8) -> log::LevelFilter {
+    match level {
+        0 => log::LevelFilter::Off,
+        1 => log::LevelFilter::Error,
+        2 => log::LevelFilter::Warn,
+        3 => log::LevelFilter::Info,
+        4 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    }
+}
+
+fn is_enabled(level: LogLevel) -> bool {
+    level_to_u8(level) <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+fn forward(record: LogRecord) {
+    if IN_SINK.with(Cell::get) {
+        return;
+    }
+    // Release the lock before calling the sink, so the sink can replace itself with
+    // `set_log_sink`.
+    let Some(sink) = SINK.read().unwrap_or_else(PoisonError::into_inner).clone() else {
+        return;
+    };
+    let _guard = InSinkGuard::enter();
+    sink.log(record);
+}
+
+// Marks the current thread as calling the sink until dropped, even if the sink panics.
+struct InSinkGuard;
+
+impl InSinkGuard {
+    fn enter() -> Self {
+        IN_SINK.with(|in_sink| in_sink.set(true));
+        Self
+    }
+}
+
+impl Drop for InSinkGuard {
+    fn drop(&mut self) {
+        IN_SINK.with(|in_sink| in_sink.set(false));
+    }
+}
+
+struct SinkLogger;
+
+impl log::Log for SinkLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        is_enabled(metadata.level().into())
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        forward(LogRecord {
+            level: record.level().into(),
+            target: record.target().to_owned(),
+            message: record.args().to_string(),
+            module_path: record.module_path().map(str::to_owned),
+            file: record.file().map(str::to_owned),
+            line: record.line(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
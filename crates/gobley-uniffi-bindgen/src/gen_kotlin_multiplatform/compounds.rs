@@ -25,10 +25,12 @@ impl OptionalCodeType {
 }
 
 impl CodeType for OptionalCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
         format!(
             "{}?",
-            super::KotlinCodeOracle.find(self.inner()).type_label(ci)
+            super::KotlinCodeOracle
+                .find(self.inner())
+                .type_label(ci, config)
         )
     }
 
@@ -70,10 +72,12 @@ impl SequenceCodeType {
 }
 
 impl CodeType for SequenceCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
         format!(
             "List<{}>",
-            super::KotlinCodeOracle.find(self.inner()).type_label(ci)
+            super::KotlinCodeOracle
+                .find(self.inner())
+                .type_label(ci, config)
         )
     }
 
@@ -118,11 +122,15 @@ impl MapCodeType {
 }
 
 impl CodeType for MapCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
         format!(
             "Map<{}, {}>",
-            super::KotlinCodeOracle.find(self.key()).type_label(ci),
-            super::KotlinCodeOracle.find(self.value()).type_label(ci),
+            super::KotlinCodeOracle
+                .find(self.key())
+                .type_label(ci, config),
+            super::KotlinCodeOracle
+                .find(self.value())
+                .type_label(ci, config),
         )
     }
 
@@ -6,22 +6,23 @@
 
 use uniffi_bindgen::ComponentInterface;
 
-use super::CodeType;
+use super::{CodeType, Config};
 
 #[derive(Debug)]
 pub struct CustomCodeType {
     name: String,
+    module_path: String,
 }
 
 impl CustomCodeType {
-    pub fn new(name: String) -> Self {
-        CustomCodeType { name }
+    pub fn new(name: String, module_path: String) -> Self {
+        CustomCodeType { name, module_path }
     }
 }
 
 impl CodeType for CustomCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::KotlinCodeOracle.class_name(ci, &self.name)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::KotlinCodeOracle.type_class_name(ci, config, &self.module_path, &self.name)
     }
 
     fn canonical_name(&self) -> String {
@@ -13,17 +13,18 @@ use super::{CodeType, Config};
 #[derive(Debug)]
 pub struct EnumCodeType {
     id: String,
+    module_path: String,
 }
 
 impl EnumCodeType {
-    pub fn new(id: String) -> Self {
-        Self { id }
+    pub fn new(id: String, module_path: String) -> Self {
+        Self { id, module_path }
     }
 }
 
 impl CodeType for EnumCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::KotlinCodeOracle.class_name(ci, &self.id)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::KotlinCodeOracle.type_class_name(ci, config, &self.module_path, &self.id)
     }
 
     fn canonical_name(&self) -> String {
@@ -40,8 +41,8 @@ impl CodeType for EnumCodeType {
             Literal::Enum(v, _) => {
                 format!(
                     "{}.{}",
-                    self.type_label(ci),
-                    super::KotlinCodeOracle.enum_variant_name(v, config)
+                    self.type_label(ci, config),
+                    super::KotlinCodeOracle.enum_variant_name(&self.id, v, config)
                 )
             }
             _ => bail!("Invalid literal for Enum type: {literal:?}"),
@@ -0,0 +1,270 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeSet, HashSet};
+
+use anyhow::{bail, Result};
+use uniffi_bindgen::interface::{
+    AsType, Callable, ComponentInterface, Constructor, Method, Object, Type,
+};
+
+use super::Config;
+
+impl Config {
+    /// Resolve `include` and `exclude` against `ci`, and record the local types that are not
+    /// generated in `excluded_types`.
+    ///
+    /// The functions and types matching `include` are generated together with the types they use,
+    /// even if `include` doesn't match them. It is an error if `exclude` matches any of the latter.
+    pub(crate) fn resolve_filters(&mut self, ci: &ComponentInterface) -> Result<()> {
+        if self.include.is_empty() && self.exclude.is_empty() {
+            return Ok(());
+        }
+
+        let local_types = ci
+            .iter_local_types()
+            .filter_map(item_name)
+            .collect::<HashSet<_>>();
+        let mut errors = BTreeSet::new();
+        let mut kept = HashSet::new();
+        // The types to keep, with the path of the item using them.
+        let mut pending = Vec::new();
+
+        for func in ci.function_definitions() {
+            if self.matches_filters(func.name()) {
+                pending.extend(callable_types(func, func.name()));
+            }
+        }
+        for name in &local_types {
+            if self.matches_filters(name) && kept.insert(*name) {
+                pending.extend(self.item_types(ci, name, &mut errors));
+            }
+        }
+        while let Some((type_, user)) = pending.pop() {
+            match &type_ {
+                Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                    pending.push((*inner_type.clone(), user));
+                }
+                Type::Map {
+                    key_type,
+                    value_type,
+                } => {
+                    pending.push((*key_type.clone(), user.clone()));
+                    pending.push((*value_type.clone(), user));
+                }
+                _ => {
+                    let Some(name) = item_name(&type_).and_then(|name| local_types.get(name))
+                    else {
+                        continue;
+                    };
+                    if self.is_excluded(name) {
+                        errors.insert(format!("`{name}` is excluded, but `{user}` uses it"));
+                    } else if kept.insert(*name) {
+                        pending.extend(self.item_types(ci, name, &mut errors));
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            bail!(
+                "invalid `include` or `exclude` in `{}`:\n{}",
+                ci.namespace(),
+                errors.into_iter().collect::<Vec<_>>().join("\n")
+            );
+        }
+        self.excluded_types = local_types
+            .difference(&kept)
+            .map(|name| (*name).to_owned())
+            .collect();
+        Ok(())
+    }
+
+    /// Whether the top-level function named `name` is generated.
+    pub(crate) fn is_function_included(&self, name: &str) -> bool {
+        self.matches_filters(name)
+    }
+
+    /// Whether the code for `type_` is generated. Compound types are generated only if the types
+    /// they contain are.
+    pub(crate) fn is_type_included(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                self.is_type_included(inner_type)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => self.is_type_included(key_type) && self.is_type_included(value_type),
+            _ => item_name(type_).map_or(true, |name| !self.excluded_types.contains(name)),
+        }
+    }
+
+    /// The methods of `obj` that are not excluded.
+    pub(crate) fn included_methods<'a>(&self, obj: &'a Object) -> Vec<&'a Method> {
+        obj.methods()
+            .into_iter()
+            .filter(|meth| !self.is_excluded(&format!("{}.{}", obj.name(), meth.name())))
+            .collect()
+    }
+
+    /// The alternate constructors of `obj` that are not excluded.
+    pub(crate) fn included_constructors<'a>(&self, obj: &'a Object) -> Vec<&'a Constructor> {
+        obj.alternate_constructors()
+            .into_iter()
+            .filter(|cons| !self.is_excluded(&format!("{}.{}", obj.name(), cons.name())))
+            .collect()
+    }
+
+    fn matches_filters(&self, path: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| glob_match(pattern, path)))
+            && !self.is_excluded(path)
+    }
+
+    fn is_excluded(&self, path: &str) -> bool {
+        self.exclude.iter().any(|pattern| glob_match(pattern, path))
+    }
+
+    /// The types used by the generated members of the local type `name`.
+    pub(super) fn item_types(
+        &self,
+        ci: &ComponentInterface,
+        name: &str,
+        errors: &mut BTreeSet<String>,
+    ) -> Vec<(Type, String)> {
+        let mut types = Vec::new();
+        if let Some(rec) = ci.get_record_definition(name) {
+            types.extend(
+                rec.fields()
+                    .iter()
+                    .map(|field| (field.as_type(), format!("{name}.{}", field.name()))),
+            );
+        } else if let Some(e) = ci.get_enum_definition(name) {
+            for variant in e.variants() {
+                types.extend(variant.fields().iter().map(|field| {
+                    (
+                        field.as_type(),
+                        format!("{name}::{}.{}", variant.name(), field.name()),
+                    )
+                }));
+            }
+        } else if let Some(obj) = ci.get_object_definition(name) {
+            if let Some(cons) = obj.primary_constructor() {
+                types.extend(callable_types(cons, &format!("{name}.{}", cons.name())));
+            }
+            for cons in self.included_constructors(obj) {
+                types.extend(callable_types(cons, &format!("{name}.{}", cons.name())));
+            }
+            let methods = self.included_methods(obj);
+            if obj.has_callback_interface() && methods.len() != obj.methods().len() {
+                errors.insert(format!(
+                    "the methods of `{name}` cannot be excluded, since Kotlin implementations of \
+                     `{name}` must implement all of them"
+                ));
+            }
+            for meth in methods {
+                types.extend(callable_types(meth, &format!("{name}.{}", meth.name())));
+            }
+        } else if let Some(cbi) = ci.get_callback_interface_definition(name) {
+            for meth in cbi.methods() {
+                types.extend(callable_types(meth, &format!("{name}.{}", meth.name())));
+            }
+        } else if let Some(Type::Custom { builtin, .. }) = ci.get_type(name) {
+            types.push((*builtin, name.to_owned()));
+        }
+        types
+    }
+}
+
+/// The name of `type_` if it is a record, an enum, an object, a callback interface, or a custom type.
+pub(super) fn item_name(type_: &Type) -> Option<&str> {
+    match type_ {
+        Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Object { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => Some(name),
+        _ => None,
+    }
+}
+
+pub(super) fn callable_types(callable: &impl Callable, path: &str) -> Vec<(Type, String)> {
+    callable
+        .arguments()
+        .into_iter()
+        .map(AsType::as_type)
+        .chain(callable.return_type().cloned())
+        .chain(callable.throws_type().cloned())
+        .map(|type_| (type_, path.to_owned()))
+        .collect()
+}
+
+/// Matches `text` against `pattern`, where `*` matches any sequence of characters and `?` matches
+/// any single character.
+pub(super) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    // The position of the last `*` and the position in `text` it currently matches up to.
+    let mut star = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_without_wildcards() {
+        assert!(glob_match("Counter", "Counter"));
+        assert!(!glob_match("Counter", "Counters"));
+        assert!(!glob_match("Counter", "Count"));
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "Counter"));
+    }
+
+    #[test]
+    fn glob_match_with_star() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "Counter"));
+        assert!(glob_match("Counter.*", "Counter.increment"));
+        assert!(!glob_match("Counter.*", "CounterBuilder.build"));
+        assert!(glob_match("*_internal", "get_internal"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("a*b*c", "aXbYcZ"));
+        assert!(glob_match("**", "anything"));
+    }
+
+    #[test]
+    fn glob_match_with_question_mark() {
+        assert!(glob_match("get?", "getX"));
+        assert!(!glob_match("get?", "get"));
+        assert!(!glob_match("get?", "getXY"));
+        assert!(glob_match("?*", "x"));
+        assert!(!glob_match("?*", ""));
+        assert!(glob_match("v?lue", "välue"));
+    }
+}
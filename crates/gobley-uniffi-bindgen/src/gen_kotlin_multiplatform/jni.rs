@@ -0,0 +1,103 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use anyhow::{Context, Result};
+use askama::Template;
+use uniffi_bindgen::interface::ComponentInterface;
+
+use super::{filters, Config};
+
+/// The sources of the Rust crate exporting the `Java_...` symbols called by `UniffiJniLib`.
+pub struct JniShim {
+    pub cargo_toml: String,
+    pub build_rs: String,
+    pub lib_rs: String,
+}
+
+impl JniShim {
+    pub fn generate(config: &Config, ci: &ComponentInterface) -> Result<Self> {
+        Ok(Self {
+            cargo_toml: JniShimCargoToml { config, ci }
+                .render()
+                .context("failed to render Cargo.toml of the JNI shim crate")?,
+            build_rs: JniShimBuildRs { config }
+                .render()
+                .context("failed to render build.rs of the JNI shim crate")?,
+            lib_rs: JniShimLibRs { config, ci }
+                .render()
+                .context("failed to render lib.rs of the JNI shim crate")?,
+        })
+    }
+}
+
+#[derive(Template)]
+#[template(syntax = "rs", escape = "none", path = "jni-shim/Cargo.toml")]
+struct JniShimCargoToml<'a> {
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+}
+
+#[derive(Template)]
+#[template(syntax = "rs", escape = "none", path = "jni-shim/build.rs")]
+struct JniShimBuildRs<'a> {
+    config: &'a Config,
+}
+
+#[derive(Template)]
+#[template(syntax = "rs", escape = "none", path = "jni-shim/lib.rs")]
+struct JniShimLibRs<'a> {
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+}
+
+impl JniShimLibRs<'_> {
+    /// The prefix of the symbols implementing the `external` functions of `UniffiJniLib`.
+    fn symbol_prefix(&self) -> String {
+        format!("Java_{}_UniffiJniLib_", mangle(&self.config.package_name()))
+    }
+}
+
+/// Mangle a fully qualified Java name as specified in the JNI specification.
+pub fn mangle(nm: &str) -> String {
+    let mut mangled = String::with_capacity(nm.len());
+    for c in nm.chars() {
+        match c {
+            '.' | '/' => mangled.push('_'),
+            '_' => mangled.push_str("_1"),
+            ';' => mangled.push_str("_2"),
+            '[' => mangled.push_str("_3"),
+            c if c.is_ascii_alphanumeric() => mangled.push(c),
+            c => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    mangled.push_str(&format!("_0{unit:04x}"));
+                }
+            }
+        }
+    }
+    mangled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mangle_ascii_names() {
+        assert_eq!(mangle("com.example.Lib"), "com_example_Lib");
+        assert_eq!(mangle("com/example/Lib"), "com_example_Lib");
+        assert_eq!(mangle("uniffi_fn_my_func"), "uniffi_1fn_1my_1func");
+        assert_eq!(mangle("Lcom/example/Lib;"), "Lcom_example_Lib_2");
+        assert_eq!(mangle("[B"), "_3B");
+    }
+
+    #[test]
+    fn mangle_non_ascii_names() {
+        assert_eq!(mangle("café"), "caf_000e9");
+        assert_eq!(mangle("a$b"), "a_00024b");
+        // Characters outside of the BMP are mangled as UTF-16 surrogate pairs.
+        assert_eq!(mangle("a😀"), "a_0d83d_0de00");
+    }
+}
@@ -6,7 +6,7 @@
 
 use uniffi_bindgen::ComponentInterface;
 
-use super::CodeType;
+use super::{CodeType, Config};
 
 macro_rules! impl_code_type_for_miscellany {
     ($T:ident, $class_name:literal, $canonical_name:literal) => {
@@ -14,7 +14,7 @@ macro_rules! impl_code_type_for_miscellany {
         pub struct $T;
 
         impl CodeType for $T {
-            fn type_label(&self, _ci: &ComponentInterface) -> String {
+            fn type_label(&self, _ci: &ComponentInterface, _config: &Config) -> String {
                 $class_name.into()
             }
 
@@ -5,7 +5,7 @@
  */
 
 use std::borrow::Borrow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
@@ -14,6 +14,8 @@ use anyhow::{anyhow, bail, Context, Result};
 use askama::Template;
 use filters::header_escape_name;
 use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+pub use jni::JniShim;
+pub use runtime::SharedRuntime;
 use serde::{Deserialize, Serialize};
 use uniffi_bindgen::interface::*;
 
@@ -21,10 +23,14 @@ mod callback_interface;
 mod compounds;
 mod custom;
 mod enum_;
+mod filter;
+mod jni;
 mod miscellany;
 mod object;
 mod primitives;
 mod record;
+mod runtime;
+mod validation;
 mod variant;
 
 #[rustfmt::skip]
@@ -47,7 +53,7 @@ const CPP_KEYWORDS: &[&str] = &[
 trait CodeType: Debug {
     /// The language specific label used to reference this type. This will be used in
     /// method signatures and property declarations.
-    fn type_label(&self, ci: &ComponentInterface) -> String;
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String;
 
     /// A representation of this type label that can be used as part of another
     /// identifier. e.g. `read_foo()`, or `FooInternals`.
@@ -63,8 +69,7 @@ trait CodeType: Debug {
         config: &Config,
     ) -> Result<String> {
         let _ = literal;
-        let _ = config;
-        bail!("Unimplemented for {}", self.type_label(ci))
+        bail!("Unimplemented for {}", self.type_label(ci, config))
     }
 
     /// Name of the FfiConverter
@@ -93,10 +98,72 @@ pub enum ConfigKotlinTarget {
     Android,
     #[serde(rename = "native")]
     Native,
+    #[serde(rename = "js")]
+    Js,
+    #[serde(rename = "wasmJs")]
+    WasmJs,
     #[serde(rename = "stub")]
     Stub,
 }
 
+impl ConfigKotlinTarget {
+    /// The `module_name` of the bindings generated for the target.
+    fn module_name(self) -> &'static str {
+        match self {
+            Self::Jvm => "jvm",
+            Self::Android => "android",
+            Self::Native => "native",
+            Self::Js => "js",
+            Self::WasmJs => "wasmJs",
+            Self::Stub => "stub",
+        }
+    }
+}
+
+/// How the Kotlin/JVM and Android bindings call into Rust.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JvmBackend {
+    #[default]
+    #[serde(rename = "jna")]
+    Jna,
+    #[serde(rename = "jni")]
+    Jni,
+    /// The Java Foreign Function & Memory API. Only applies to Kotlin/JVM.
+    #[serde(rename = "ffm")]
+    Ffm,
+}
+
+/// A value of `async_dispatcher` or `blocking_dispatcher`.
+enum DispatcherConfig<'a> {
+    /// `none`, the caller's context.
+    None,
+    /// `io`, the default when unset.
+    Io,
+    /// `default`
+    Default,
+    /// Any other value, which is a fully qualified Kotlin expression.
+    Expression(&'a str),
+}
+
+/// The keywords accepted by `async_dispatcher` and `blocking_dispatcher`, matched
+/// case-insensitively.
+const DISPATCHER_KEYWORDS: [&str; 3] = ["none", "io", "default"];
+
+fn dispatcher_config(value: Option<&str>) -> DispatcherConfig<'_> {
+    let Some(value) = value.map(str::trim) else {
+        return DispatcherConfig::Io;
+    };
+    if value.eq_ignore_ascii_case("none") {
+        DispatcherConfig::None
+    } else if value.eq_ignore_ascii_case("io") {
+        DispatcherConfig::Io
+    } else if value.eq_ignore_ascii_case("default") {
+        DispatcherConfig::Default
+    } else {
+        DispatcherConfig::Expression(value)
+    }
+}
+
 // config options to customize the generated Kotlin.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -126,6 +193,58 @@ pub struct Config {
     android_dynamic_library_dependencies: Vec<String>,
     #[serde(default)]
     dynamic_library_dependencies: Vec<String>,
+    #[serde(default)]
+    jvm_backend: JvmBackend,
+    #[serde(default)]
+    runtime_package: Option<String>,
+    #[serde(default)]
+    split_output: bool,
+    #[serde(default)]
+    jvm_common_source_set: Option<String>,
+    #[serde(default)]
+    visibility: Option<Visibility>,
+    #[serde(default)]
+    visibility_overrides: HashMap<String, Visibility>,
+    #[serde(default)]
+    rename: HashMap<String, String>,
+    #[serde(default)]
+    async_iterators: Vec<String>,
+    #[serde(default)]
+    async_dispatcher: Option<String>,
+    #[serde(default)]
+    suspend_wrappers: Vec<String>,
+    #[serde(default)]
+    blocking_dispatcher: Option<String>,
+    #[serde(default)]
+    java_interop: bool,
+    #[serde(default)]
+    java_interop_futures: Option<bool>,
+    #[serde(default)]
+    abort_on_panic: bool,
+    panic_backtrace_function: Option<String>,
+    #[serde(default)]
+    debug_object_tracking: bool,
+    #[serde(default)]
+    generate_parcelable: bool,
+    #[serde(default)]
+    compose_stability: bool,
+    compose_targets: Option<Vec<ConfigKotlinTarget>>,
+    #[serde(default)]
+    compose_immutable_collections: bool,
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    /// The local types that are not generated because of `include` and `exclude`.
+    #[serde(skip)]
+    pub(super) excluded_types: HashSet<String>,
+    /// The renamed types of the other components keyed by their crate names, so the references to
+    /// them use the new names.
+    #[serde(skip)]
+    pub(super) external_type_renames: HashMap<String, HashMap<String, String>>,
+    /// The packages of the other components sharing `runtime_package` with this component.
+    #[serde(skip)]
+    pub(super) runtime_package_peers: HashSet<String>,
 }
 
 impl Config {
@@ -134,7 +253,15 @@ impl Config {
     }
 }
 
+/// An async method returning `Option<T>` that is exposed as a `Flow<T>`, which calls the method until
+/// it returns `None`.
+pub(crate) struct AsyncIterator<'a> {
+    pub(crate) method: &'a Method,
+    pub(crate) item_type: Type,
+}
+
 // TODO: Make this public in 0.4.0
+// The variants are ordered from the most visible to the least visible.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub(crate) enum Visibility {
     #[serde(rename = "public")]
@@ -152,26 +279,87 @@ pub struct CustomTypeConfig {
     lift: String,
     from_custom: String, // b/w compat alias for lower
     lower: String,
+    /// Generate an inline value class wrapping the builtin type instead of a typealias.
+    value_class: bool,
+    /// The Kotlin type is parcelable, so records and enums containing it can be parcelable.
+    parcelable: bool,
+    jvm: Option<CustomTypePlatformConfig>,
+    android: Option<CustomTypePlatformConfig>,
+    native: Option<CustomTypePlatformConfig>,
+}
+
+/// Overrides of a `CustomTypeConfig` for a single platform.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomTypePlatformConfig {
+    imports: Option<Vec<String>>,
+    type_name: Option<String>,
+    lift: Option<String>,
+    lower: Option<String>,
 }
 
 // functions replace literal "{}" in strings with a specified value.
 impl CustomTypeConfig {
     fn lift(&self, name: &str) -> String {
-        let converter = if self.lift.is_empty() {
-            &self.into_custom
-        } else {
-            &self.lift
-        };
-        converter.replace("{}", name)
+        self.lift_converter().1.replace("{}", name)
     }
 
     fn lower(&self, name: &str) -> String {
-        let converter = if self.lower.is_empty() {
-            &self.from_custom
-        } else {
-            &self.lower
+        self.lower_converter().1.replace("{}", name)
+    }
+
+    /// The platform overrides, keyed by their names in `[bindings.kotlin.custom_types.<name>]`.
+    fn platforms(&self) -> [(&'static str, Option<&CustomTypePlatformConfig>); 3] {
+        [
+            ("jvm", self.jvm.as_ref()),
+            ("android", self.android.as_ref()),
+            ("native", self.native.as_ref()),
+        ]
+    }
+
+    /// Whether the Kotlin type differs between the platforms, in which case the common bindings
+    /// declare an `expect class` and each platform provides an `actual typealias`.
+    fn has_platform_types(&self) -> bool {
+        self.platforms()
+            .into_iter()
+            .flat_map(|(_, platform)| platform)
+            .any(|platform| platform.type_name.is_some() || platform.imports.is_some())
+    }
+
+    /// The configuration used by the bindings for `module_name`, with the overrides for the
+    /// platform applied. The shared Kotlin/JVM and Android bindings use the `jvm` overrides.
+    ///
+    /// Value classes get the converters wrapping and unwrapping `type_name`.
+    fn for_module(&self, module_name: &str, type_name: &str) -> CustomTypeConfig {
+        if self.value_class {
+            return CustomTypeConfig {
+                lift: format!("{type_name}({{}})"),
+                lower: "{}.value".to_owned(),
+                ..self.clone()
+            };
+        }
+        let platform = match module_name {
+            "jvm" | "jvmCommon" => self.jvm.as_ref(),
+            "android" => self.android.as_ref(),
+            "native" => self.native.as_ref(),
+            _ => None,
         };
-        converter.replace("{}", name)
+        let mut config = self.clone();
+        if let Some(platform) = platform {
+            if let Some(imports) = &platform.imports {
+                config.imports = Some(imports.clone());
+            }
+            if let Some(type_name) = &platform.type_name {
+                config.type_name = Some(type_name.clone());
+            }
+            if let Some(lift) = &platform.lift {
+                config.lift = lift.clone();
+            }
+            if let Some(lower) = &platform.lower {
+                config.lower = lower.clone();
+            }
+        }
+        config
     }
 }
 
@@ -248,6 +436,33 @@ impl Config {
         self.generate_serializable_types.unwrap_or(false)
     }
 
+    /// Whether to make the records and the enums parcelable on Android.
+    pub fn generate_parcelable(&self) -> bool {
+        self.generate_parcelable
+    }
+
+    /// Whether to annotate the records and the enums with the Compose stability annotations.
+    pub fn compose_stability(&self) -> bool {
+        self.compose_stability
+    }
+
+    /// Whether the bindings of `module_name` are compiled with the Compose runtime, so
+    /// `UniffiStable` and `UniffiImmutable` are the Compose annotations. `compose_targets` defaults
+    /// to Android only.
+    pub fn has_compose_runtime(&self, module_name: &str) -> bool {
+        self.compose_targets
+            .as_deref()
+            .unwrap_or(&[ConfigKotlinTarget::Android])
+            .iter()
+            .any(|target| target.module_name() == module_name)
+    }
+
+    /// Whether the `List` and `Map` fields of records use the `kotlinx.collections.immutable`
+    /// types instead.
+    pub fn compose_immutable_collections(&self) -> bool {
+        self.compose_immutable_collections
+    }
+
     pub fn jvm_dynamic_library_dependencies(&self) -> Vec<String> {
         let mut libraries = self.jvm_dynamic_library_dependencies.clone();
         libraries.extend_from_slice(&self.dynamic_library_dependencies);
@@ -268,6 +483,292 @@ impl Config {
         }
     }
 
+    /// The name of the generated JNI shim library used when `jvm_backend` is `jni`.
+    pub fn jni_shim_name(&self, namespace: &str) -> String {
+        format!("uniffi_{namespace}_jni")
+    }
+
+    /// The package containing the WebAssembly module embedded by `gobley-wasm-transformer`.
+    pub fn wasm_package_name(&self) -> String {
+        format!("gobley.wasm.{}", self.cdylib_name().replace('-', "_"))
+    }
+
+    /// The Kotlin expression of the coroutine context the async calls into Rust are polled in,
+    /// unless `UniffiAsyncDispatcher.context` is set at runtime.
+    pub fn async_dispatcher(&self) -> &str {
+        match dispatcher_config(self.async_dispatcher.as_deref()) {
+            // Polling Rust futures doesn't block, so they can be polled in the caller's context.
+            DispatcherConfig::None => "kotlin.coroutines.EmptyCoroutineContext",
+            DispatcherConfig::Io => "Dispatchers.IO",
+            DispatcherConfig::Default => "Dispatchers.Default",
+            DispatcherConfig::Expression(expression) => expression,
+        }
+    }
+
+    /// The Kotlin expression of the coroutine context the suspend wrappers of `module_name` call
+    /// into Rust in, unless `UniffiAsyncDispatcher.blockingContext` is set at runtime.
+    pub fn blocking_dispatcher(&self, module_name: &str) -> &str {
+        match dispatcher_config(self.blocking_dispatcher.as_deref()) {
+            DispatcherConfig::None => "kotlin.coroutines.EmptyCoroutineContext",
+            // Kotlin/JS and Kotlin/Wasm don't have `Dispatchers.IO`.
+            DispatcherConfig::Io if matches!(module_name, "js" | "wasmJs" | "stub") => {
+                "kotlinx.coroutines.Dispatchers.Default"
+            }
+            DispatcherConfig::Io => "Dispatchers.IO",
+            DispatcherConfig::Default => "kotlinx.coroutines.Dispatchers.Default",
+            DispatcherConfig::Expression(expression) => expression,
+        }
+    }
+
+    /// Whether to generate the Java-friendly APIs, such as `CompletableFuture` variants of async
+    /// functions, in the bindings of `module_name`. Only the Kotlin/JVM and Android bindings get
+    /// them.
+    pub fn has_java_interop(&self, module_name: &str) -> bool {
+        self.java_interop && matches!(module_name, "jvm" | "android" | "jvmCommon")
+    }
+
+    /// Whether to generate the `CompletableFuture` variants of async functions in the bindings of
+    /// `module_name` when `java_interop` is set. Unless `java_interop_futures` says otherwise, only
+    /// the Kotlin/JVM bindings get them, since `CompletableFuture` requires Android API level 24.
+    pub fn has_java_interop_futures(&self, module_name: &str) -> bool {
+        self.has_java_interop(module_name)
+            && self.java_interop_futures.unwrap_or(module_name == "jvm")
+    }
+
+    /// Whether to abort the process instead of throwing `RustPanicException` when the Rust code
+    /// panics.
+    pub fn abort_on_panic(&self) -> bool {
+        self.abort_on_panic
+    }
+
+    /// The function of the component returning the Rust backtrace of the last panic, which
+    /// `RustPanicException` calls to set `rustBacktrace`.
+    pub fn panic_backtrace_function(&self) -> Option<&str> {
+        self.panic_backtrace_function.as_deref()
+    }
+
+    /// Whether to write each record, enum, error, object, and callback interface into its own file.
+    pub fn split_output(&self) -> bool {
+        self.split_output
+    }
+
+    /// The package containing the helpers shared across components, if any.
+    pub fn runtime_package(&self) -> Option<&str> {
+        self.runtime_package.as_deref()
+    }
+
+    /// Whether the platform-specific helpers such as `ByteBuffer`, the primitive converters, and
+    /// the JNA `RustBuffer` structures come from `runtime_package`. Kotlin/JS and Kotlin/Wasm keep
+    /// their own copies, since they read the WebAssembly memory of each component.
+    pub fn has_shared_platform_runtime(&self, module_name: &str) -> bool {
+        self.runtime_package.is_some()
+            && matches!(module_name, "jvm" | "android" | "jvmCommon" | "native")
+    }
+
+    /// Whether the JNA `RustBuffer` structures come from `runtime_package`.
+    pub fn has_shared_rust_buffer(&self, module_name: &str) -> bool {
+        self.has_shared_platform_runtime(module_name) && self.uses_jna(module_name)
+    }
+
+    /// The package declaring the `RustBuffer` used by the component in `package_name`.
+    pub fn rust_buffer_package_of<'a>(
+        &'a self,
+        module_name: &str,
+        package_name: &'a str,
+    ) -> &'a str {
+        match self.runtime_package() {
+            Some(runtime_package)
+                if self.has_shared_rust_buffer(module_name)
+                    && self.shares_runtime_with(package_name) =>
+            {
+                runtime_package
+            }
+            _ => package_name,
+        }
+    }
+
+    /// Whether the bindings for `module_name` call into Rust through JNA.
+    pub(crate) fn uses_jna(&self, module_name: &str) -> bool {
+        match module_name {
+            "jvmCommon" => true,
+            "jvm" => self.jvm_backend == JvmBackend::Jna,
+            // Android does not provide the FFM API, so only the JNI backend applies.
+            "android" => self.jvm_backend != JvmBackend::Jni,
+            _ => false,
+        }
+    }
+
+    /// The name of the source set shared by Kotlin/JVM and Android without the `Main` suffix, if
+    /// the code shared by the two platforms should be generated there.
+    pub fn jvm_common_source_set(&self) -> Option<&str> {
+        let targets = self.kotlin_targets();
+        let has_both_targets = targets.contains(&ConfigKotlinTarget::Jvm)
+            && targets.contains(&ConfigKotlinTarget::Android);
+        self.jvm_common_source_set
+            .as_deref()
+            .filter(|_| self.kotlin_multiplatform && has_both_targets)
+    }
+
+    /// The visibility of the declarations without an entry in `visibility_overrides`.
+    pub(crate) fn visibility(&self) -> Visibility {
+        self.visibility.unwrap_or(Visibility::Public)
+    }
+
+    /// The visibility of the type or the top-level function named `name`.
+    pub(crate) fn item_visibility(&self, name: &str) -> Visibility {
+        self.visibility_overrides
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| self.visibility())
+    }
+
+    /// The visibility of the helpers such as `Disposable` and the FFI converters of builtin types.
+    /// They are referenced by every public declaration, so they stay public unless nothing is.
+    pub(crate) fn helper_visibility(&self) -> Visibility {
+        if self.visibility() == Visibility::Public
+            || self
+                .visibility_overrides
+                .values()
+                .any(|visibility| *visibility == Visibility::Public)
+        {
+            Visibility::Public
+        } else {
+            Visibility::Internal
+        }
+    }
+
+    /// The visibility of the code generated for `type_`. Compound types are as visible as the least
+    /// visible type they contain, since their FFI converters expose it.
+    pub(crate) fn type_visibility(&self, type_: &Type) -> Visibility {
+        match type_ {
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                self.type_visibility(inner_type)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => self
+                .type_visibility(key_type)
+                .max(self.type_visibility(value_type)),
+            Type::Enum { name, .. }
+            | Type::Object { name, .. }
+            | Type::Record { name, .. }
+            | Type::CallbackInterface { name, .. }
+            | Type::Custom { name, .. } => self.item_visibility(name),
+            _ => self.helper_visibility(),
+        }
+    }
+
+    /// The method of `obj` to turn into a `Flow` according to `async_iterators`, if any.
+    ///
+    /// The patterns match object names and may end with `.method` to use a method other than
+    /// `next`. The method must be async, take no arguments, and return an `Option`.
+    pub(crate) fn async_iterator<'a>(&self, obj: &'a Object) -> Option<AsyncIterator<'a>> {
+        self.async_iterators
+            .iter()
+            .find_map(|pattern| self.async_iterator_matching(pattern, obj))
+    }
+
+    fn async_iterator_matching<'a>(
+        &self,
+        pattern: &str,
+        obj: &'a Object,
+    ) -> Option<AsyncIterator<'a>> {
+        let (object_pattern, method_name) = pattern.split_once('.').unwrap_or((pattern, "next"));
+        if !filter::glob_match(object_pattern, obj.name()) {
+            return None;
+        }
+        let method = self
+            .included_methods(obj)
+            .into_iter()
+            .find(|meth| meth.name() == method_name)?;
+        match method.return_type() {
+            // `asFlow()` stops at the first `null`, so a nested `Option` would end the flow at the
+            // first `None` item.
+            Some(Type::Optional { inner_type })
+                if method.is_async()
+                    && method.arguments().is_empty()
+                    && !matches!(**inner_type, Type::Optional { .. }) =>
+            {
+                Some(AsyncIterator {
+                    method,
+                    item_type: (**inner_type).clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether to generate a suspend wrapper for the top-level function or the method at `path`
+    /// according to `suspend_wrappers`. Async functions and methods never get one.
+    pub(crate) fn has_suspend_wrapper(&self, path: &str, callable: &impl Callable) -> bool {
+        !callable.is_async()
+            && self
+                .suspend_wrappers
+                .iter()
+                .any(|pattern| filter::glob_match(pattern, path))
+    }
+
+    /// Whether any top-level function or method of `ci` gets a suspend wrapper.
+    pub fn has_suspend_wrappers(&self, ci: &ComponentInterface) -> bool {
+        if self.suspend_wrappers.is_empty() {
+            return false;
+        }
+        ci.function_definitions().iter().any(|func| {
+            self.is_function_included(func.name()) && self.has_suspend_wrapper(func.name(), func)
+        }) || ci.object_definitions().iter().any(|obj| {
+            self.is_type_included(&obj.as_type()) && !self.suspend_wrapped_methods(obj).is_empty()
+        })
+    }
+
+    /// The methods of `obj` that get a suspend wrapper.
+    pub(crate) fn suspend_wrapped_methods<'a>(&self, obj: &'a Object) -> Vec<&'a Method> {
+        self.included_methods(obj)
+            .into_iter()
+            .filter(|meth| {
+                self.has_suspend_wrapper(&format!("{}.{}", obj.name(), meth.name()), *meth)
+            })
+            .collect()
+    }
+
+    /// The Kotlin name given to `path` in `rename`, e.g., `Record`, `Object.method`,
+    /// `Record.field`, `Enum::Variant`, or `Enum::Variant.field`.
+    pub(crate) fn renamed(&self, path: &str) -> Option<&str> {
+        self.rename.get(path).map(String::as_str)
+    }
+
+    /// The renamed types of this component, keyed by their names in Rust.
+    pub(crate) fn type_renames(&self) -> HashMap<String, String> {
+        self.rename
+            .iter()
+            .filter(|(path, _)| !path.contains(['.', ':']))
+            .map(|(path, name)| (path.clone(), name.clone()))
+            .collect()
+    }
+
+    /// The Kotlin name given to the type `name` defined in `module_path` by the `rename` of the
+    /// crate defining it.
+    pub(crate) fn renamed_type(
+        &self,
+        ci: &ComponentInterface,
+        module_path: &str,
+        name: &str,
+    ) -> Option<&str> {
+        let crate_name = module_path.split("::").next().unwrap();
+        if crate_name == ci.crate_name() {
+            return self.renamed(name);
+        }
+        self.external_type_renames
+            .get(crate_name)?
+            .get(name)
+            .map(String::as_str)
+    }
+
+    /// Whether the component in `package_name` uses the same `runtime_package`.
+    pub fn shares_runtime_with(&self, package_name: impl AsRef<str>) -> bool {
+        self.runtime_package_peers.contains(package_name.as_ref())
+    }
+
     // Get the package name for an external type
     pub fn external_package_name(&self, module_path: &str, namespace: Option<&str>) -> String {
         // config overrides are keyed by the crate name, default fallback is the namespace.
@@ -285,11 +786,15 @@ impl Config {
 
 pub struct MultiplatformBindings {
     pub common: String,
+    pub jvm_common: Option<String>,
     pub jvm: Option<String>,
     pub android: Option<String>,
     pub native: Option<String>,
+    pub js: Option<String>,
+    pub wasm_js: Option<String>,
     pub stub: Option<String>,
     pub header: Option<String>,
+    pub jni_shim: Option<JniShim>,
 }
 
 // Generate kotlin bindings for the given ComponentInterface, as a string.
@@ -297,7 +802,8 @@ pub fn generate_bindings(
     config: &Config,
     ci: &ComponentInterface,
 ) -> Result<MultiplatformBindings> {
-    let common = CommonKotlinWrapper::new("common", Some(Visibility::Public), config.clone(), ci)
+    let visibility = Some(config.helper_visibility());
+    let common = CommonKotlinWrapper::new("common", visibility, config.clone(), ci)
         .context("failed to create a common binding generator")?
         .render()
         .context("failed to render common Kotlin bindings")?;
@@ -314,36 +820,106 @@ pub fn generate_bindings(
             .map_or(Ok(None), |v| v.map(Some))
     }
 
+    let kotlin_targets = config.kotlin_targets();
+    let use_jni = config.jvm_backend == JvmBackend::Jni
+        && (kotlin_targets.contains(&ConfigKotlinTarget::Jvm)
+            || kotlin_targets.contains(&ConfigKotlinTarget::Android));
+    if use_jni {
+        check_jni_backend_support(ci)?;
+    }
+
+    // Kotlin/JVM and Android can share the code only when both use JNA.
+    let jvm_common_source_set = config
+        .jvm_common_source_set()
+        .filter(|_| config.jvm_backend != JvmBackend::Ffm && !use_jni);
+
+    let jvm_common = jvm_common_source_set
+        .map(|source_set_name| {
+            AndroidJvmKotlinWrapper::new("jvmCommon", visibility, config.clone(), ci)
+                .context("failed to create a shared JVM binding generator")?
+                .render()
+                .with_context(|| format!("failed to render {source_set_name}Main bindings"))
+        })
+        .transpose()?;
+
     let jvm = run_with_target(config, ConfigKotlinTarget::Jvm, || {
-        AndroidJvmKotlinWrapper::new("jvm", Some(Visibility::Public), config.clone(), ci)
+        if let Some(source_set_name) = jvm_common_source_set {
+            return AndroidJvmPlatformKotlinWrapper::new("jvm", source_set_name, config, ci)
+                .render()
+                .context("failed to render Kotlin/JVM platform bindings");
+        }
+        if config.jvm_backend == JvmBackend::Ffm {
+            return FfmKotlinWrapper::new("jvm", visibility, config.clone(), ci)
+                .context("failed to create a JVM FFM binding generator")?
+                .render()
+                .context("failed to render Kotlin/JVM FFM bindings");
+        }
+        if use_jni {
+            return JniKotlinWrapper::new("jvm", visibility, config.clone(), ci)
+                .context("failed to create a JVM JNI binding generator")?
+                .render()
+                .context("failed to render Kotlin/JVM JNI bindings");
+        }
+        AndroidJvmKotlinWrapper::new("jvm", visibility, config.clone(), ci)
             .context("failed to create a JVM binding generator")?
             .render()
             .context("failed to render Kotlin/JVM bindings")
     })?;
 
     let android = run_with_target(config, ConfigKotlinTarget::Android, || {
-        AndroidJvmKotlinWrapper::new("android", Some(Visibility::Public), config.clone(), ci)
+        if let Some(source_set_name) = jvm_common_source_set {
+            return AndroidJvmPlatformKotlinWrapper::new("android", source_set_name, config, ci)
+                .render()
+                .context("failed to render Android platform bindings");
+        }
+        if use_jni {
+            return JniKotlinWrapper::new("android", visibility, config.clone(), ci)
+                .context("failed to create a Android JNI binding generator")?
+                .render()
+                .context("failed to render Android Kotlin/JVM JNI bindings");
+        }
+        AndroidJvmKotlinWrapper::new("android", visibility, config.clone(), ci)
             .context("failed to create a Android binding generator")?
             .render()
             .context("failed to render Android Kotlin/JVM bindings")
     })?;
 
+    let jni_shim = (use_jni && (jvm.is_some() || android.is_some()))
+        .then(|| JniShim::generate(config, ci))
+        .transpose()?;
+
     let native = run_with_target(config, ConfigKotlinTarget::Native, || {
-        NativeKotlinWrapper::new("native", Some(Visibility::Public), config.clone(), ci)
+        NativeKotlinWrapper::new("native", visibility, config.clone(), ci)
             .context("failed to create a native binding generator")?
             .render()
             .context("failed to render Kotlin/Native bindings")
     })?;
 
+    let js = run_with_target(config, ConfigKotlinTarget::Js, || {
+        check_web_target_support(ci, "Kotlin/JS")?;
+        JsKotlinWrapper::new("js", visibility, config.clone(), ci)
+            .context("failed to create a Kotlin/JS binding generator")?
+            .render()
+            .context("failed to render Kotlin/JS bindings")
+    })?;
+
+    let wasm_js = run_with_target(config, ConfigKotlinTarget::WasmJs, || {
+        check_web_target_support(ci, "Kotlin/Wasm")?;
+        WasmJsKotlinWrapper::new("wasmJs", visibility, config.clone(), ci)
+            .context("failed to create a Kotlin/Wasm binding generator")?
+            .render()
+            .context("failed to render Kotlin/Wasm bindings")
+    })?;
+
     let stub = run_with_target(config, ConfigKotlinTarget::Stub, || {
-        StubKotlinWrapper::new("stub", Some(Visibility::Public), config.clone(), ci)
+        StubKotlinWrapper::new("stub", visibility, config.clone(), ci)
             .context("failed to create a stub binding generator")?
             .render()
             .context("failed to render stub bindings")
     })?;
 
     let header = run_with_target(config, ConfigKotlinTarget::Native, || {
-        HeadersKotlinWrapper::new("headers", Some(Visibility::Public), config.clone(), ci)
+        HeadersKotlinWrapper::new("headers", visibility, config.clone(), ci)
             .context("failed to create a native header binding generator")?
             .render()
             .context("failed to render Kotlin/Native headers")
@@ -351,14 +927,143 @@ pub fn generate_bindings(
 
     Ok(MultiplatformBindings {
         common,
+        jvm_common,
         jvm,
         android,
         native,
+        js,
+        wasm_js,
         stub,
         header,
+        jni_shim,
     })
 }
 
+/// Fails when the component uses features Kotlin/JS and Kotlin/Wasm bindings can't implement yet.
+/// Rust calling back into Kotlin, which callback interfaces, foreign traits, and polling the futures
+/// of async functions require, needs Kotlin functions to be placed in the function table of the
+/// WebAssembly module.
+fn check_web_target_support(ci: &ComponentInterface, target_name: &str) -> Result<()> {
+    let unsupported = items_calling_into_kotlin(ci);
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "{target_name} bindings cannot be generated for `{}`, since it uses features requiring \
+         Rust to call back into Kotlin, which {target_name} does not support yet: {}. Generate \
+         the bindings without the {target_name} target, or move these items to a separate crate.",
+        ci.namespace(),
+        unsupported.join(", ")
+    )
+}
+
+/// Fails when the component uses features the JNI shim cannot export to Rust yet.
+fn check_jni_backend_support(ci: &ComponentInterface) -> Result<()> {
+    let unsupported = items_calling_into_kotlin(ci);
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "the `jni` JVM backend cannot be used for `{}`, since it uses features requiring Rust to \
+         call back into Kotlin, which the JNI shim does not support yet: {}. Set `jvm_backend` to \
+         `jna` or `ffm`, or move these items to a separate crate.",
+        ci.namespace(),
+        unsupported.join(", ")
+    )
+}
+
+/// Describes the callback interfaces, foreign traits, and async functions of the component, which
+/// require Rust to call Kotlin functions.
+fn items_calling_into_kotlin(ci: &ComponentInterface) -> Vec<String> {
+    let mut items = ci
+        .callback_interface_definitions()
+        .iter()
+        .map(|cbi| format!("callback interface `{}`", cbi.name()))
+        .collect::<Vec<_>>();
+    for obj in ci.object_definitions() {
+        if obj.has_callback_interface() {
+            items.push(format!("foreign trait `{}`", obj.name()));
+        }
+        for cons in obj.constructors() {
+            if cons.is_async() {
+                items.push(format!(
+                    "async constructor `{}.{}`",
+                    obj.name(),
+                    cons.name()
+                ));
+            }
+        }
+        for meth in obj.methods() {
+            if meth.is_async() {
+                items.push(format!("async method `{}.{}`", obj.name(), meth.name()));
+            }
+        }
+    }
+    for func in ci.function_definitions() {
+        if func.is_async() {
+            items.push(format!("async function `{}`", func.name()));
+        }
+    }
+    items
+}
+
+// Prefixes the lines separating the code of each type when `split_output` is enabled. The rest of the
+// line is the name of the file the following code belongs to, or empty for the main file.
+const SPLIT_OUTPUT_MARKER: &str = "// uniffi-split-output: ";
+
+// The name of the file containing the helpers shared by the types when `split_output` is enabled,
+// such as `RustBuffer`, the FFI converters of builtin types, and the FFI function declarations.
+const SPLIT_OUTPUT_HELPERS: &str = "UniffiHelpers";
+
+/// Split bindings rendered with `split_output` into the code for the main file and the code for
+/// each type, keyed by the type name. The shared helpers are keyed by `SPLIT_OUTPUT_HELPERS`.
+///
+/// The file annotations, the package directive and the imports of the main file are copied to the
+/// file of each type.
+pub fn split_sources(content: &str) -> (String, Vec<(String, String)>) {
+    let mut main = String::new();
+    let mut types = Vec::<(String, String)>::new();
+    let mut header = Vec::new();
+    let mut current: Option<usize> = None;
+    let mut seen_marker = false;
+
+    for line in content.lines() {
+        if let Some(name) = line.strip_prefix(SPLIT_OUTPUT_MARKER) {
+            seen_marker = true;
+            current = (!name.is_empty()).then(|| {
+                types
+                    .iter()
+                    .position(|(type_name, _)| type_name == name)
+                    .unwrap_or_else(|| {
+                        types.push((name.to_owned(), String::new()));
+                        types.len() - 1
+                    })
+            });
+            continue;
+        }
+        if !seen_marker
+            && (line.starts_with("@file:")
+                || line.starts_with("package ")
+                || line.starts_with("import "))
+        {
+            header.push(line);
+        }
+        let code = match current {
+            Some(index) => &mut types[index].1,
+            None => &mut main,
+        };
+        code.push_str(line);
+        code.push('\n');
+    }
+
+    let header = header.join("\n");
+    let types = types
+        .into_iter()
+        .map(|(name, code)| (name, format!("{header}\n{code}")))
+        .collect();
+    (main, types)
+}
+
 /// A struct to record a Kotlin import statement.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum ImportRequirement {
@@ -396,6 +1101,8 @@ macro_rules! kotlin_type_renderer {
             ci: &'a ComponentInterface,
             // Track imports added with the `add_import()` macro
             imports: RefCell<BTreeSet<ImportRequirement>>,
+            // The visibility of the type being rendered, set by `visibility_section()`
+            item_visibility: Cell<Option<Visibility>>,
         }
 
         #[allow(dead_code)]
@@ -412,6 +1119,7 @@ macro_rules! kotlin_type_renderer {
                     config,
                     ci,
                     imports: RefCell::new(BTreeSet::new()),
+                    item_visibility: Cell::new(None),
                 }
             }
 
@@ -448,11 +1156,63 @@ macro_rules! kotlin_type_renderer {
             }
 
             fn visibility(&self) -> &str {
-                match self.visibility {
-                    None => "",
-                    Some(Visibility::Public) => "public ",
-                    Some(Visibility::Internal) => "internal ",
+                let Some(visibility) = self.visibility else {
+                    return "";
+                };
+                match self.item_visibility.get().unwrap_or(visibility) {
+                    Visibility::Public => "public ",
+                    Visibility::Internal => "internal ",
+                }
+            }
+
+            // The local types to generate, according to `include` and `exclude`.
+            fn generated_types(&self) -> impl Iterator<Item = &'a Type> + 'a {
+                let config = self.config;
+                self.ci
+                    .iter_local_types()
+                    .filter(move |type_| config.is_type_included(type_))
+            }
+
+            // Makes `visibility()` return the visibility of `type_` until `visibility_section_end()`
+            // is called, so the declarations and the FFI converter of a type can be hidden or
+            // exposed on their own with `visibility_overrides`.
+            fn visibility_section(&self, type_: &Type) -> &str {
+                self.item_visibility
+                    .set(Some(self.config.type_visibility(type_)));
+                ""
+            }
+
+            fn visibility_section_end(&self) -> &str {
+                self.item_visibility.set(None);
+                ""
+            }
+
+            // Marks the beginning of the code for `type_` when `split_output` is enabled.
+            //
+            // Records, enums, errors, objects and callback interfaces are moved to their own files
+            // by `split_sources`. The code for the other types goes to the helpers file.
+            fn split_output_section(&self, type_: &Type) -> String {
+                if !self.config.split_output() {
+                    return String::new();
+                }
+                let name = match type_ {
+                    Type::Enum { name, .. }
+                    | Type::Object { name, .. }
+                    | Type::Record { name, .. }
+                    | Type::CallbackInterface { name, .. } => {
+                        KotlinCodeOracle.class_name(self.ci, self.config, name)
+                    }
+                    _ => SPLIT_OUTPUT_HELPERS.to_owned(),
+                };
+                format!("\n{SPLIT_OUTPUT_MARKER}{name}\n")
+            }
+
+            // Marks the end of the per-type code when `split_output` is enabled.
+            fn split_output_section_end(&self) -> String {
+                if !self.config.split_output() {
+                    return String::new();
                 }
+                format!("\n{SPLIT_OUTPUT_MARKER}\n")
             }
         }
     };
@@ -470,6 +1230,8 @@ macro_rules! kotlin_wrapper {
             ci: &'a ComponentInterface,
             type_helper_code: String,
             type_imports: BTreeSet<ImportRequirement>,
+            // The visibility of the function being rendered, set by `visibility_section()`
+            item_visibility: Cell<Option<Visibility>>,
         }
 
         #[allow(dead_code)]
@@ -482,7 +1244,12 @@ macro_rules! kotlin_wrapper {
             ) -> Result<Self> {
                 let type_renderer = $TypeRenderer::new(module_name, visibility, &config, ci);
                 let type_helper_code = type_renderer.render()?;
-                let type_imports = type_renderer.imports.into_inner();
+                let mut type_imports = type_renderer.imports.into_inner();
+                if let Some(runtime_package) = config.runtime_package() {
+                    type_imports.insert(ImportRequirement::Import {
+                        name: format!("{runtime_package}.*"),
+                    });
+                }
                 Ok(Self {
                     module_name,
                     visibility,
@@ -490,6 +1257,7 @@ macro_rules! kotlin_wrapper {
                     ci,
                     type_helper_code,
                     type_imports,
+                    item_visibility: Cell::new(None),
                 })
             }
 
@@ -497,6 +1265,7 @@ macro_rules! kotlin_wrapper {
                 let init_fns = self
                     .ci
                     .iter_local_types()
+                    .filter(|t| self.config.is_type_included(t))
                     .map(|t| KotlinCodeOracle.find(t))
                     .filter_map(|ct| ct.initialization_fn())
                     .map(|fn_name| format!("{fn_name}(lib)"));
@@ -524,11 +1293,51 @@ macro_rules! kotlin_wrapper {
                 self.type_imports.iter().cloned().collect()
             }
 
+            // The top-level functions to generate, according to `include` and `exclude`.
+            fn generated_functions(&self) -> impl Iterator<Item = &'a Function> + '_ {
+                self.ci
+                    .function_definitions()
+                    .iter()
+                    .filter(|func| self.config.is_function_included(func.name()))
+            }
+
+            // Makes `visibility()` return the visibility of `func` until `visibility_section_end()`
+            // is called.
+            fn visibility_section(&self, func: &Function) -> &str {
+                self.item_visibility
+                    .set(Some(self.config.item_visibility(func.name())));
+                ""
+            }
+
+            fn visibility_section_end(&self) -> &str {
+                self.item_visibility.set(None);
+                ""
+            }
+
+            // Marks the beginning of the helpers when `split_output` is enabled, which
+            // `split_sources` moves to their own file.
+            fn split_output_helpers_section(&self) -> String {
+                if !self.config.split_output() {
+                    return String::new();
+                }
+                format!("\n{SPLIT_OUTPUT_MARKER}{SPLIT_OUTPUT_HELPERS}\n")
+            }
+
+            // Marks the end of the helpers when `split_output` is enabled.
+            fn split_output_section_end(&self) -> String {
+                if !self.config.split_output() {
+                    return String::new();
+                }
+                format!("\n{SPLIT_OUTPUT_MARKER}\n")
+            }
+
             fn visibility(&self) -> &str {
-                match self.visibility {
-                    None => "",
-                    Some(Visibility::Public) => "public ",
-                    Some(Visibility::Internal) => "internal ",
+                let Some(visibility) = self.visibility else {
+                    return "";
+                };
+                match self.item_visibility.get().unwrap_or(visibility) {
+                    Visibility::Public => "public ",
+                    Visibility::Internal => "internal ",
                 }
             }
         }
@@ -545,9 +1354,55 @@ kotlin_wrapper!(
     "android+jvm/wrapper.kt"
 );
 
+// The parts of the Kotlin/JVM and Android bindings that differ between the two platforms, used when
+// the rest is generated in `jvm_common_source_set`.
+#[derive(Template)]
+#[template(syntax = "kt", escape = "none", path = "android+jvm/platform.kt")]
+pub struct AndroidJvmPlatformKotlinWrapper<'a> {
+    module_name: &'a str,
+    source_set_name: &'a str,
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+}
+
+impl<'a> AndroidJvmPlatformKotlinWrapper<'a> {
+    pub fn new(
+        module_name: &'a str,
+        source_set_name: &'a str,
+        config: &'a Config,
+        ci: &'a ComponentInterface,
+    ) -> Self {
+        Self {
+            module_name,
+            source_set_name,
+            config,
+            ci,
+        }
+    }
+
+    fn visibility(&self) -> &str {
+        match self.config.helper_visibility() {
+            Visibility::Public => "public ",
+            Visibility::Internal => "internal ",
+        }
+    }
+}
+
+kotlin_type_renderer!(JniTypeRenderer, "jni/Types.kt");
+kotlin_wrapper!(JniKotlinWrapper, JniTypeRenderer, "jni/wrapper.kt");
+
+kotlin_type_renderer!(FfmTypeRenderer, "ffm/Types.kt");
+kotlin_wrapper!(FfmKotlinWrapper, FfmTypeRenderer, "ffm/wrapper.kt");
+
 kotlin_type_renderer!(NativeTypeRenderer, "native/Types.kt");
 kotlin_wrapper!(NativeKotlinWrapper, NativeTypeRenderer, "native/wrapper.kt");
 
+kotlin_type_renderer!(JsTypeRenderer, "js/Types.kt");
+kotlin_wrapper!(JsKotlinWrapper, JsTypeRenderer, "js/wrapper.kt");
+
+kotlin_type_renderer!(WasmJsTypeRenderer, "wasmJs/Types.kt");
+kotlin_wrapper!(WasmJsKotlinWrapper, WasmJsTypeRenderer, "wasmJs/wrapper.kt");
+
 kotlin_type_renderer!(StubTypeRenderer, "stub/Types.kt");
 kotlin_wrapper!(StubKotlinWrapper, StubTypeRenderer, "stub/wrapper.kt");
 
@@ -568,8 +1423,8 @@ kotlin_wrapper!(
 /// This all impacts what types `FfiConverter.lower()` inputs.  If it's a "foreign trait"
 /// `lower` must lower anything that implements the interface (ie, a kotlin implementation).
 /// If not, then lower only lowers the concrete class (ie, our simple instance with the pointer).
-fn object_interface_name(ci: &ComponentInterface, obj: &Object) -> String {
-    let class_name = KotlinCodeOracle.class_name(ci, obj.name());
+fn object_interface_name(ci: &ComponentInterface, config: &Config, obj: &Object) -> String {
+    let class_name = KotlinCodeOracle.class_name(ci, config, obj.name());
     if obj.has_callback_interface() {
         class_name
     } else {
@@ -579,7 +1434,7 @@ fn object_interface_name(ci: &ComponentInterface, obj: &Object) -> String {
 
 // *sigh* - same thing for a trait, which might be either Object or CallbackInterface.
 // (we should either fold it into object or kill it!)
-fn trait_interface_name(ci: &ComponentInterface, name: &str) -> Result<String> {
+fn trait_interface_name(ci: &ComponentInterface, config: &Config, name: &str) -> Result<String> {
     let (obj_name, has_callback_interface) = match ci.get_object_definition(name) {
         Some(obj) => (obj.name(), obj.has_callback_interface()),
         None => (
@@ -589,7 +1444,7 @@ fn trait_interface_name(ci: &ComponentInterface, name: &str) -> Result<String> {
             true,
         ),
     };
-    let class_name = KotlinCodeOracle.class_name(ci, obj_name);
+    let class_name = KotlinCodeOracle.class_name(ci, config, obj_name);
     if has_callback_interface {
         Ok(class_name)
     } else {
@@ -598,8 +1453,8 @@ fn trait_interface_name(ci: &ComponentInterface, name: &str) -> Result<String> {
 }
 
 // The name of the object exposing a Rust implementation.
-fn object_impl_name(ci: &ComponentInterface, obj: &Object) -> String {
-    let class_name = KotlinCodeOracle.class_name(ci, obj.name());
+fn object_impl_name(ci: &ComponentInterface, config: &Config, obj: &Object) -> String {
+    let class_name = KotlinCodeOracle.class_name(ci, config, obj.name());
     if obj.has_callback_interface() {
         format!("{class_name}Impl")
     } else {
@@ -607,6 +1462,12 @@ fn object_impl_name(ci: &ComponentInterface, obj: &Object) -> String {
     }
 }
 
+// Whether `ci` is the component of the `gobley-log` crate, whose bindings get the adapters
+// forwarding its records to the platform loggers.
+fn is_gobley_log(ci: &ComponentInterface) -> bool {
+    ci.namespace() == "gobley_log" && ci.get_function_definition("set_log_sink").is_some()
+}
+
 #[derive(Clone)]
 pub struct KotlinCodeOracle;
 
@@ -616,7 +1477,26 @@ impl KotlinCodeOracle {
     }
 
     /// Get the idiomatic Kotlin rendering of a class name (for enums, records, errors, etc).
-    fn class_name(&self, ci: &ComponentInterface, nm: &str) -> String {
+    fn class_name(&self, ci: &ComponentInterface, config: &Config, nm: &str) -> String {
+        self.type_class_name(ci, config, ci.crate_name(), nm)
+    }
+
+    /// `class_name` for the type `nm` defined in `module_path`, which may be another crate.
+    fn type_class_name(
+        &self,
+        ci: &ComponentInterface,
+        config: &Config,
+        module_path: &str,
+        nm: &str,
+    ) -> String {
+        config
+            .renamed_type(ci, module_path, nm)
+            .map(str::to_owned)
+            .unwrap_or_else(|| self.idiomatic_class_name(ci, nm))
+    }
+
+    /// `class_name` without the renames, used for the names that are not types in Rust.
+    fn idiomatic_class_name(&self, ci: &ComponentInterface, nm: &str) -> String {
         let name = nm.to_string().to_upper_camel_case();
         // fixup errors.
         ci.is_name_used_as_error(nm)
@@ -631,15 +1511,57 @@ impl KotlinCodeOracle {
         }
     }
 
-    /// Get the idiomatic Kotlin rendering of a function name.
-    fn fn_name(&self, nm: &str) -> String {
-        format!("`{}`", nm.to_string().to_lower_camel_case())
+    /// Get the Kotlin rendering of the function `nm`, or the method `nm` of `owner` when `owner`
+    /// is not empty.
+    fn callable_name(&self, owner: &str, nm: &str, config: &Config) -> String {
+        let path = if owner.is_empty() {
+            nm.to_owned()
+        } else {
+            format!("{owner}.{nm}")
+        };
+        match config.renamed(&path) {
+            Some(name) => format!("`{name}`"),
+            None => self.fn_name(nm),
+        }
     }
 
-    /// Get the idiomatic Kotlin rendering of a variable name.
-    fn var_name(&self, nm: &str) -> String {
-        format!("`{}`", self.var_name_raw(nm))
-    }
+    /// Get the name of a function generated next to the function or the method `nm` of `owner`,
+    /// which is its Kotlin name with `suffix` appended, e.g., `Async` for the suspend wrappers.
+    fn suffixed_callable_name(
+        &self,
+        owner: &str,
+        nm: &str,
+        suffix: &str,
+        config: &Config,
+    ) -> String {
+        let path = if owner.is_empty() {
+            nm.to_owned()
+        } else {
+            format!("{owner}.{nm}")
+        };
+        match config.renamed(&path) {
+            Some(name) => format!("`{name}{suffix}`"),
+            None => format!("`{}{suffix}`", nm.to_lower_camel_case()),
+        }
+    }
+
+    /// Get the Kotlin rendering of the field `nm` of `owner`, a record or an enum variant.
+    fn field_name(&self, owner: &str, nm: &str, config: &Config) -> String {
+        match config.renamed(&format!("{owner}.{nm}")) {
+            Some(name) => format!("`{name}`"),
+            None => self.var_name(nm),
+        }
+    }
+
+    /// Get the idiomatic Kotlin rendering of a function name.
+    fn fn_name(&self, nm: &str) -> String {
+        format!("`{}`", nm.to_string().to_lower_camel_case())
+    }
+
+    /// Get the idiomatic Kotlin rendering of a variable name.
+    fn var_name(&self, nm: &str) -> String {
+        format!("`{}`", self.var_name_raw(nm))
+    }
 
     /// `var_name` without the backticks.  Useful for using in `@Structure.FieldOrder`.
     pub fn var_name_raw(&self, nm: &str) -> String {
@@ -647,8 +1569,10 @@ impl KotlinCodeOracle {
     }
 
     /// Get the idiomatic Kotlin rendering of an individual enum variant.
-    fn enum_variant_name(&self, nm: &str, config: &Config) -> String {
-        if config.use_pascal_case_enum_class == Some(true) {
+    fn enum_variant_name(&self, owner: &str, nm: &str, config: &Config) -> String {
+        if let Some(name) = config.renamed(&format!("{owner}::{nm}")) {
+            name.to_owned()
+        } else if config.use_pascal_case_enum_class == Some(true) {
             nm.to_upper_camel_case()
         } else {
             nm.to_shouty_snake_case()
@@ -912,12 +1836,20 @@ impl<T: AsType> AsCodeType for T {
             Type::Timestamp => Box::new(miscellany::TimestampCodeType),
             Type::Duration => Box::new(miscellany::DurationCodeType),
 
-            Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name)),
-            Type::Object { name, imp, .. } => Box::new(object::ObjectCodeType::new(name, imp)),
-            Type::Record { name, .. } => Box::new(record::RecordCodeType::new(name)),
-            Type::CallbackInterface { name, .. } => {
-                Box::new(callback_interface::CallbackInterfaceCodeType::new(name))
+            Type::Enum {
+                name, module_path, ..
+            } => Box::new(enum_::EnumCodeType::new(name, module_path)),
+            Type::Object {
+                name,
+                module_path,
+                imp,
+            } => Box::new(object::ObjectCodeType::new(name, module_path, imp)),
+            Type::Record { name, module_path } => {
+                Box::new(record::RecordCodeType::new(name, module_path))
             }
+            Type::CallbackInterface { name, module_path } => Box::new(
+                callback_interface::CallbackInterfaceCodeType::new(name, module_path),
+            ),
             Type::Optional { inner_type } => {
                 Box::new(compounds::OptionalCodeType::new(*inner_type))
             }
@@ -928,7 +1860,9 @@ impl<T: AsType> AsCodeType for T {
                 key_type,
                 value_type,
             } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
-            Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name)),
+            Type::Custom {
+                name, module_path, ..
+            } => Box::new(custom::CustomCodeType::new(name, module_path)),
         }
     }
 }
@@ -952,16 +1886,22 @@ mod filters {
     pub(super) fn type_name(
         as_ct: &impl AsCodeType,
         ci: &ComponentInterface,
+        config: &Config,
     ) -> Result<String, askama::Error> {
-        Ok(as_ct.as_codetype().type_label(ci))
+        Ok(as_ct.as_codetype().type_label(ci, config))
     }
 
     // Workaround problem with impl AsCodeType for &Variant (see variant.rs).
-    pub fn variant_type_name(
+    pub fn variant_type_name<S: AsRef<str>>(
         v: &Variant,
+        owner: S,
         ci: &ComponentInterface,
+        config: &Config,
     ) -> Result<String, askama::Error> {
-        Ok(VariantCodeType { v: v.clone() }.type_label(ci))
+        if let Some(name) = config.renamed(&format!("{}::{}", owner.as_ref(), v.name())) {
+            return Ok(name.to_owned());
+        }
+        Ok(VariantCodeType { v: v.clone() }.type_label(ci, config))
     }
 
     pub(super) fn canonical_name(as_ct: &impl AsCodeType) -> Result<String, askama::Error> {
@@ -1105,45 +2045,133 @@ mod filters {
         Ok(as_bytes_field_type_inner(&as_ct.as_type()))
     }
 
-    fn serializable_type(type_: &Type, ci: &ComponentInterface) -> Result<bool, askama::Error> {
+    pub fn serializable_type(type_: &Type, ci: &ComponentInterface) -> Result<bool, askama::Error> {
+        derivable_type(type_, ci, Derive::Serializable)
+    }
+
+    pub fn serializable_record(
+        record: &Record,
+        ci: &ComponentInterface,
+    ) -> Result<bool, askama::Error> {
+        derivable_fields(record.fields(), ci, Derive::Serializable)
+    }
+
+    pub fn serializable_enum(enum_: &Enum, ci: &ComponentInterface) -> Result<bool, askama::Error> {
+        derivable_enum(enum_, ci, Derive::Serializable)
+    }
+
+    pub fn serializable_enum_variant(
+        variant: &Variant,
+        ci: &ComponentInterface,
+    ) -> Result<bool, askama::Error> {
+        derivable_fields(variant.fields(), ci, Derive::Serializable)
+    }
+
+    pub fn parcelable_record(
+        record: &Record,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<bool, askama::Error> {
+        // Parcelize doesn't support the `kotlinx.collections.immutable` types.
+        if record
+            .fields()
+            .iter()
+            .any(|field| immutable_collection(&field.as_type(), config).is_some())
+        {
+            return Ok(false);
+        }
+        derivable_fields(record.fields(), ci, Derive::Parcelable(config))
+    }
+
+    pub fn parcelable_enum(
+        enum_: &Enum,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<bool, askama::Error> {
+        derivable_enum(enum_, ci, Derive::Parcelable(config))
+    }
+
+    /// The compiler plugins generating the implementation of a class from the types of its fields.
+    #[derive(Clone, Copy)]
+    enum Derive<'a> {
+        /// `@kotlinx.serialization.Serializable`
+        Serializable,
+        /// `@kotlinx.parcelize.Parcelize`, with the config telling which custom types are
+        /// parcelable.
+        Parcelable(&'a Config),
+    }
+
+    fn derivable_type(
+        type_: &Type,
+        ci: &ComponentInterface,
+        derive: Derive<'_>,
+    ) -> Result<bool, askama::Error> {
         Ok(match type_ {
             Type::Object { .. } | Type::CallbackInterface { .. } => false,
-            Type::Record { name, .. } => serializable_record(
+            Type::Record { name, .. } => derivable_fields(
                 ci.get_record_definition(name)
-                    .ok_or_else(|| to_askama_error(&format!("could not find record '{name}'")))?,
+                    .ok_or_else(|| to_askama_error(&format!("could not find record '{name}'")))?
+                    .fields(),
                 ci,
+                derive,
             )?,
-            Type::Enum { name, .. } => serializable_enum(
+            Type::Enum { name, .. } => derivable_enum(
                 ci.get_enum_definition(name)
                     .ok_or_else(|| to_askama_error(&format!("could not find enum '{name}'")))?,
                 ci,
+                derive,
             )?,
             Type::Optional { inner_type } | Type::Sequence { inner_type } => {
-                serializable_type(inner_type, ci)?
+                derivable_type(inner_type, ci, derive)?
             }
             Type::Map {
                 key_type,
                 value_type,
-            } => serializable_type(key_type, ci)? && serializable_type(value_type, ci)?,
-            // Assume a custom type using a serializable type is also serializable.
-            Type::Custom { builtin, .. } => serializable_type(builtin, ci)?,
+            } => derivable_type(key_type, ci, derive)? && derivable_type(value_type, ci, derive)?,
+            Type::Custom { name, builtin, .. } => match derive {
+                // Assume a custom type using a serializable type is also serializable.
+                Derive::Serializable => derivable_type(builtin, ci, derive)?,
+                // A configured custom type may map to any Kotlin class, so it's parcelable only
+                // when it wraps the builtin type in a value class or is marked as parcelable.
+                // Without a config entry, the custom type is a typealias of the builtin type.
+                Derive::Parcelable(config) => match config.custom_types.get(name) {
+                    Some(custom_type) if custom_type.parcelable => true,
+                    Some(custom_type) if custom_type.value_class => {
+                        derivable_type(builtin, ci, derive)?
+                    }
+                    Some(_) => false,
+                    None => derivable_type(builtin, ci, derive)?,
+                },
+            },
+            // Parcelize doesn't support the unsigned integers, `Instant`, and `Duration`.
+            Type::UInt8
+            | Type::UInt16
+            | Type::UInt32
+            | Type::UInt64
+            | Type::Timestamp
+            | Type::Duration => !matches!(derive, Derive::Parcelable(_)),
             _ => true,
         })
     }
 
-    pub fn serializable_record(
-        record: &Record,
+    fn derivable_fields(
+        fields: &[Field],
         ci: &ComponentInterface,
+        derive: Derive<'_>,
     ) -> Result<bool, askama::Error> {
-        for field in record.fields() {
-            if !serializable_type(&field.as_type(), ci)? {
+        for field in fields {
+            if !derivable_type(&field.as_type(), ci, derive)? {
                 return Ok(false);
             }
         }
         Ok(true)
     }
 
-    pub fn serializable_enum(enum_: &Enum, ci: &ComponentInterface) -> Result<bool, askama::Error> {
+    fn derivable_enum(
+        enum_: &Enum,
+        ci: &ComponentInterface,
+        derive: Derive<'_>,
+    ) -> Result<bool, askama::Error> {
         if ci.is_name_used_as_error(enum_.name()) {
             return Ok(false);
         }
@@ -1152,29 +2180,21 @@ mod filters {
             let Some(variant_discr_type) = enum_.variant_discr_type() else {
                 return Ok(true);
             };
-            return serializable_type(variant_discr_type, ci);
+            return derivable_type(variant_discr_type, ci, derive);
         }
 
         // Unlike records or enum variants, if any of the variants are serializable, the
-        // enum can be marked as serializable.
+        // enum can be marked as serializable. Parcelize requires every subclass of a parcelable
+        // sealed class to be parcelable.
         for variant in enum_.variants() {
-            if serializable_enum_variant(variant, ci)? {
-                return Ok(true);
+            let derivable = derivable_fields(variant.fields(), ci, derive)?;
+            match derive {
+                Derive::Serializable if derivable => return Ok(true),
+                Derive::Parcelable(_) if !derivable => return Ok(false),
+                _ => {}
             }
         }
-        Ok(false)
-    }
-
-    pub fn serializable_enum_variant(
-        variant: &Variant,
-        ci: &ComponentInterface,
-    ) -> Result<bool, askama::Error> {
-        for field in variant.fields() {
-            if !serializable_type(&field.as_type(), ci)? {
-                return Ok(false);
-            }
-        }
-        Ok(true)
+        Ok(matches!(derive, Derive::Parcelable(_)))
     }
 
     pub fn ffi_type_name_by_value(
@@ -1260,6 +2280,572 @@ mod filters {
         Ok(format!(".from{}ToLocal()", metadata.name))
     }
 
+    /// Whether the arguments and the return value of `func` are plain values, excluding the
+    /// callbacks and the FFI structs that require Rust to call Kotlin functions.
+    fn ffi_func_has_plain_signature(func: &FfiFunction) -> bool {
+        fn plain(type_: &FfiType) -> bool {
+            !matches!(
+                type_,
+                FfiType::Callback(_)
+                    | FfiType::Struct(_)
+                    | FfiType::Reference(_)
+                    | FfiType::MutReference(_)
+            )
+        }
+        func.arguments().iter().all(|a| plain(&a.type_())) && func.return_type().is_none_or(plain)
+    }
+
+    /// Kotlin/JS bindings can only call FFI functions whose arguments can be lowered into
+    /// WebAssembly values or copied into the linear memory.
+    pub fn ffi_func_supported_js(func: &FfiFunction) -> Result<bool, askama::Error> {
+        Ok(ffi_func_has_plain_signature(func))
+    }
+
+    /// RustBuffers returned by value are written to a caller-provided address in the
+    /// WebAssembly C ABI.
+    pub fn ffi_returns_indirectly_js(func: &FfiFunction) -> Result<bool, askama::Error> {
+        Ok(matches!(func.return_type(), Some(FfiType::RustBuffer(_))))
+    }
+
+    /// Convert a Kotlin/JS FFI value to a value accepted by a WebAssembly export.
+    pub fn ffi_lower_into_wasm_js(
+        type_: &FfiType,
+        nm: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            FfiType::Int64 | FfiType::UInt64 | FfiType::Handle => format!("{nm}.toBigInt()"),
+            _ => lower_into_wasm(type_, nm, ci)?,
+        })
+    }
+
+    /// Convert a Kotlin/Wasm FFI value to a value accepted by a WebAssembly export. Unlike
+    /// Kotlin/JS, `Long` values are converted from and to BigInt values by the compiler.
+    pub fn ffi_lower_into_wasm_wasm_js(
+        type_: &FfiType,
+        nm: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        lower_into_wasm(type_, nm, ci)
+    }
+
+    fn lower_into_wasm(
+        type_: &FfiType,
+        nm: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            FfiType::Int8 | FfiType::UInt8 | FfiType::Int16 | FfiType::UInt16 => {
+                format!("{nm}.toInt()")
+            }
+            FfiType::RustArcPtr(_) | FfiType::VoidPointer => format!("({nm} ?: 0)"),
+            FfiType::RustBuffer(_) => format!(
+                "frame.push({nm}{})",
+                ffi_cast_to_local_rust_buffer_if_needed(type_, ci)?
+            ),
+            FfiType::ForeignBytes => format!("frame.push({nm})"),
+            _ => nm.to_owned(),
+        })
+    }
+
+    /// Convert a value returned by a WebAssembly export to a Kotlin/JS FFI value.
+    pub fn ffi_lift_from_wasm_js(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            Some(FfiType::Int64 | FfiType::UInt64 | FfiType::Handle) => {
+                ".bigIntToLong()".to_owned()
+            }
+            _ => ffi_lift_from_wasm_wasm_js(func)?,
+        })
+    }
+
+    /// Convert a value returned by a WebAssembly export to a Kotlin/Wasm FFI value.
+    pub fn ffi_lift_from_wasm_wasm_js(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            Some(FfiType::Int8 | FfiType::UInt8) => ".toByte()".to_owned(),
+            Some(FfiType::Int16 | FfiType::UInt16) => ".toShort()".to_owned(),
+            _ => String::new(),
+        })
+    }
+
+    /// The Kotlin/Wasm type of a WebAssembly value that `type_` is lowered into.
+    pub fn ffi_type_name_wasm_js(type_: &FfiType) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            FfiType::Int64 | FfiType::UInt64 | FfiType::Handle => "Long",
+            FfiType::Float32 => "Float",
+            FfiType::Float64 => "Double",
+            // Integers narrower than 32 bits and pointers to the linear memory.
+            _ => "Int",
+        }
+        .to_owned())
+    }
+
+    /// The JNI shim can only forward FFI functions whose arguments and return values can be
+    /// passed as JNI primitives or `long[]` arrays.
+    pub fn ffi_func_supported_jni(func: &FfiFunction) -> Result<bool, askama::Error> {
+        Ok(ffi_func_has_plain_signature(func))
+    }
+
+    /// RustBuffers returned by value are written into a `long[]` array passed by the caller,
+    /// since a JNI function can only return a single primitive.
+    pub fn ffi_returns_indirectly_jni(func: &FfiFunction) -> Result<bool, askama::Error> {
+        Ok(matches!(func.return_type(), Some(FfiType::RustBuffer(_))))
+    }
+
+    pub fn jni_mangle(nm: &str) -> Result<String, askama::Error> {
+        Ok(jni::mangle(nm))
+    }
+
+    /// The Kotlin type of a primitive value passed through JNI.
+    fn jni_kotlin_type(type_: &FfiType) -> &'static str {
+        match type_ {
+            FfiType::Int8 | FfiType::UInt8 => "Byte",
+            FfiType::Int16 | FfiType::UInt16 => "Short",
+            FfiType::Int32 | FfiType::UInt32 => "Int",
+            FfiType::Float32 => "Float",
+            FfiType::Float64 => "Double",
+            // 64-bit integers, handles, and pointers.
+            _ => "Long",
+        }
+    }
+
+    /// The Rust type of a primitive value passed through JNI.
+    fn jni_sys_type(type_: &FfiType) -> &'static str {
+        match type_ {
+            FfiType::Int8 | FfiType::UInt8 => "jbyte",
+            FfiType::Int16 | FfiType::UInt16 => "jshort",
+            FfiType::Int32 | FfiType::UInt32 => "jint",
+            FfiType::Float32 => "jfloat",
+            FfiType::Float64 => "jdouble",
+            _ => "jlong",
+        }
+    }
+
+    /// The Rust type used by the UniFFI scaffolding.
+    fn jni_scaffolding_type(type_: &FfiType) -> &'static str {
+        match type_ {
+            FfiType::Int8 => "i8",
+            FfiType::UInt8 => "u8",
+            FfiType::Int16 => "i16",
+            FfiType::UInt16 => "u16",
+            FfiType::Int32 => "i32",
+            FfiType::UInt32 => "u32",
+            FfiType::Int64 => "i64",
+            FfiType::UInt64 | FfiType::Handle => "u64",
+            FfiType::Float32 => "f32",
+            FfiType::Float64 => "f64",
+            FfiType::RustBuffer(_) => "RustBuffer",
+            FfiType::ForeignBytes => "ForeignBytes",
+            // Pointers. Callbacks and structs are filtered out by `ffi_func_supported_jni`.
+            _ => "*const c_void",
+        }
+    }
+
+    /// Parameters of the `external fun` declared in `UniffiJniLib`.
+    pub fn jni_external_params(func: &FfiFunction) -> Result<Vec<String>, askama::Error> {
+        let mut params = vec![];
+        if ffi_returns_indirectly_jni(func)? {
+            params.push("uniffiReturnValue: LongArray".to_owned());
+        }
+        for arg in func.arguments() {
+            let nm = KotlinCodeOracle.var_name_raw(arg.name());
+            match arg.type_() {
+                FfiType::RustBuffer(_) => {
+                    params.push(format!("{nm}Capacity: Long"));
+                    params.push(format!("{nm}Len: Long"));
+                    params.push(format!("{nm}Data: Long"));
+                }
+                FfiType::ForeignBytes => {
+                    params.push(format!("{nm}Len: Int"));
+                    params.push(format!("{nm}Data: Long"));
+                }
+                type_ => params.push(format!("`{nm}`: {}", jni_kotlin_type(&type_))),
+            }
+        }
+        if func.has_rust_call_status_arg() {
+            params.push("uniffiCallStatus: LongArray".to_owned());
+        }
+        Ok(params)
+    }
+
+    pub fn jni_external_return_type(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            None | Some(FfiType::RustBuffer(_)) => "Unit",
+            Some(type_) => jni_kotlin_type(type_),
+        }
+        .to_owned())
+    }
+
+    /// Arguments passed from `UniffiLibInstance` to `UniffiJniLib`.
+    pub fn jni_call_args(func: &FfiFunction) -> Result<Vec<String>, askama::Error> {
+        let mut args = vec![];
+        if ffi_returns_indirectly_jni(func)? {
+            args.push("uniffiReturnValue".to_owned());
+        }
+        for arg in func.arguments() {
+            let nm = KotlinCodeOracle.var_name(arg.name());
+            match arg.type_() {
+                FfiType::RustBuffer(_) => {
+                    args.push(format!("{nm}.capacity"));
+                    args.push(format!("{nm}.len"));
+                    args.push(format!("{nm}.data ?: 0L"));
+                }
+                FfiType::ForeignBytes => {
+                    args.push(format!("{nm}.len"));
+                    args.push(format!("{nm}.data ?: 0L"));
+                }
+                FfiType::RustArcPtr(_) => args.push(format!("{nm} ?: 0L")),
+                _ => args.push(nm),
+            }
+        }
+        if func.has_rust_call_status_arg() {
+            args.push("uniffiCallStatus.raw".to_owned());
+        }
+        Ok(args)
+    }
+
+    pub fn jni_lift_return_value(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            Some(FfiType::RustArcPtr(_)) => ".takeIf { it != 0L }".to_owned(),
+            _ => String::new(),
+        })
+    }
+
+    /// Parameters of the `Java_...` function exported by the JNI shim.
+    pub fn jni_shim_params(func: &FfiFunction) -> Result<Vec<String>, askama::Error> {
+        let mut params = vec!["env: *mut JNIEnv".to_owned(), "_class: jclass".to_owned()];
+        if ffi_returns_indirectly_jni(func)? {
+            params.push("uniffi_return_value: jlongArray".to_owned());
+        }
+        for (idx, arg) in func.arguments().iter().enumerate() {
+            match arg.type_() {
+                FfiType::RustBuffer(_) => {
+                    params.push(format!("arg{idx}_capacity: jlong"));
+                    params.push(format!("arg{idx}_len: jlong"));
+                    params.push(format!("arg{idx}_data: jlong"));
+                }
+                FfiType::ForeignBytes => {
+                    params.push(format!("arg{idx}_len: jint"));
+                    params.push(format!("arg{idx}_data: jlong"));
+                }
+                type_ => params.push(format!("arg{idx}: {}", jni_sys_type(&type_))),
+            }
+        }
+        if func.has_rust_call_status_arg() {
+            params.push("uniffi_call_status: jlongArray".to_owned());
+        }
+        Ok(params)
+    }
+
+    pub fn jni_shim_return_type(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            None | Some(FfiType::RustBuffer(_)) => String::new(),
+            Some(type_) => format!(" -> {}", jni_sys_type(type_)),
+        })
+    }
+
+    /// Arguments passed from the JNI shim to the scaffolding function.
+    pub fn jni_shim_call_args(func: &FfiFunction) -> Result<Vec<String>, askama::Error> {
+        let mut args = vec![];
+        for (idx, arg) in func.arguments().iter().enumerate() {
+            args.push(match arg.type_() {
+                FfiType::RustBuffer(_) => {
+                    format!("RustBuffer::from_jni(arg{idx}_capacity, arg{idx}_len, arg{idx}_data)")
+                }
+                FfiType::ForeignBytes => format!(
+                    "ForeignBytes {{ len: arg{idx}_len, data: arg{idx}_data as usize as *const u8 }}"
+                ),
+                FfiType::Float32 | FfiType::Float64 => format!("arg{idx}"),
+                type_ => format!("arg{idx} as {}", jni_scaffolding_type(&type_)),
+            });
+        }
+        if func.has_rust_call_status_arg() {
+            args.push("&mut uniffi_status".to_owned());
+        }
+        Ok(args)
+    }
+
+    /// Convert the value returned by the scaffolding function to a JNI value.
+    pub fn jni_shim_return_conversion(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            None | Some(FfiType::Float32 | FfiType::Float64) => String::new(),
+            Some(FfiType::RustArcPtr(_) | FfiType::VoidPointer) => " as usize as jlong".to_owned(),
+            Some(type_) => format!(" as {}", jni_sys_type(type_)),
+        })
+    }
+
+    pub fn jni_shim_extern_params(func: &FfiFunction) -> Result<Vec<String>, askama::Error> {
+        let mut params = func
+            .arguments()
+            .iter()
+            .enumerate()
+            .map(|(idx, arg)| format!("arg{idx}: {}", jni_scaffolding_type(&arg.type_())))
+            .collect::<Vec<_>>();
+        if func.has_rust_call_status_arg() {
+            params.push("uniffi_call_status: &mut RustCallStatus".to_owned());
+        }
+        Ok(params)
+    }
+
+    pub fn jni_shim_extern_return_type(func: &FfiFunction) -> Result<String, askama::Error> {
+        Ok(match func.return_type() {
+            Some(type_) => format!(" -> {}", jni_scaffolding_type(type_)),
+            None => String::new(),
+        })
+    }
+
+    /// The `MemoryLayout` of an FFI type used by the Java FFM API.
+    pub fn ffm_layout(type_: &FfiType) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            FfiType::Int8 | FfiType::UInt8 => "ValueLayout.JAVA_BYTE".to_owned(),
+            FfiType::Int16 | FfiType::UInt16 => "ValueLayout.JAVA_SHORT".to_owned(),
+            FfiType::Int32 | FfiType::UInt32 => "ValueLayout.JAVA_INT".to_owned(),
+            FfiType::Int64 | FfiType::UInt64 | FfiType::Handle => {
+                "ValueLayout.JAVA_LONG".to_owned()
+            }
+            FfiType::Float32 => "ValueLayout.JAVA_FLOAT".to_owned(),
+            FfiType::Float64 => "ValueLayout.JAVA_DOUBLE".to_owned(),
+            FfiType::RustBuffer(_) => "UNIFFI_RUST_BUFFER_LAYOUT".to_owned(),
+            FfiType::ForeignBytes => "UNIFFI_FOREIGN_BYTES_LAYOUT".to_owned(),
+            FfiType::RustCallStatus => "UNIFFI_RUST_CALL_STATUS_LAYOUT".to_owned(),
+            FfiType::Struct(name) => {
+                format!("{}Struct.LAYOUT", KotlinCodeOracle.ffi_struct_name(name))
+            }
+            // Pointers, callbacks, and references.
+            _ => "ValueLayout.ADDRESS".to_owned(),
+        })
+    }
+
+    fn ffm_is_primitive(type_: &FfiType) -> bool {
+        matches!(
+            type_,
+            FfiType::Int8
+                | FfiType::UInt8
+                | FfiType::Int16
+                | FfiType::UInt16
+                | FfiType::Int32
+                | FfiType::UInt32
+                | FfiType::Int64
+                | FfiType::UInt64
+                | FfiType::Handle
+                | FfiType::Float32
+                | FfiType::Float64
+        )
+    }
+
+    /// Whether the FFI type is a struct passed by value.
+    fn ffm_is_struct(type_: &FfiType) -> bool {
+        matches!(
+            type_,
+            FfiType::RustBuffer(_)
+                | FfiType::ForeignBytes
+                | FfiType::RustCallStatus
+                | FfiType::Struct(_)
+        )
+    }
+
+    /// The Kotlin type of the values passed to or returned from method handles.
+    pub fn ffm_carrier(type_: &FfiType) -> Result<String, askama::Error> {
+        Ok(if ffm_is_primitive(type_) {
+            jni_kotlin_type(type_).to_owned()
+        } else {
+            "MemorySegment".to_owned()
+        })
+    }
+
+    /// The `Class` of the values passed to or returned from method handles.
+    pub fn ffm_carrier_class(type_: &FfiType) -> Result<String, askama::Error> {
+        if !ffm_is_primitive(type_) {
+            return Ok("MemorySegment::class.java".to_owned());
+        }
+        let carrier = ffm_carrier(type_)?;
+        let boxed = match carrier.as_str() {
+            "Int" => "Integer",
+            carrier => carrier,
+        };
+        Ok(format!("java.lang.{boxed}.TYPE"))
+    }
+
+    fn ffm_descriptor(
+        arguments: &[&FfiArgument],
+        has_rust_call_status_arg: bool,
+        return_type: Option<&FfiType>,
+    ) -> Result<String, askama::Error> {
+        let mut layouts = arguments
+            .iter()
+            .map(|arg| ffm_layout(&arg.type_()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if has_rust_call_status_arg {
+            layouts.push("ValueLayout.ADDRESS".to_owned());
+        }
+        Ok(match return_type {
+            Some(return_type) => {
+                layouts.insert(0, ffm_layout(return_type)?);
+                format!("FunctionDescriptor.of({})", layouts.join(", "))
+            }
+            None => format!("FunctionDescriptor.ofVoid({})", layouts.join(", ")),
+        })
+    }
+
+    pub fn ffm_function_descriptor(func: &FfiFunction) -> Result<String, askama::Error> {
+        ffm_descriptor(
+            &func.arguments(),
+            func.has_rust_call_status_arg(),
+            func.return_type(),
+        )
+    }
+
+    pub fn ffm_callback_descriptor(
+        callback: &FfiCallbackFunction,
+    ) -> Result<String, askama::Error> {
+        ffm_descriptor(
+            &callback.arguments(),
+            callback.has_rust_call_status_arg(),
+            callback.return_type(),
+        )
+    }
+
+    /// The `MethodType` of the method implementing a callback in its upcall adapter.
+    pub fn ffm_callback_method_type(
+        callback: &FfiCallbackFunction,
+    ) -> Result<String, askama::Error> {
+        let mut classes = vec![match callback.return_type() {
+            Some(return_type) => ffm_carrier_class(return_type)?,
+            None => "java.lang.Void.TYPE".to_owned(),
+        }];
+        for arg in callback.arguments() {
+            classes.push(ffm_carrier_class(&arg.type_())?);
+        }
+        if callback.has_rust_call_status_arg() {
+            classes.push("MemorySegment::class.java".to_owned());
+        }
+        Ok(format!("MethodType.methodType({})", classes.join(", ")))
+    }
+
+    /// Whether a method handle returning `return_type` needs an arena to allocate the result in.
+    pub fn ffm_returns_struct(return_type: Option<&FfiType>) -> Result<bool, askama::Error> {
+        Ok(return_type.is_some_and(ffm_is_struct))
+    }
+
+    /// Whether `ffi_struct` is the vtable of a callback interface or a foreign trait. Rust keeps
+    /// the pointers to vtables, so they must stay in the native memory.
+    pub fn ffm_is_vtable(
+        ffi_struct: &FfiStruct,
+        ci: &ComponentInterface,
+    ) -> Result<bool, askama::Error> {
+        let vtable = FfiType::Struct(ffi_struct.name().to_owned());
+        Ok(ci
+            .callback_interface_definitions()
+            .iter()
+            .map(|cbi| cbi.vtable())
+            .chain(
+                ci.object_definitions()
+                    .iter()
+                    .filter_map(|obj| obj.vtable()),
+            )
+            .any(|t| t == vtable))
+    }
+
+    /// Convert a Kotlin FFI value to a value passed to a method handle.
+    pub fn ffm_lower(type_: &FfiType, nm: &str) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            _ if ffm_is_primitive(type_) => nm.to_owned(),
+            FfiType::RustArcPtr(_) => format!("({nm} ?: MemorySegment.NULL)"),
+            FfiType::VoidPointer => nm.to_owned(),
+            FfiType::Callback(_) => format!("{nm}.uniffiUpcallStub()"),
+            // Structs and references are backed by memory segments.
+            _ => format!("{nm}.segment"),
+        })
+    }
+
+    /// Convert a value returned from a downcall method handle to a Kotlin FFI value.
+    pub fn ffm_lift(
+        type_: &FfiType,
+        nm: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            _ if ffm_is_primitive(type_) => nm.to_owned(),
+            FfiType::RustArcPtr(_) => format!("{nm}.takeUnless {{ it.address() == 0L }}"),
+            FfiType::Callback(name) => {
+                format!("{}Downcall({nm})", KotlinCodeOracle.ffi_callback_name(name))
+            }
+            _ if ffm_is_struct(type_) => format!(
+                "{}({nm}){}",
+                KotlinCodeOracle.ffi_type_label_by_value(&strip_external(type_), ci),
+                ffi_cast_to_external_rust_buffer_if_needed(type_, ci)?,
+            ),
+            FfiType::Reference(inner) | FfiType::MutReference(inner) => format!(
+                "{}({nm}.reinterpret({}.byteSize()))",
+                KotlinCodeOracle.ffi_type_label_by_reference(inner, ci),
+                ffm_layout(inner)?,
+            ),
+            _ => nm.to_owned(),
+        })
+    }
+
+    /// Convert a value only valid during a call, i.e., passed to an upcall stub or returned into
+    /// the arena of a downcall, to a Kotlin FFI value. Structs are copied out of the native memory.
+    pub fn ffm_lift_copied(
+        type_: &FfiType,
+        nm: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        if ffm_is_struct(type_) {
+            return ffm_lift(
+                type_,
+                &format!("uniffiCopy({nm}, {})", ffm_layout(type_)?),
+                ci,
+            );
+        }
+        ffm_lift(type_, nm, ci)
+    }
+
+    /// Read a field of an FFI struct from `segment`.
+    pub fn ffm_get_field(
+        type_: &FfiType,
+        offset: &str,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        let layout = ffm_layout(type_)?;
+        Ok(match type_ {
+            FfiType::Callback(_) => format!(
+                "segment.get({layout}, {offset}).takeUnless {{ it.address() == 0L }}?.let {{ {} }}",
+                ffm_lift(type_, "it", ci)?,
+            ),
+            _ if ffm_is_struct(type_) => ffm_lift(
+                type_,
+                &format!("uniffiCopy(segment.asSlice({offset}, {layout}.byteSize()), {layout})"),
+                ci,
+            )?,
+            _ => ffm_lift(type_, &format!("segment.get({layout}, {offset})"), ci)?,
+        })
+    }
+
+    /// Write `value` to a field of an FFI struct in `segment`.
+    pub fn ffm_set_field(type_: &FfiType, offset: &str) -> Result<String, askama::Error> {
+        let layout = ffm_layout(type_)?;
+        Ok(match type_ {
+            FfiType::Callback(_) => format!(
+                "segment.set({layout}, {offset}, value?.uniffiUpcallStub() ?: MemorySegment.NULL)"
+            ),
+            _ if ffm_is_struct(type_) => format!(
+                "MemorySegment.copy(value.segment, 0L, segment, {offset}, {layout}.byteSize())"
+            ),
+            _ => format!(
+                "segment.set({layout}, {offset}, {})",
+                ffm_lower(type_, "value")?
+            ),
+        })
+    }
+
+    /// Local `RustBuffer`s are constructed from memory segments, and then cast to external ones.
+    fn strip_external(type_: &FfiType) -> FfiType {
+        match type_ {
+            FfiType::RustBuffer(_) => FfiType::RustBuffer(None),
+            _ => type_.clone(),
+        }
+    }
+
     /// Append a `_` if the name is a valid c/c++ keyword
     pub fn header_escape_name(nm: &str) -> Result<String, askama::Error> {
         if CPP_KEYWORDS.contains(&nm) {
@@ -1287,17 +2873,143 @@ mod filters {
         Ok(KotlinCodeOracle.ffi_default_value(&type_))
     }
 
-    /// Get the idiomatic Kotlin rendering of a function name.
-    pub fn class_name<S: AsRef<str>>(
+    /// Get the idiomatic Kotlin rendering of the class name of the external type `nm` defined in
+    /// `module_path`, applying the `rename` of the crate defining it.
+    pub fn external_class_name<S: AsRef<str>>(
         nm: S,
+        module_path: &str,
         ci: &ComponentInterface,
+        config: &Config,
     ) -> Result<String, askama::Error> {
-        Ok(KotlinCodeOracle.class_name(ci, nm.as_ref()))
+        Ok(KotlinCodeOracle.type_class_name(ci, config, module_path, nm.as_ref()))
     }
 
-    /// Get the idiomatic Kotlin rendering of a function name.
-    pub fn fn_name<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(KotlinCodeOracle.fn_name(nm.as_ref()))
+    /// Get the Kotlin rendering of a function or a method name, applying `rename`. `owner` is the
+    /// name of the object or the callback interface in Rust, or empty for top-level functions.
+    pub fn callable_name<S: AsRef<str>, O: AsRef<str>>(
+        nm: S,
+        owner: O,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(KotlinCodeOracle.callable_name(owner.as_ref(), nm.as_ref(), config))
+    }
+
+    /// Get the name of a function generated next to a function or a method, such as the suspend
+    /// wrappers, which is its Kotlin name with `suffix` appended.
+    pub fn suffixed_callable_name<S: AsRef<str>, O: AsRef<str>, X: AsRef<str>>(
+        nm: S,
+        owner: O,
+        suffix: X,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(KotlinCodeOracle.suffixed_callable_name(
+            owner.as_ref(),
+            nm.as_ref(),
+            suffix.as_ref(),
+            config,
+        ))
+    }
+
+    /// Whether any argument of `callable` has a default value.
+    pub fn has_default_arguments(callable: impl Callable) -> Result<bool, askama::Error> {
+        Ok(callable
+            .arguments()
+            .iter()
+            .any(|arg| arg.default_value().is_some()))
+    }
+
+    /// Whether any field of `rec` has a default value.
+    pub fn has_default_fields(rec: &Record) -> Result<bool, askama::Error> {
+        Ok(rec
+            .fields()
+            .iter()
+            .any(|field| field.default_value().is_some()))
+    }
+
+    /// The type of a record field, which is an `ImmutableList` or an `ImmutableMap` instead of a
+    /// `List` or a `Map` with `compose_immutable_collections`.
+    pub fn record_field_type_name(
+        field: &Field,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(match immutable_collection(&field.as_type(), config) {
+            Some(ImmutableCollection::List(inner_type)) => format!(
+                "kotlinx.collections.immutable.ImmutableList<{}>",
+                inner_type.as_codetype().type_label(ci, config)
+            ),
+            Some(ImmutableCollection::Map(key_type, value_type)) => format!(
+                "kotlinx.collections.immutable.ImmutableMap<{}, {}>",
+                key_type.as_codetype().type_label(ci, config),
+                value_type.as_codetype().type_label(ci, config)
+            ),
+            None => type_name(field, ci, config)?,
+        })
+    }
+
+    /// Renders the default value of a record field, matching [record_field_type_name].
+    pub fn render_record_field_literal(
+        literal: &Literal,
+        field: &Field,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(
+            match (immutable_collection(&field.as_type(), config), literal) {
+                (Some(ImmutableCollection::List(_)), Literal::EmptySequence) => {
+                    "kotlinx.collections.immutable.persistentListOf()".into()
+                }
+                (Some(ImmutableCollection::Map(..)), Literal::EmptyMap) => {
+                    "kotlinx.collections.immutable.persistentMapOf()".into()
+                }
+                _ => render_literal(literal, field, ci, config)?,
+            },
+        )
+    }
+
+    /// The call converting the value read by the `FfiConverter` of a record field to the type
+    /// returned by [record_field_type_name].
+    pub fn record_field_conversion(
+        field: &Field,
+        config: &Config,
+    ) -> Result<&'static str, askama::Error> {
+        Ok(match immutable_collection(&field.as_type(), config) {
+            Some(ImmutableCollection::List(_)) => ".toImmutableList()",
+            Some(ImmutableCollection::Map(..)) => ".toImmutableMap()",
+            None => "",
+        })
+    }
+
+    enum ImmutableCollection<'a> {
+        List(&'a Type),
+        Map(&'a Type, &'a Type),
+    }
+
+    fn immutable_collection<'a>(
+        type_: &'a Type,
+        config: &Config,
+    ) -> Option<ImmutableCollection<'a>> {
+        if !config.compose_immutable_collections() {
+            return None;
+        }
+        match type_ {
+            Type::Sequence { inner_type } => Some(ImmutableCollection::List(inner_type)),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Some(ImmutableCollection::Map(key_type, value_type)),
+            _ => None,
+        }
+    }
+
+    /// Get the Kotlin rendering of a field name, applying `rename`. `owner` is the name of the
+    /// record, or the path of the enum variant like `Enum::Variant`.
+    pub fn field_var_name<S: AsRef<str>, O: AsRef<str>>(
+        nm: S,
+        owner: O,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(KotlinCodeOracle.field_name(owner.as_ref(), nm.as_ref(), config))
     }
 
     /// Get the idiomatic Kotlin rendering of a variable name.
@@ -1311,11 +3023,22 @@ mod filters {
     }
 
     /// Get a String representing the name used for an individual enum variant.
-    pub fn variant_name(v: &Variant, config: &Config) -> Result<String, askama::Error> {
-        Ok(KotlinCodeOracle.enum_variant_name(v.name(), config))
+    pub fn variant_name<S: AsRef<str>>(
+        v: &Variant,
+        owner: S,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(KotlinCodeOracle.enum_variant_name(owner.as_ref(), v.name(), config))
     }
 
-    pub fn error_variant_name(v: &Variant) -> Result<String, askama::Error> {
+    pub fn error_variant_name<S: AsRef<str>>(
+        v: &Variant,
+        owner: S,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        if let Some(name) = config.renamed(&format!("{}::{}", owner.as_ref(), v.name())) {
+            return Ok(name.to_owned());
+        }
         let name = v.name().to_string().to_upper_camel_case();
         Ok(KotlinCodeOracle.convert_error_suffix(&name))
     }
@@ -1351,9 +3074,10 @@ mod filters {
             Some(return_type) if ci.is_external(return_type) => {
                 let ffi_type = FfiType::from(return_type);
                 match ffi_type {
+                    // The local alias of the external `RustBuffer` is declared by
+                    // `ExternalTypeTemplate.kt` with the name of the type in Rust.
                     FfiType::RustBuffer(Some(ExternalFfiMetadata { name, .. })) => {
-                        let suffix = KotlinCodeOracle.class_name(ci, &name);
-                        format!("{call}.let {{ RustBuffer{suffix}ByValue(it.capacity, it.len, it.data) }}")
+                        format!("{call}.let {{ RustBuffer{name}ByValue(it.capacity, it.len, it.data) }}")
                     }
                     _ => call,
                 }
@@ -1406,3 +3130,68 @@ mod filters {
         Ok(string.repeat(n))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_sources_without_markers() {
+        let content = "package com.example\n\nfun main() {}\n";
+        let (main, types) = split_sources(content);
+        assert_eq!(main, content);
+        assert!(types.is_empty());
+    }
+
+    #[test]
+    fn split_sources_by_type() {
+        let content = "\
+@file:Suppress(\"NAME_SHADOWING\")
+package com.example
+
+import kotlinx.coroutines.Dispatchers
+
+fun main() {}
+// uniffi-split-output: Counter
+class Counter
+// uniffi-split-output: UniffiHelpers
+object RustBufferHelper
+// uniffi-split-output: 
+fun afterTypes() {}
+// uniffi-split-output: Counter
+fun Counter.extension() {}
+import not.a.Header
+";
+        let (main, types) = split_sources(content);
+        let header = "\
+@file:Suppress(\"NAME_SHADOWING\")
+package com.example
+import kotlinx.coroutines.Dispatchers
+";
+        assert_eq!(
+            main,
+            "\
+@file:Suppress(\"NAME_SHADOWING\")
+package com.example
+
+import kotlinx.coroutines.Dispatchers
+
+fun main() {}
+fun afterTypes() {}
+"
+        );
+        assert_eq!(
+            types,
+            [
+                (
+                    "Counter".to_owned(),
+                    format!("{header}class Counter\nfun Counter.extension() {{}}\nimport not.a.Header\n"),
+                ),
+                (
+                    SPLIT_OUTPUT_HELPERS.to_owned(),
+                    format!("{header}object RustBufferHelper\n"),
+                ),
+            ]
+        );
+    }
+}
@@ -6,23 +6,28 @@
 
 use uniffi_bindgen::{interface::ObjectImpl, ComponentInterface};
 
-use super::CodeType;
+use super::{CodeType, Config};
 
 #[derive(Debug)]
 pub struct ObjectCodeType {
     name: String,
+    module_path: String,
     imp: ObjectImpl,
 }
 
 impl ObjectCodeType {
-    pub fn new(name: String, imp: ObjectImpl) -> Self {
-        Self { name, imp }
+    pub fn new(name: String, module_path: String, imp: ObjectImpl) -> Self {
+        Self {
+            name,
+            module_path,
+            imp,
+        }
     }
 }
 
 impl CodeType for ObjectCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::KotlinCodeOracle.class_name(ci, &self.name)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::KotlinCodeOracle.type_class_name(ci, config, &self.module_path, &self.name)
     }
 
     fn canonical_name(&self) -> String {
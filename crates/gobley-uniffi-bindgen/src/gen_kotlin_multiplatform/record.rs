@@ -6,22 +6,23 @@
 
 use uniffi_bindgen::ComponentInterface;
 
-use super::CodeType;
+use super::{CodeType, Config};
 
 #[derive(Debug)]
 pub struct RecordCodeType {
     id: String,
+    module_path: String,
 }
 
 impl RecordCodeType {
-    pub fn new(id: String) -> Self {
-        Self { id }
+    pub fn new(id: String, module_path: String) -> Self {
+        Self { id, module_path }
     }
 }
 
 impl CodeType for RecordCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::KotlinCodeOracle.class_name(ci, &self.id)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::KotlinCodeOracle.type_class_name(ci, config, &self.module_path, &self.id)
     }
 
     fn canonical_name(&self) -> String {
@@ -0,0 +1,105 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use anyhow::{Context, Result};
+use askama::Template;
+
+use super::{Config, ConfigKotlinTarget, JvmBackend};
+
+/// The helpers shared by the components with the same `runtime_package`.
+pub struct SharedRuntime {
+    pub common: String,
+    pub jvm_common: Option<String>,
+    pub jvm: Option<String>,
+    pub android: Option<String>,
+    pub native: Option<String>,
+}
+
+impl SharedRuntime {
+    /// Render the shared runtime for the targets of `config`. Returns `None` if `config` has no
+    /// `runtime_package`.
+    pub fn generate(config: &Config) -> Result<Option<Self>> {
+        let Some(package_name) = config.runtime_package() else {
+            return Ok(None);
+        };
+        // The helpers check `runtime_package` to skip the shared declarations, so render them as
+        // if they were not shared.
+        let mut helper_config = config.clone();
+        helper_config.runtime_package = None;
+
+        let targets = config.kotlin_targets();
+        // Kotlin/JVM and Android share the code only when both use JNA, as in `generate_bindings`.
+        let jvm_common_source_set = config
+            .jvm_common_source_set()
+            .filter(|_| config.jvm_backend == JvmBackend::Jna);
+        let render_platform = |module_name: &str| {
+            PlatformRuntime {
+                module_name,
+                package_name,
+                jna: config.uses_jna(module_name),
+                config: &helper_config,
+            }
+            .render()
+            .with_context(|| format!("failed to render the {module_name} runtime"))
+        };
+        let platform = |target: ConfigKotlinTarget, module_name: &str| {
+            // The Kotlin/JVM and Android runtimes are identical, so they are generated once in
+            // the shared source set if there is one.
+            let is_jvm = matches!(
+                target,
+                ConfigKotlinTarget::Jvm | ConfigKotlinTarget::Android
+            );
+            (targets.contains(&target) && !(is_jvm && jvm_common_source_set.is_some()))
+                .then(|| render_platform(module_name))
+                .transpose()
+        };
+
+        Ok(Some(Self {
+            common: CommonRuntime {
+                package_name,
+                config: &helper_config,
+            }
+            .render()
+            .context("failed to render the common runtime")?,
+            jvm_common: jvm_common_source_set
+                .map(|_| render_platform("jvmCommon"))
+                .transpose()?,
+            jvm: platform(ConfigKotlinTarget::Jvm, "jvm")?,
+            android: platform(ConfigKotlinTarget::Android, "android")?,
+            native: platform(ConfigKotlinTarget::Native, "native")?,
+        }))
+    }
+}
+
+#[derive(Template)]
+#[template(syntax = "kt", escape = "none", path = "runtime/common.kt")]
+struct CommonRuntime<'a> {
+    package_name: &'a str,
+    config: &'a Config,
+}
+
+impl CommonRuntime<'_> {
+    fn visibility(&self) -> &str {
+        "public "
+    }
+}
+
+#[derive(Template)]
+#[template(syntax = "kt", escape = "none", path = "runtime/platform.kt")]
+struct PlatformRuntime<'a> {
+    module_name: &'a str,
+    package_name: &'a str,
+    /// Whether the bindings for `module_name` use JNA, whose `RustBuffer` structures are shared
+    /// as well.
+    jna: bool,
+    config: &'a Config,
+}
+
+impl PlatformRuntime<'_> {
+    fn visibility(&self) -> &str {
+        "public "
+    }
+}
@@ -0,0 +1,695 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use anyhow::{anyhow, bail, Result};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use uniffi_bindgen::interface::{AsType, ComponentInterface, Type};
+
+use super::filter::{callable_types, glob_match, item_name};
+use super::{
+    dispatcher_config, Config, ConfigKotlinTarget, CustomTypeConfig, CustomTypePlatformConfig,
+    DispatcherConfig, KotlinCodeOracle, Visibility, DISPATCHER_KEYWORDS,
+};
+
+impl Config {
+    /// Reject the keys in `[bindings.kotlin]` that are not known options, since serde silently
+    /// ignores them and a typo would otherwise go unnoticed.
+    pub(crate) fn check_keys(toml: &toml::Value) -> Result<()> {
+        let mut errors = Vec::new();
+        check_table_keys::<Config>(toml, "bindings.kotlin", &mut errors);
+        if let Some(custom_types) = toml.get("custom_types").and_then(toml::Value::as_table) {
+            for (name, custom_type) in custom_types {
+                let table_name = format!("bindings.kotlin.custom_types.{name}");
+                check_table_keys::<CustomTypeConfig>(custom_type, &table_name, &mut errors);
+                for platform in ["jvm", "android", "native"] {
+                    if let Some(platform_config) = custom_type.get(platform) {
+                        check_table_keys::<CustomTypePlatformConfig>(
+                            platform_config,
+                            &format!("{table_name}.{platform}"),
+                            &mut errors,
+                        );
+                    }
+                }
+            }
+        }
+        if !errors.is_empty() {
+            bail!("invalid configuration:\n{}", errors.join("\n"));
+        }
+        Ok(())
+    }
+
+    /// Check the values that deserialize fine but would generate code that does not compile.
+    pub(crate) fn validate(&self) -> Result<()> {
+        let mut errors = Vec::new();
+        let mut custom_types = self.custom_types.iter().collect::<Vec<_>>();
+        custom_types.sort_by_key(|(name, _)| name.as_str());
+        for (name, custom_type) in custom_types {
+            if custom_type.value_class {
+                let conflicts = [
+                    ("type_name", custom_type.type_name.is_some()),
+                    ("lift", !custom_type.lift.is_empty()),
+                    ("into_custom", !custom_type.into_custom.is_empty()),
+                    ("lower", !custom_type.lower.is_empty()),
+                    ("from_custom", !custom_type.from_custom.is_empty()),
+                    ("jvm", custom_type.jvm.is_some()),
+                    ("android", custom_type.android.is_some()),
+                    ("native", custom_type.native.is_some()),
+                ];
+                for (key, _) in conflicts.into_iter().filter(|(_, is_set)| *is_set) {
+                    errors.push(format!(
+                        "`bindings.kotlin.custom_types.{name}.{key}` cannot be used together with \
+                         `value_class`, which generates the type and its converters"
+                    ));
+                }
+                continue;
+            }
+            let mut converters = [custom_type.lift_converter(), custom_type.lower_converter()]
+                .into_iter()
+                .map(|(key, converter)| (key.to_owned(), converter))
+                .collect::<Vec<_>>();
+            for (platform, platform_config) in custom_type.platforms() {
+                let Some(platform_config) = platform_config else {
+                    continue;
+                };
+                converters.extend(
+                    [
+                        ("lift", &platform_config.lift),
+                        ("lower", &platform_config.lower),
+                    ]
+                    .into_iter()
+                    .filter_map(|(key, converter)| {
+                        Some((format!("{platform}.{key}"), converter.as_deref()?))
+                    }),
+                );
+            }
+            for (key, converter) in converters {
+                if !converter.contains("{}") {
+                    errors.push(format!(
+                        "`bindings.kotlin.custom_types.{name}.{key}` must contain `{{}}`, \
+                         which is replaced with the value to convert (got `{converter}`)"
+                    ));
+                }
+            }
+            if self.jvm_common_source_set().is_some() && custom_type.jvm != custom_type.android {
+                errors.push(format!(
+                    "`bindings.kotlin.custom_types.{name}.jvm` and \
+                     `bindings.kotlin.custom_types.{name}.android` must be the same when \
+                     `jvm_common_source_set` is set, since the two platforms share the converter"
+                ));
+            }
+        }
+        errors.extend(check_dispatcher(
+            "async_dispatcher",
+            self.async_dispatcher.as_deref(),
+        ));
+        errors.extend(check_dispatcher(
+            "blocking_dispatcher",
+            self.blocking_dispatcher.as_deref(),
+        ));
+        if !errors.is_empty() {
+            bail!("invalid configuration:\n{}", errors.join("\n"));
+        }
+        Ok(())
+    }
+
+    /// Warn about the `custom_types` entries that don't match any custom type in `ci`.
+    pub(crate) fn warn_unused_custom_types(&self, ci: &ComponentInterface) {
+        let mut names = self.custom_types.keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let type_ = ci.iter_local_types().find(|type_| match type_ {
+                Type::Custom { name: n, .. }
+                | Type::Record { name: n, .. }
+                | Type::Enum { name: n, .. }
+                | Type::Object { name: n, .. }
+                | Type::CallbackInterface { name: n, .. } => n == name,
+                _ => false,
+            });
+            match type_ {
+                Some(Type::Custom { .. }) => {}
+                Some(_) => println!(
+                    "Warning: `bindings.kotlin.custom_types.{name}` is ignored since `{name}` \
+                     is not a custom type in `{}`",
+                    ci.namespace()
+                ),
+                None => {
+                    let candidates = ci.iter_local_types().filter_map(|type_| match type_ {
+                        Type::Custom { name, .. } => Some(name.as_str()),
+                        _ => None,
+                    });
+                    println!(
+                        "Warning: `bindings.kotlin.custom_types.{name}` does not match any custom \
+                         type in `{}`{}",
+                        ci.namespace(),
+                        suggestion(name, candidates)
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Warn about the `rename` entries that don't match any item in `ci`.
+    pub(crate) fn warn_unused_renames(&self, ci: &ComponentInterface) {
+        if self.rename.is_empty() {
+            return;
+        }
+        let mut paths = ci
+            .function_definitions()
+            .iter()
+            .map(|func| func.name().to_owned())
+            .collect::<HashSet<_>>();
+        for type_ in ci.iter_local_types() {
+            match type_ {
+                Type::Record { name, .. } => {
+                    paths.insert(name.clone());
+                    let Some(rec) = ci.get_record_definition(name) else {
+                        continue;
+                    };
+                    paths.extend(
+                        rec.fields()
+                            .iter()
+                            .map(|field| format!("{name}.{}", field.name())),
+                    );
+                }
+                Type::Enum { name, .. } => {
+                    paths.insert(name.clone());
+                    let Some(e) = ci.get_enum_definition(name) else {
+                        continue;
+                    };
+                    for variant in e.variants() {
+                        let variant_path = format!("{name}::{}", variant.name());
+                        paths.extend(
+                            variant
+                                .fields()
+                                .iter()
+                                .map(|field| format!("{variant_path}.{}", field.name())),
+                        );
+                        paths.insert(variant_path);
+                    }
+                }
+                Type::Object { name, .. } => {
+                    paths.insert(name.clone());
+                    let Some(obj) = ci.get_object_definition(name) else {
+                        continue;
+                    };
+                    paths.extend(
+                        obj.alternate_constructors()
+                            .iter()
+                            .map(|cons| format!("{name}.{}", cons.name())),
+                    );
+                    paths.extend(
+                        obj.methods()
+                            .iter()
+                            .map(|meth| format!("{name}.{}", meth.name())),
+                    );
+                }
+                Type::CallbackInterface { name, .. } => {
+                    paths.insert(name.clone());
+                    let Some(cbi) = ci.get_callback_interface_definition(name) else {
+                        continue;
+                    };
+                    paths.extend(
+                        cbi.methods()
+                            .iter()
+                            .map(|meth| format!("{name}.{}", meth.name())),
+                    );
+                }
+                Type::Custom { name, .. } => {
+                    paths.insert(name.clone());
+                }
+                _ => {}
+            }
+        }
+
+        let mut renamed = self.rename.keys().collect::<Vec<_>>();
+        renamed.sort();
+        for path in renamed {
+            if !paths.contains(path) {
+                println!(
+                    "Warning: `bindings.kotlin.rename.{path}` does not match any item in `{}`{}",
+                    ci.namespace(),
+                    suggestion(path, paths.iter().map(String::as_str))
+                );
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Warn about the `async_iterators` patterns that don't match any object with a method that
+    /// can be turned into a `Flow`.
+    pub(crate) fn warn_unused_async_iterators(&self, ci: &ComponentInterface) {
+        for pattern in &self.async_iterators {
+            if !ci
+                .object_definitions()
+                .iter()
+                .any(|obj| self.async_iterator_matching(pattern, obj).is_some())
+            {
+                println!(
+                    "Warning: `bindings.kotlin.async_iterators` pattern `{pattern}` does not match \
+                     any object in `{}` with an async method that takes no arguments and returns \
+                     an `Option` of a non-optional type",
+                    ci.namespace()
+                );
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Warn about the `suspend_wrappers` patterns that don't match any synchronous function or
+    /// method.
+    pub(crate) fn warn_unused_suspend_wrappers(&self, ci: &ComponentInterface) {
+        let mut paths = ci
+            .function_definitions()
+            .iter()
+            .filter(|func| !func.is_async())
+            .map(|func| func.name().to_owned())
+            .collect::<Vec<_>>();
+        for obj in ci.object_definitions() {
+            paths.extend(
+                obj.methods()
+                    .iter()
+                    .filter(|meth| !meth.is_async())
+                    .map(|meth| format!("{}.{}", obj.name(), meth.name())),
+            );
+        }
+        for pattern in &self.suspend_wrappers {
+            if !paths.iter().any(|path| glob_match(pattern, path)) {
+                println!(
+                    "Warning: `bindings.kotlin.suspend_wrappers` pattern `{pattern}` does not match \
+                     any synchronous function or method in `{}`{}",
+                    ci.namespace(),
+                    suggestion(pattern, paths.iter().map(String::as_str))
+                );
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Fail when the components sharing a `runtime_package` use different JVM backends, Kotlin
+    /// targets, or `jvm_common_source_set`s, since the shared runtime is rendered once for all of
+    /// them and would miss the files needed by some of the components.
+    pub(crate) fn check_shared_runtimes<'a>(
+        configs: impl IntoIterator<Item = &'a Config>,
+    ) -> Result<()> {
+        let mut first_configs = HashMap::<&str, &Config>::new();
+        for config in configs {
+            let Some(runtime_package) = config.runtime_package() else {
+                continue;
+            };
+            let first = *first_configs.entry(runtime_package).or_insert(config);
+            let mismatch = |option: &str, value: String, first_value: String| {
+                anyhow!(
+                    "`{}` and `{}` share `runtime_package = \"{runtime_package}\"` but use \
+                     different `{option}`s ({value} and {first_value}). Use the same \
+                     `{option}`, or a different `runtime_package` for each value.",
+                    config.package_name(),
+                    first.package_name(),
+                )
+            };
+            if config.jvm_backend != first.jvm_backend {
+                return Err(mismatch(
+                    "jvm_backend",
+                    format!("{:?}", config.jvm_backend),
+                    format!("{:?}", first.jvm_backend),
+                ));
+            }
+            let targets = config.kotlin_targets().into_iter().collect::<BTreeSet<_>>();
+            let first_targets = first.kotlin_targets().into_iter().collect::<BTreeSet<_>>();
+            if targets != first_targets {
+                return Err(mismatch(
+                    "kotlin_targets",
+                    format!("{targets:?}"),
+                    format!("{first_targets:?}"),
+                ));
+            }
+            if config.jvm_common_source_set() != first.jvm_common_source_set() {
+                return Err(mismatch(
+                    "jvm_common_source_set",
+                    format!("{:?}", config.jvm_common_source_set()),
+                    format!("{:?}", first.jvm_common_source_set()),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Warn that the Kotlin/JS and Kotlin/Wasm bindings don't use `runtime_package` for the
+    /// platform-specific helpers.
+    pub(crate) fn warn_unshared_web_runtime(&self, ci: &ComponentInterface) {
+        let Some(runtime_package) = self.runtime_package() else {
+            return;
+        };
+        let targets = self.kotlin_targets();
+        let web_targets = [
+            (ConfigKotlinTarget::Js, "Kotlin/JS"),
+            (ConfigKotlinTarget::WasmJs, "Kotlin/Wasm"),
+        ]
+        .into_iter()
+        .filter(|(target, _)| targets.contains(target))
+        .map(|(_, name)| name)
+        .collect::<Vec<_>>();
+        if web_targets.is_empty() {
+            return;
+        }
+        println!(
+            "Warning: the {} bindings of `{}` include their own copies of `ByteBuffer`, \
+             `RustBuffer`, and the primitive converters instead of using `runtime_package = \
+             \"{runtime_package}\"`, since they read the WebAssembly memory of each component",
+            web_targets.join(" and "),
+            ci.namespace(),
+        );
+    }
+}
+
+impl Config {
+    /// Fail when a public declaration uses a type made `internal` by `visibility` or
+    /// `visibility_overrides`, since Kotlin rejects a public declaration exposing an internal type.
+    pub(crate) fn check_exposed_types(&self, ci: &ComponentInterface) -> Result<()> {
+        let mut used_types = Vec::new();
+        for func in ci.function_definitions() {
+            if self.is_function_included(func.name()) {
+                used_types.push((func.name(), callable_types(func, func.name())));
+            }
+        }
+        // Errors about `include` and `exclude` are reported by `resolve_filters`.
+        let mut filter_errors = BTreeSet::new();
+        for type_ in ci.iter_local_types() {
+            if let Some(name) = item_name(type_).filter(|_| self.is_type_included(type_)) {
+                used_types.push((name, self.item_types(ci, name, &mut filter_errors)));
+            }
+        }
+
+        let mut errors = BTreeSet::new();
+        for (item, types) in used_types {
+            if self.item_visibility(item) != Visibility::Public {
+                continue;
+            }
+            for (type_, path) in types {
+                if let Some(internal_type) = self.internal_type_name(&type_) {
+                    errors.insert(format!(
+                        "`{path}` is public but uses `{internal_type}`, which is internal. Make \
+                         `{item}` internal or `{internal_type}` public in `visibility_overrides`"
+                    ));
+                }
+            }
+        }
+        if !errors.is_empty() {
+            bail!(
+                "invalid visibility in `{}`:\n{}",
+                ci.namespace(),
+                errors.into_iter().collect::<Vec<_>>().join("\n")
+            );
+        }
+        Ok(())
+    }
+
+    /// Fail when the builder class generated for a record by `java_interop` has the name of a type
+    /// of the component, since both would be declared in the same package.
+    pub(crate) fn check_java_builders(&self, ci: &ComponentInterface) -> Result<()> {
+        if !self.java_interop {
+            return Ok(());
+        }
+        let type_names = ci
+            .iter_local_types()
+            .filter(|type_| self.is_type_included(type_))
+            .filter_map(item_name)
+            .map(|name| (KotlinCodeOracle.class_name(ci, self, name), name))
+            .collect::<HashMap<_, _>>();
+
+        let mut errors = BTreeSet::new();
+        for rec in ci.record_definitions() {
+            let type_ = rec.as_type();
+            if !self.is_type_included(&type_)
+                || rec
+                    .fields()
+                    .iter()
+                    .all(|field| field.default_value().is_none())
+            {
+                continue;
+            }
+            let builder_name = format!(
+                "{}Builder",
+                KotlinCodeOracle.class_name(ci, self, rec.name())
+            );
+            if let Some(name) = type_names.get(&builder_name) {
+                errors.insert(format!(
+                    "the builder of `{}` is named `{builder_name}`, which is the name of `{name}`. \
+                     Rename either of them in `rename`",
+                    rec.name(),
+                ));
+            }
+        }
+        if !errors.is_empty() {
+            bail!(
+                "conflicting names in `{}`:\n{}",
+                ci.namespace(),
+                errors.into_iter().collect::<Vec<_>>().join("\n")
+            );
+        }
+        Ok(())
+    }
+
+    /// Fail when `panic_backtrace_function` is not a generated function taking no arguments and
+    /// returning `Option<String>`, since the bindings call it when the Rust code panics.
+    pub(crate) fn check_panic_backtrace_function(&self, ci: &ComponentInterface) -> Result<()> {
+        let Some(name) = self.panic_backtrace_function() else {
+            return Ok(());
+        };
+        let Some(func) = ci.get_function_definition(name) else {
+            bail!(
+                "`bindings.kotlin.panic_backtrace_function` does not match any function in \
+                 `{}`{}",
+                ci.namespace(),
+                suggestion(
+                    name,
+                    ci.function_definitions().iter().map(|func| func.name())
+                )
+            );
+        };
+        if !self.is_function_included(name) {
+            bail!(
+                "`bindings.kotlin.panic_backtrace_function` is `{name}`, which is excluded from \
+                 the bindings of `{}`",
+                ci.namespace()
+            );
+        }
+        let returns_optional_string = matches!(
+            func.return_type(),
+            Some(Type::Optional { inner_type }) if **inner_type == Type::String
+        );
+        if !func.arguments().is_empty()
+            || !returns_optional_string
+            || func.is_async()
+            || func.throws()
+        {
+            bail!(
+                "`bindings.kotlin.panic_backtrace_function` is `{name}`, which must be a \
+                 synchronous function taking no arguments and returning `Option<String>`"
+            );
+        }
+        Ok(())
+    }
+
+    /// The name of the internal type contained in `type_`, if any.
+    fn internal_type_name<'a>(&self, type_: &'a Type) -> Option<&'a str> {
+        match type_ {
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                self.internal_type_name(inner_type)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => self
+                .internal_type_name(key_type)
+                .or_else(|| self.internal_type_name(value_type)),
+            _ => item_name(type_).filter(|name| self.item_visibility(name) == Visibility::Internal),
+        }
+    }
+}
+
+impl CustomTypeConfig {
+    /// The key and the value of the converter used to lift the builtin value.
+    pub(super) fn lift_converter(&self) -> (&'static str, &str) {
+        if self.lift.is_empty() && !self.into_custom.is_empty() {
+            ("into_custom", &self.into_custom)
+        } else {
+            ("lift", &self.lift)
+        }
+    }
+
+    /// The key and the value of the converter used to lower the custom value.
+    pub(super) fn lower_converter(&self) -> (&'static str, &str) {
+        if self.lower.is_empty() && !self.from_custom.is_empty() {
+            ("from_custom", &self.from_custom)
+        } else {
+            ("lower", &self.lower)
+        }
+    }
+}
+
+fn check_table_keys<'de, T: Deserialize<'de>>(
+    toml: &toml::Value,
+    table_name: &str,
+    errors: &mut Vec<String>,
+) {
+    let Some(table) = toml.as_table() else {
+        // Leave it to serde to report the type mismatch.
+        return;
+    };
+    let fields = field_names::<T>();
+    for key in table.keys() {
+        if !fields.contains(&key.as_str()) {
+            errors.push(format!(
+                "unknown key `{key}` in [{table_name}]{}",
+                suggestion(key, fields.iter().copied())
+            ));
+        }
+    }
+}
+
+/// Returns `"; did you mean `candidate`?"` for the candidate closest to `value`, or an empty
+/// string if none of them is close enough.
+fn suggestion<'a>(value: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    candidates
+        .into_iter()
+        .map(|candidate| (strsim::jaro(value, candidate), candidate))
+        .filter(|(confidence, _)| *confidence > 0.8)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, candidate)| format!("; did you mean `{candidate}`?"))
+        .unwrap_or_default()
+}
+
+/// Reject an empty dispatcher value, and a single word close to one of the keywords, which is
+/// most likely a misspelled keyword rather than a Kotlin expression.
+fn check_dispatcher(key: &str, value: Option<&str>) -> Option<String> {
+    match dispatcher_config(value) {
+        DispatcherConfig::Expression("") => Some(format!(
+            "`bindings.kotlin.{key}` must be one of {} or a Kotlin expression",
+            DISPATCHER_KEYWORDS.map(|k| format!("`{k}`")).join(", ")
+        )),
+        DispatcherConfig::Expression(expression)
+            if expression.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            let suggestion = suggestion(
+                &expression.to_ascii_lowercase(),
+                DISPATCHER_KEYWORDS.iter().copied(),
+            );
+            (!suggestion.is_empty()).then(|| {
+                format!(
+                    "`bindings.kotlin.{key}` is not one of {}, and is used as a Kotlin expression \
+                     (got `{expression}`){suggestion}",
+                    DISPATCHER_KEYWORDS.map(|k| format!("`{k}`")).join(", ")
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The names of the fields that `T` accepts, which serde passes to `Deserializer::deserialize_struct`.
+fn field_names<'de, T: Deserialize<'de>>() -> &'static [&'static str] {
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("field names captured"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+            ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggestion_requires_a_close_candidate() {
+        let candidates = ["package_name", "cdylib_name", "kotlin_targets"];
+        assert_eq!(
+            suggestion("pakage_name", candidates),
+            "; did you mean `package_name`?"
+        );
+        assert_eq!(
+            suggestion("kotlin_target", candidates),
+            "; did you mean `kotlin_targets`?"
+        );
+        assert_eq!(suggestion("visibility", candidates), "");
+        assert_eq!(suggestion("package_name", []), "");
+    }
+
+    #[test]
+    fn check_keys_accepts_known_keys() {
+        let toml = toml::from_str::<toml::Value>(
+            r#"
+            package_name = "com.example"
+            kotlin_targets = ["jvm"]
+
+            [custom_types.Url]
+            type_name = "java.net.URL"
+            into_custom = "java.net.URL({})"
+            from_custom = "{}.toString()"
+
+            [custom_types.Url.jvm]
+            type_name = "java.net.URL"
+            "#,
+        )
+        .unwrap();
+        Config::check_keys(&toml).unwrap();
+    }
+
+    #[test]
+    fn check_keys_reports_every_unknown_key() {
+        let toml = toml::from_str::<toml::Value>(
+            r#"
+            pakage_name = "com.example"
+
+            [custom_types.Url]
+            type_nme = "java.net.URL"
+
+            [custom_types.Url.native]
+            unrelated = true
+            "#,
+        )
+        .unwrap();
+        let error = Config::check_keys(&toml).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid configuration:\n\
+             unknown key `pakage_name` in [bindings.kotlin]; did you mean `package_name`?\n\
+             unknown key `type_nme` in [bindings.kotlin.custom_types.Url]; did you mean \
+             `type_name`?\n\
+             unknown key `unrelated` in [bindings.kotlin.custom_types.Url.native]"
+        );
+    }
+}
@@ -6,7 +6,7 @@
 
 use uniffi_bindgen::interface::{ComponentInterface, Variant};
 
-use super::{CodeType, KotlinCodeOracle};
+use super::{CodeType, Config, KotlinCodeOracle};
 
 #[derive(Debug)]
 pub(super) struct VariantCodeType {
@@ -14,8 +14,8 @@ pub(super) struct VariantCodeType {
 }
 
 impl CodeType for VariantCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        KotlinCodeOracle.class_name(ci, self.v.name())
+    fn type_label(&self, ci: &ComponentInterface, _config: &Config) -> String {
+        KotlinCodeOracle.idiomatic_class_name(ci, self.v.name())
     }
 
     fn canonical_name(&self) -> String {
@@ -4,19 +4,39 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::{collections::HashMap, fs::File, io::Write, process::Command};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    process::Command,
+};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
 use uniffi_bindgen::{BindingGenerator, Component, ComponentInterface, GenerationSettings};
 
 mod gen_kotlin_multiplatform;
-use gen_kotlin_multiplatform::{generate_bindings, Config};
+use gen_kotlin_multiplatform::{generate_bindings, split_sources, Config, JniShim, SharedRuntime};
 
 #[derive(Default)]
 pub struct KotlinBindingGenerator {
     pub multiplatform: Option<bool>,
+    pub check: bool,
+    generated_files: RefCell<Vec<GeneratedFile>>,
+    out_of_date_files: RefCell<Vec<String>>,
+}
+
+/// A file generated by [`KotlinBindingGenerator`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedFile {
+    pub path: Utf8PathBuf,
+    /// The Kotlin target the file is generated for, e.g., `common` or `jvm`. `jni` for the files
+    /// of the JNI shim crate.
+    pub target: String,
+    /// The Gradle source set containing the file, or `None` for files that are not Kotlin sources.
+    pub source_set: Option<String>,
 }
 
 impl KotlinBindingGenerator {
@@ -28,6 +48,24 @@ impl KotlinBindingGenerator {
         self.multiplatform = Some(enabled);
         self
     }
+
+    /// Compare the generated files against the files on the disk instead of writing them. See
+    /// [`KotlinBindingGenerator::out_of_date_files`].
+    pub fn with_check(mut self, enabled: bool) -> Self {
+        self.check = enabled;
+        self
+    }
+
+    /// The files generated so far.
+    pub fn generated_files(&self) -> Vec<GeneratedFile> {
+        self.generated_files.borrow().clone()
+    }
+
+    /// The unified diffs of the files on the disk that differ from the generated files, when
+    /// [`KotlinBindingGenerator::with_check`] is enabled.
+    pub fn out_of_date_files(&self) -> Vec<String> {
+        self.out_of_date_files.borrow().clone()
+    }
 }
 
 impl BindingGenerator for KotlinBindingGenerator {
@@ -38,7 +76,9 @@ impl BindingGenerator for KotlinBindingGenerator {
             return Ok(Config::default());
         };
 
+        Config::check_keys(config)?;
         let mut config: Config = config.clone().try_into()?;
+        config.validate()?;
 
         // Override with CLI flags if provided
         if let Some(multiplatform) = self.multiplatform {
@@ -54,6 +94,15 @@ impl BindingGenerator for KotlinBindingGenerator {
         components: &mut Vec<Component<Self::Config>>,
     ) -> Result<()> {
         for c in &mut *components {
+            c.config.warn_unused_custom_types(&c.ci);
+            c.config.warn_unused_renames(&c.ci);
+            c.config.warn_unused_async_iterators(&c.ci);
+            c.config.warn_unused_suspend_wrappers(&c.ci);
+            c.config.warn_unshared_web_runtime(&c.ci);
+            c.config.resolve_filters(&c.ci)?;
+            c.config.check_exposed_types(&c.ci)?;
+            c.config.check_java_builders(&c.ci)?;
+            c.config.check_panic_backtrace_function(&c.ci)?;
             c.config
                 .package_name
                 .get_or_insert_with(|| format!("uniffi.{}", c.ci.namespace()));
@@ -70,7 +119,41 @@ impl BindingGenerator for KotlinBindingGenerator {
                 .iter()
                 .map(|c| (c.ci.crate_name().to_string(), c.config.package_name())),
         );
+        Config::check_shared_runtimes(components.iter().map(|c| &c.config))?;
+        // Components sharing a runtime package also share `ByteBuffer`, which changes how external
+        // types are read and written.
+        let runtime_packages =
+            HashMap::<String, Option<String>>::from_iter(components.iter().map(|c| {
+                (
+                    c.config.package_name(),
+                    c.config.runtime_package().map(str::to_owned),
+                )
+            }));
+        // References to the types of the other components use their renamed names. The renames are
+        // keyed by crate, so types with the same name in different crates don't collide.
+        let type_renames = HashMap::<String, HashMap<String, String>>::from_iter(
+            components
+                .iter()
+                .map(|c| (c.ci.crate_name().to_owned(), c.config.type_renames())),
+        );
         for c in components {
+            for (crate_name, renames) in &type_renames {
+                if crate_name != c.ci.crate_name() {
+                    c.config
+                        .external_type_renames
+                        .insert(crate_name.clone(), renames.clone());
+                }
+            }
+            if let Some(runtime_package) = c.config.runtime_package().map(str::to_owned) {
+                c.config.runtime_package_peers = runtime_packages
+                    .iter()
+                    .filter(|(package, peer_runtime_package)| {
+                        **package != c.config.package_name()
+                            && peer_runtime_package.as_deref() == Some(runtime_package.as_str())
+                    })
+                    .map(|(package, _)| package.clone())
+                    .collect();
+            }
             for (ext_crate, ext_package) in &packages {
                 if ext_crate != c.ci.crate_name()
                     && !c.config.external_packages.contains_key(ext_crate)
@@ -89,75 +172,351 @@ impl BindingGenerator for KotlinBindingGenerator {
         settings: &GenerationSettings,
         components: &[Component<Self::Config>],
     ) -> Result<()> {
+        let mut outputs = Vec::new();
         for Component { ci, config, .. } in components {
             let bindings = generate_bindings(config, ci)?;
 
-            write_bindings_target(ci, settings, config, "common", bindings.common);
+            outputs.extend(bindings_target_files(
+                ci,
+                settings,
+                config,
+                "common",
+                bindings.common,
+            ));
+
+            if let Some(jvm_common) = bindings.jvm_common {
+                let source_set_name = config
+                    .jvm_common_source_set()
+                    .expect("jvm_common_source_set should be set when generating shared bindings");
+                outputs.extend(bindings_target_files(
+                    ci,
+                    settings,
+                    config,
+                    source_set_name,
+                    jvm_common,
+                ));
+            }
 
             if let Some(jvm) = bindings.jvm {
-                write_bindings_target(ci, settings, config, "jvm", jvm);
+                outputs.extend(bindings_target_files(ci, settings, config, "jvm", jvm));
             }
             if let Some(android) = bindings.android {
-                write_bindings_target(ci, settings, config, "android", android);
+                outputs.extend(bindings_target_files(
+                    ci, settings, config, "android", android,
+                ));
             }
             if let Some(native) = bindings.native {
-                write_bindings_target(ci, settings, config, "native", native);
+                outputs.extend(bindings_target_files(
+                    ci, settings, config, "native", native,
+                ));
+            }
+            if let Some(js) = bindings.js {
+                outputs.extend(bindings_target_files(ci, settings, config, "js", js));
+            }
+            if let Some(wasm_js) = bindings.wasm_js {
+                outputs.extend(bindings_target_files(
+                    ci, settings, config, "wasmJs", wasm_js,
+                ));
             }
             if let Some(stub) = bindings.stub {
-                write_bindings_target(ci, settings, config, "stub", stub);
+                outputs.extend(bindings_target_files(ci, settings, config, "stub", stub));
             }
 
             if let Some(header) = bindings.header {
-                write_cinterop(ci, &settings.out_dir, header);
+                outputs.push(cinterop_file(ci, &settings.out_dir, header));
+            }
+            if let Some(jni_shim) = bindings.jni_shim {
+                outputs.extend(jni_shim_files(ci, &settings.out_dir, jni_shim));
             }
         }
+
+        let mut written_runtimes = HashSet::new();
+        for Component { config, .. } in components {
+            let Some(runtime_package) = config.runtime_package() else {
+                continue;
+            };
+            if !written_runtimes.insert(runtime_package.to_owned()) {
+                continue;
+            }
+            let Some(runtime) = SharedRuntime::generate(config)? else {
+                continue;
+            };
+
+            outputs.push(runtime_target_file(
+                settings,
+                config,
+                "common",
+                runtime.common,
+            ));
+
+            if let Some(jvm_common) = runtime.jvm_common {
+                let source_set_name = config
+                    .jvm_common_source_set()
+                    .expect("jvm_common_source_set should be set when generating shared runtime");
+                outputs.push(runtime_target_file(
+                    settings,
+                    config,
+                    source_set_name,
+                    jvm_common,
+                ));
+            }
+
+            if let Some(jvm) = runtime.jvm {
+                outputs.push(runtime_target_file(settings, config, "jvm", jvm));
+            }
+            if let Some(android) = runtime.android {
+                outputs.push(runtime_target_file(settings, config, "android", android));
+            }
+            if let Some(native) = runtime.native {
+                outputs.push(runtime_target_file(settings, config, "native", native));
+            }
+        }
+
+        check_path_collisions(&outputs)?;
+        for output in &outputs {
+            if self.check {
+                if let Some(diff) = diff_output_file(settings, output)? {
+                    self.out_of_date_files.borrow_mut().push(diff);
+                }
+            } else {
+                write_output_file(settings, output)?;
+            }
+        }
+        self.generated_files
+            .borrow_mut()
+            .extend(outputs.into_iter().map(|output| output.file));
         Ok(())
     }
 }
 
-fn write_bindings_target(
+// A file rendered by the generator, before being written to the disk.
+struct OutputFile {
+    file: GeneratedFile,
+    content: String,
+}
+
+fn bindings_target_files(
     ci: &ComponentInterface,
     settings: &GenerationSettings,
     config: &Config,
     target: &str,
     content: String,
-) {
+) -> Vec<OutputFile> {
+    let package_name = config.package_name();
+    let file_name = format!("{}.{}.kt", ci.namespace(), target);
+    if !config.split_output() {
+        return vec![kotlin_source_file(
+            settings,
+            config,
+            &package_name,
+            target,
+            &file_name,
+            content,
+        )];
+    }
+
+    let (main, types) = split_sources(&content);
+    let mut files = vec![kotlin_source_file(
+        settings,
+        config,
+        &package_name,
+        target,
+        &file_name,
+        main,
+    )];
+    // The split files are placed in a subdirectory, so `Url.<target>.kt` does not collide with
+    // `url.<target>.kt` on case-insensitive file systems.
+    let split_dir = Utf8PathBuf::from(ci.namespace());
+    for (type_name, type_content) in types {
+        let file_name = split_dir.join(format!("{}.{}.kt", type_name, target));
+        files.push(kotlin_source_file(
+            settings,
+            config,
+            &package_name,
+            target,
+            &file_name,
+            type_content,
+        ));
+    }
+    files
+}
+
+fn runtime_target_file(
+    settings: &GenerationSettings,
+    config: &Config,
+    target: &str,
+    content: String,
+) -> OutputFile {
+    let runtime_package = config
+        .runtime_package()
+        .expect("runtime package should be set when writing the runtime");
+    let file_name = format!("UniffiRuntime.{}.kt", target);
+    kotlin_source_file(
+        settings,
+        config,
+        runtime_package,
+        target,
+        &file_name,
+        content,
+    )
+}
+
+fn kotlin_source_file(
+    settings: &GenerationSettings,
+    config: &Config,
+    package_name: &str,
+    target: &str,
+    file_name: impl AsRef<Utf8Path>,
+    content: String,
+) -> OutputFile {
     let source_set_name = if config.kotlin_multiplatform {
         format!("{}Main", target)
     } else {
         String::from("main")
     };
-    let package_path: Utf8PathBuf = config.package_name().split('.').collect();
-    let file_name = format!("{}.{}.kt", ci.namespace(), target);
+    let package_path: Utf8PathBuf = package_name.split('.').collect();
 
-    let dest_dir = Utf8PathBuf::from(&settings.out_dir)
-        .join(source_set_name)
+    let path = Utf8PathBuf::from(&settings.out_dir)
+        .join(&source_set_name)
         .join("kotlin")
-        .join(package_path);
-    let file_path = Utf8PathBuf::from(&dest_dir).join(file_name);
+        .join(package_path)
+        .join(file_name);
 
-    fs::create_dir_all(dest_dir).unwrap();
-    fs::write(&file_path, content).unwrap();
+    OutputFile {
+        file: GeneratedFile {
+            path,
+            target: target.to_owned(),
+            source_set: Some(source_set_name),
+        },
+        content,
+    }
+}
 
-    if settings.try_format_code {
+// Fails when two generated files have paths differing only in case, since one would overwrite the
+// other on case-insensitive file systems, e.g., the default ones of macOS and Windows.
+fn check_path_collisions(outputs: &[OutputFile]) -> Result<()> {
+    let mut paths = HashMap::<String, &Utf8Path>::new();
+    for output in outputs {
+        let path = output.file.path.as_path();
+        if let Some(other) = paths.insert(path.as_str().to_lowercase(), path) {
+            if other != path {
+                bail!(
+                    "`{other}` and `{path}` differ only in case and would overwrite each other on \
+                     case-insensitive file systems. Use `rename` to give one of the types a \
+                     different name."
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cinterop_file(ci: &ComponentInterface, out_dir: &Utf8Path, content: String) -> OutputFile {
+    let path = Utf8PathBuf::from(out_dir)
+        .join("nativeInterop")
+        .join("cinterop")
+        .join("headers")
+        .join(ci.namespace())
+        .join(format!("{}.h", ci.namespace()));
+
+    OutputFile {
+        file: GeneratedFile {
+            path,
+            target: String::from("native"),
+            source_set: None,
+        },
+        content,
+    }
+}
+
+fn jni_shim_files(
+    ci: &ComponentInterface,
+    out_dir: &Utf8Path,
+    jni_shim: JniShim,
+) -> Vec<OutputFile> {
+    let dst_dir = Utf8PathBuf::from(out_dir)
+        .join("jniShim")
+        .join(ci.namespace());
+
+    [
+        (dst_dir.join("Cargo.toml"), jni_shim.cargo_toml),
+        (dst_dir.join("build.rs"), jni_shim.build_rs),
+        (dst_dir.join("src").join("lib.rs"), jni_shim.lib_rs),
+    ]
+    .into_iter()
+    .map(|(path, content)| OutputFile {
+        file: GeneratedFile {
+            path,
+            target: String::from("jni"),
+            source_set: None,
+        },
+        content,
+    })
+    .collect()
+}
+
+fn write_output_file(settings: &GenerationSettings, output: &OutputFile) -> Result<()> {
+    let file_path = &output.file.path;
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(file_path, &output.content)?;
+
+    if settings.try_format_code && file_path.extension() == Some("kt") {
         println!("Code generation complete, formatting with ktlint (use --no-format to disable)");
-        if let Err(e) = Command::new("ktlint").arg("-F").arg(&file_path).output() {
+        if let Err(e) = Command::new("ktlint").arg("-F").arg(file_path).output() {
             println!(
                 "Warning: Unable to auto-format {} using ktlint: {e:?}",
                 file_path.file_name().unwrap(),
             );
         }
     }
+    Ok(())
 }
 
-fn write_cinterop(ci: &ComponentInterface, out_dir: &Utf8Path, content: String) {
-    let dst_dir = Utf8PathBuf::from(out_dir)
-        .join("nativeInterop")
-        .join("cinterop")
-        .join("headers")
-        .join(ci.namespace());
-    fs::create_dir_all(&dst_dir).unwrap();
-    let file_path = dst_dir.join(format!("{}.h", ci.namespace()));
-    let mut f = File::create(file_path).unwrap();
-    write!(f, "{}", content).unwrap();
+// Returns the unified diff between the file on the disk and the generated content, or `None` if
+// they are the same. The content is formatted first when `--format` is given, since the file on
+// the disk was formatted when it was written.
+fn diff_output_file(settings: &GenerationSettings, output: &OutputFile) -> Result<Option<String>> {
+    let file_path = &output.file.path;
+    let (old_content, old_header) = if file_path.is_file() {
+        (fs::read_to_string(file_path)?, file_path.as_str())
+    } else {
+        (String::new(), "/dev/null")
+    };
+    let new_content = if settings.try_format_code && file_path.extension() == Some("kt") {
+        format_kotlin_source(output)?
+    } else {
+        output.content.clone()
+    };
+    if old_content == new_content {
+        return Ok(None);
+    }
+    let diff = TextDiff::from_lines(&old_content, &new_content)
+        .unified_diff()
+        .header(old_header, file_path.as_str())
+        .to_string();
+    Ok(Some(diff))
+}
+
+// Formats `output` with ktlint without touching the file on the disk. The content is written to a
+// temporary file next to the output file when its directory exists, so ktlint picks up the same
+// `.editorconfig`.
+fn format_kotlin_source(output: &OutputFile) -> Result<String> {
+    let file_path = &output.file.path;
+    let file_name = file_path.file_name().unwrap();
+    let temp_dir = file_path
+        .parent()
+        .filter(|parent| parent.is_dir())
+        .map(Utf8Path::to_path_buf)
+        .unwrap_or_else(|| Utf8PathBuf::try_from(std::env::temp_dir()).unwrap());
+    let temp_path = temp_dir.join(format!(".{}.{file_name}", std::process::id()));
+    fs::write(&temp_path, &output.content)?;
+    let result = Command::new("ktlint").arg("-F").arg(&temp_path).output();
+    let content = fs::read_to_string(&temp_path);
+    fs::remove_file(&temp_path)?;
+    if let Err(e) = result {
+        bail!("`--check` with `--format` requires ktlint to format {file_name}: {e:?}");
+    }
+    Ok(content?)
 }
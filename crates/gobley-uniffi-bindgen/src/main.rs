@@ -4,14 +4,17 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
 use std::error::Error;
 use std::fs;
 
 use anyhow::Context as _;
 use camino::{Utf8Path, Utf8PathBuf};
 use clap::Parser;
-use gobley_uniffi_bindgen::KotlinBindingGenerator;
+use gobley_uniffi_bindgen::{GeneratedFile, KotlinBindingGenerator};
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
 use uniffi_bindgen::BindgenCrateConfigSupplier;
 
 #[derive(Parser)]
@@ -57,6 +60,20 @@ struct Cli {
     #[clap(long = "no-multiplatform")]
     no_multiplatform: bool,
 
+    /// Write a JSON file listing the generated files with their targets and source sets.
+    /// Files listed in the manifest of the previous run that are no longer generated are removed.
+    #[clap(long)]
+    manifest: Option<Utf8PathBuf>,
+
+    /// Write a Makefile-style depfile listing the files read to generate the bindings.
+    #[clap(long)]
+    depfile: Option<Utf8PathBuf>,
+
+    /// Compare the generated files against the files in the output directory instead of writing
+    /// them. Prints a unified diff for each changed file and fails if any file is out of date.
+    #[clap(long)]
+    check: bool,
+
     /// Path to the UDL file, or cdylib if `library-mode` is specified.
     source: Utf8PathBuf,
 }
@@ -78,15 +95,19 @@ where
 pub struct CliCrateConfigSupplier {
     crate_configs: HashMap<String, Utf8PathBuf>,
     crate_pths: HashMap<String, Utf8PathBuf>,
+    read_files: RefCell<BTreeSet<Utf8PathBuf>>,
 }
 
 impl BindgenCrateConfigSupplier for CliCrateConfigSupplier {
     fn get_toml(&self, crate_name: &str) -> anyhow::Result<Option<toml::value::Table>> {
         if let Some(path) = self.crate_configs.get(crate_name) {
+            self.read_files.borrow_mut().insert(path.clone());
             return load_toml_file(path);
         }
         if let Some(crate_path) = self.crate_pths.get(crate_name) {
-            return load_toml_file(&crate_path.join("uniffi.toml"));
+            let path = crate_path.join("uniffi.toml");
+            self.read_files.borrow_mut().insert(path.clone());
+            return load_toml_file(&path);
         }
         Ok(None)
     }
@@ -99,6 +120,7 @@ impl BindgenCrateConfigSupplier for CliCrateConfigSupplier {
             .join("src")
             .join(format!("{udl_name}.udl"));
         if path.exists() {
+            self.read_files.borrow_mut().insert(path.clone());
             Ok(fs::read_to_string(path)?)
         } else {
             anyhow::bail!(format!("No UDL file found at '{path}'"));
@@ -125,11 +147,26 @@ fn main() -> anyhow::Result<()> {
         source,
         try_format_code,
         no_multiplatform,
+        manifest,
+        depfile,
+        check,
     } = Cli::parse();
 
-    let binding_generator = KotlinBindingGenerator {
-        multiplatform: no_multiplatform.then_some(false),
+    let mut binding_generator = KotlinBindingGenerator::new().with_check(check);
+    if no_multiplatform {
+        binding_generator = binding_generator.with_multiplatform(false);
+    }
+
+    let previous_files = match &manifest {
+        Some(manifest) => read_manifest(manifest)?,
+        None => Vec::new(),
     };
+    // Where the bindings are written to. Without `--out-dir`, UniFFI writes next to the UDL file.
+    let bindings_dir = out_dir
+        .clone()
+        .or_else(|| source.parent().map(Utf8Path::to_path_buf));
+    let mut read_files = BTreeSet::from([source.clone()]);
+    read_files.extend(config.clone());
 
     if library_mode {
         if lib_file.is_some() {
@@ -137,19 +174,34 @@ fn main() -> anyhow::Result<()> {
         }
         let out_dir = out_dir.expect("--out-dir is required when using --library");
 
+        let config_supplier = CliCrateConfigSupplier {
+            crate_configs: crate_configs.into_iter().collect(),
+            crate_pths: crate_paths.into_iter().collect(),
+            read_files: RefCell::default(),
+        };
         uniffi_bindgen::library_mode::generate_bindings(
             &source,
             crate_name,
             &binding_generator,
-            &CliCrateConfigSupplier {
-                crate_configs: crate_configs.into_iter().collect(),
-                crate_pths: crate_paths.into_iter().collect(),
-            },
+            &config_supplier,
             config.as_deref(),
             &out_dir,
             try_format_code,
         )?;
+        read_files.extend(config_supplier.read_files.into_inner());
     } else {
+        read_files.extend(lib_file.clone());
+        // The crate root is where `generate_external_bindings` looks for `Cargo.toml` and
+        // `uniffi.toml`.
+        if let Ok(crate_root) = uniffi_bindgen::guess_crate_root(&source) {
+            if crate_name.is_none() {
+                read_files.insert(crate_root.join("Cargo.toml"));
+            }
+            let crate_config = crate_root.join("uniffi.toml");
+            if crate_config.is_file() {
+                read_files.insert(crate_config);
+            }
+        }
         uniffi_bindgen::generate_external_bindings(
             &binding_generator,
             source,
@@ -161,5 +213,204 @@ fn main() -> anyhow::Result<()> {
         )?;
     }
 
+    if check {
+        let mut out_of_date_files = binding_generator.out_of_date_files();
+        let generated_files = binding_generator.generated_files();
+        for file in stale_files(&previous_files, &generated_files, bindings_dir.as_deref())? {
+            out_of_date_files.push(stale_file_diff(file)?);
+        }
+        if out_of_date_files.is_empty() {
+            return Ok(());
+        }
+        for diff in &out_of_date_files {
+            print!("{diff}");
+        }
+        anyhow::bail!(
+            "{} generated file(s) are out of date; rerun without --check to update them",
+            out_of_date_files.len()
+        );
+    }
+
+    let generated_files = binding_generator.generated_files();
+    if let Some(manifest) = manifest {
+        remove_stale_files(&previous_files, &generated_files, bindings_dir.as_deref())?;
+        write_manifest(&manifest, &generated_files)?;
+    }
+    if let Some(depfile) = depfile {
+        write_depfile(&depfile, &generated_files, &read_files)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    files: Vec<GeneratedFile>,
+}
+
+fn read_manifest(path: &Utf8Path) -> anyhow::Result<Vec<GeneratedFile>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(path).with_context(|| format!("read file: {:?}", path))?;
+    let manifest: Manifest =
+        serde_json::from_str(&contents).with_context(|| format!("parse manifest: {:?}", path))?;
+    Ok(manifest.files)
+}
+
+fn write_manifest(path: &Utf8Path, files: &[GeneratedFile]) -> anyhow::Result<()> {
+    let manifest = Manifest {
+        files: files.to_vec(),
+    };
+    let contents = serde_json::to_string_pretty(&manifest)?;
+    fs::write(path, contents).with_context(|| format!("write file: {:?}", path))
+}
+
+/// The files generated by the previous run that are not generated anymore, e.g., the bindings of a
+/// removed namespace or the file of a removed type with `split_output`.
+///
+/// Only the files inside `bindings_dir` are returned, so a manifest that was edited by hand or
+/// written for another output directory never makes us touch unrelated files.
+fn stale_files<'a>(
+    previous_files: &'a [GeneratedFile],
+    generated_files: &[GeneratedFile],
+    bindings_dir: Option<&Utf8Path>,
+) -> anyhow::Result<Vec<&'a GeneratedFile>> {
+    let Some(bindings_dir) = bindings_dir else {
+        return Ok(Vec::new());
+    };
+    let bindings_dir = bindings_dir
+        .canonicalize_utf8()
+        .with_context(|| format!("canonicalize directory: {:?}", bindings_dir))?;
+    let generated_paths = generated_files
+        .iter()
+        .map(|file| &file.path)
+        .collect::<BTreeSet<_>>();
+    let mut files = Vec::new();
+    for file in previous_files {
+        if generated_paths.contains(&file.path) || !file.path.is_file() {
+            continue;
+        }
+        let path = file
+            .path
+            .canonicalize_utf8()
+            .with_context(|| format!("canonicalize file: {:?}", file.path))?;
+        if path.starts_with(&bindings_dir) {
+            files.push(file);
+        } else {
+            println!(
+                "Warning: the stale file {:?} is outside of {:?}, leaving it in place",
+                file.path, bindings_dir
+            );
+        }
+    }
+    Ok(files)
+}
+
+/// Remove the files returned by [`stale_files`].
+fn remove_stale_files(
+    previous_files: &[GeneratedFile],
+    generated_files: &[GeneratedFile],
+    bindings_dir: Option<&Utf8Path>,
+) -> anyhow::Result<()> {
+    for file in stale_files(previous_files, generated_files, bindings_dir)? {
+        fs::remove_file(&file.path)
+            .with_context(|| format!("remove stale file: {:?}", file.path))?;
+    }
     Ok(())
 }
+
+/// The unified diff removing a stale file, reported by `--check`.
+fn stale_file_diff(file: &GeneratedFile) -> anyhow::Result<String> {
+    let content =
+        fs::read_to_string(&file.path).with_context(|| format!("read file: {:?}", file.path))?;
+    Ok(TextDiff::from_lines(content.as_str(), "")
+        .unified_diff()
+        .header(file.path.as_str(), "/dev/null")
+        .to_string())
+}
+
+fn write_depfile(
+    path: &Utf8Path,
+    generated_files: &[GeneratedFile],
+    read_files: &BTreeSet<Utf8PathBuf>,
+) -> anyhow::Result<()> {
+    let targets = generated_files
+        .iter()
+        .map(|file| escape_depfile_path(&file.path))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let dependencies = read_files
+        .iter()
+        .map(|file| escape_depfile_path(file))
+        .collect::<Vec<_>>()
+        .join(" \\\n  ");
+    fs::write(path, format!("{targets}: {dependencies}\n"))
+        .with_context(|| format!("write file: {:?}", path))
+}
+
+/// Escapes a path the way GNU Make and Ninja read it back from a depfile.
+fn escape_depfile_path(path: &Utf8Path) -> String {
+    let mut escaped = String::with_capacity(path.as_str().len());
+    let mut backslashes = 0;
+    for c in path.as_str().chars() {
+        match c {
+            '\\' => {
+                backslashes += 1;
+                escaped.push(c);
+                continue;
+            }
+            // Backslashes preceding a space or `#` escape each other, so double them.
+            ' ' | '#' => {
+                escaped.extend(std::iter::repeat_n('\\', backslashes + 1));
+            }
+            ':' => escaped.push('\\'),
+            '$' => escaped.push('$'),
+            _ => {}
+        }
+        backslashes = 0;
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn depfile_paths_are_escaped() {
+        assert_eq!(escape_depfile_path("a/b.kt".into()), "a/b.kt");
+        assert_eq!(escape_depfile_path("a b/$c#d".into()), "a\\ b/$$c\\#d");
+        assert_eq!(escape_depfile_path("C:/out".into()), "C\\:/out");
+        assert_eq!(escape_depfile_path("a\\b".into()), "a\\b");
+        assert_eq!(escape_depfile_path("a\\ b".into()), "a\\\\\\ b");
+    }
+    #[test]
+    fn stale_files_outside_of_the_bindings_dir_are_kept() {
+        let root = Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("gobley-stale-files-{}", std::process::id()));
+        let bindings_dir = root.join("bindings");
+        fs::create_dir_all(&bindings_dir).unwrap();
+        let file = |path: Utf8PathBuf| {
+            fs::write(&path, "").unwrap();
+            GeneratedFile {
+                path,
+                target: "common".into(),
+                source_set: None,
+            }
+        };
+        let previous_files = [
+            file(bindings_dir.join("kept.kt")),
+            file(bindings_dir.join("removed.kt")),
+            file(root.join("unrelated.kt")),
+        ];
+        let generated_files = [previous_files[0].clone()];
+
+        let stale = stale_files(&previous_files, &generated_files, Some(&bindings_dir)).unwrap();
+        let stale = stale.iter().map(|file| &file.path).collect::<Vec<_>>();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(stale, [&bindings_dir.join("removed.kt")]);
+    }
+}
@@ -1,3 +1,4 @@
+{%- if !config.has_shared_platform_runtime(module_name) %}
 
 @kotlin.jvm.JvmInline
 {{ visibility() }}value class ByteBuffer(private val inner: java.nio.ByteBuffer) {
@@ -55,3 +56,4 @@
         inner.putDouble(value)
     }
 }
+{%- endif %}
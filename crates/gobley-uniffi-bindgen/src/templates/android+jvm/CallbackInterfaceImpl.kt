@@ -12,7 +12,7 @@ internal object {{ trait_impl }} {
         {%- endif %} {
             val uniffiObj = {{ ffi_converter_name }}.handleMap.get(uniffiHandle)
             val makeCall = {% if meth.is_async() %}suspend {% endif %}{ ->
-                uniffiObj.{{ meth.name()|fn_name() }}(
+                uniffiObj.{{ meth.name()|callable_name(name, config) }}(
                     {%- for arg in meth.arguments() %}
                     {%- if arg|as_ffi_type|ref|need_non_null_assertion %}
                     {{ arg|lift_fn }}({{ arg.name()|var_name }}!!),
@@ -26,7 +26,7 @@ internal object {{ trait_impl }} {
 
             {%- match meth.return_type() %}
             {%- when Some(return_type) %}
-            val writeReturn = { uniffiResultValue: {{ return_type|type_name(ci) }} ->
+            val writeReturn = { uniffiResultValue: {{ return_type|type_name(ci, config) }} ->
                 uniffiOutReturn.setValue({{ return_type|lower_fn }}(uniffiResultValue))
             }
             {%- when None %}
@@ -45,11 +45,11 @@ internal object {{ trait_impl }} {
                 uniffiCallStatus,
                 makeCall,
                 writeReturn,
-            ) { e: {{error_type|type_name(ci) }} -> {{ error_type|lower_fn }}(e) }
+            ) { e: {{error_type|type_name(ci, config) }} -> {{ error_type|lower_fn }}(e) }
             {%- endmatch %}
 
             {%- else %}
-            val uniffiHandleSuccess = { {% if meth.return_type().is_some() %}returnValue{% else %}_{% endif %}: {% match meth.return_type() %}{%- when Some(return_type) %}{{ return_type|type_name(ci) }}{%- when None %}Unit{% endmatch %} ->
+            val uniffiHandleSuccess = { {% if meth.return_type().is_some() %}returnValue{% else %}_{% endif %}: {% match meth.return_type() %}{%- when Some(return_type) %}{{ return_type|type_name(ci, config) }}{%- when None %}Unit{% endmatch %} ->
                 val uniffiResult = {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}UniffiByValue(
                     {%- if let Some(return_type) = meth.return_type() %}
                     {{ return_type|lower_fn }}(returnValue),
@@ -84,7 +84,7 @@ internal object {{ trait_impl }} {
                     makeCall,
                     uniffiHandleSuccess,
                     uniffiHandleError,
-                ) { e: {{error_type|type_name(ci) }} -> {{ error_type|lower_fn }}(e) }
+                ) { e: {{error_type|type_name(ci, config) }} -> {{ error_type|lower_fn }}(e) }
                 {%- endmatch %}
             )
             {%- endif %}
@@ -3,11 +3,12 @@
 {%- let package_name=self.external_type_package_name(module_path, namespace) %}
 {%- include "ffi/ExternalTypeTemplate.kt" %}
 
-{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|class_name(ci)) %}
+{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|external_class_name(module_path, ci, config)) %}
 {%- let fully_qualified_ffi_converter_name = "{}.FfiConverterType{}"|format(package_name, name) %}
-{%- let fully_qualified_rustbuffer_name = "{}.RustBuffer"|format(package_name) %}
+{%- let rustbuffer_package_name = config.rust_buffer_package_of(module_name, package_name) %}
+{%- let fully_qualified_rustbuffer_name = "{}.RustBuffer"|format(rustbuffer_package_name) %}
 {%- let local_rustbuffer_name = "RustBuffer{}"|format(name) %}
-{%- let fully_qualified_rustbuffer_by_value_name = "{}.RustBufferByValue"|format(package_name) %}
+{%- let fully_qualified_rustbuffer_by_value_name = "{}.RustBufferByValue"|format(rustbuffer_package_name) %}
 {%- let local_rustbuffer_by_value_name = "RustBuffer{}ByValue"|format(name) %}
 
 {{- self.add_import(fully_qualified_type_name) }}
@@ -31,10 +32,23 @@ internal fun {{ local_rustbuffer_by_value_name }}.from{{ name }}ToLocal(): RustB
     )
 }
 
-internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|class_name(ci) }} {
+{%- if config.has_shared_platform_runtime(module_name) && config.shares_runtime_with(package_name) %}
+
+// The `ByteBuffer` class is shared with {{ package_name }}.
+internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|external_class_name(module_path, ci, config) }} {
+    return read(buf)
+}
+
+internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|external_class_name(module_path, ci, config) }}, buf: ByteBuffer) {
+    write(value, buf)
+}
+{%- else %}
+
+internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|external_class_name(module_path, ci, config) }} {
     return read({{ package_name }}.ByteBuffer(buf.internal()))
 }
 
-internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|class_name(ci) }}, buf: ByteBuffer) {
+internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|external_class_name(module_path, ci, config) }}, buf: ByteBuffer) {
     write(value, {{ package_name }}.ByteBuffer(buf.internal()))
-}
\ No newline at end of file
+}
+{%- endif %}
\ No newline at end of file
@@ -1,3 +1,4 @@
+{%- if !config.has_shared_platform_runtime(module_name) %}
 
 internal class UniffiHandleMap<T: Any> {
     private val map = java.util.concurrent.ConcurrentHashMap<Long, T>()
@@ -23,3 +24,4 @@ internal class UniffiHandleMap<T: Any> {
         return map.remove(handle) ?: throw InternalException("UniffiHandleMap.remove: Invalid handle")
     }
 }
+{%- endif %}
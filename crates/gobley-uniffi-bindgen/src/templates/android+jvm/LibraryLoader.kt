@@ -0,0 +1,64 @@
+@Synchronized
+private fun findLibraryName(componentName: String): String {
+    val libOverride = System.getProperty("uniffi.component.$componentName.libraryOverride")
+    if (libOverride != null) {
+        return libOverride
+    }
+    return "{{ config.cdylib_name() }}"
+}
+
+private fun uniffiLoadLibrary(libraryName: String) {
+    if (java.io.File(libraryName).isAbsolute) {
+        System.load(libraryName)
+        return
+    }
+    {%- if module_name == "jvm" %}
+    try {
+        System.loadLibrary(libraryName)
+    } catch (e: UnsatisfiedLinkError) {
+        // Like JNA, fall back to the libraries bundled in the JAR.
+        System.load(uniffiExtractLibrary(libraryName) ?: throw e)
+    }
+    {%- else %}
+    System.loadLibrary(libraryName)
+    {%- endif %}
+}
+{%- if module_name == "jvm" %}
+
+// The directory containing the libraries extracted from the JAR. All libraries are extracted to
+// the same directory with their original file names, so that libraries can find the libraries
+// they link to.
+private val uniffiLibraryDirectory: java.io.File by lazy {
+    java.nio.file.Files.createTempDirectory("uniffi").toFile().also { it.deleteOnExit() }
+}
+
+// The resource prefix used by JNA, so that the same JAR layout works for all backends.
+private val uniffiResourcePrefix: String by lazy {
+    val osName = System.getProperty("os.name").lowercase()
+    val os = when {
+        osName.startsWith("windows") -> "win32"
+        osName.startsWith("mac") -> "darwin"
+        else -> "linux"
+    }
+    val arch = when (val osArch = System.getProperty("os.arch").lowercase()) {
+        "amd64", "x86_64" -> "x86-64"
+        "arm64" -> "aarch64"
+        else -> osArch
+    }
+    "$os-$arch"
+}
+
+@Synchronized
+private fun uniffiExtractLibrary(libraryName: String): String? {
+    val fileName = System.mapLibraryName(libraryName)
+    val file = java.io.File(uniffiLibraryDirectory, fileName)
+    if (!file.exists()) {
+        val resource = UniffiLib::class.java.classLoader
+            ?.getResourceAsStream("$uniffiResourcePrefix/$fileName")
+            ?: return null
+        resource.use { input -> file.outputStream().use { input.copyTo(it) } }
+        file.deleteOnExit()
+    }
+    return file.absolutePath
+}
+{%- endif %}
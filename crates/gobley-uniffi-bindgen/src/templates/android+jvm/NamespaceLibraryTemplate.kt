@@ -69,11 +69,19 @@ private fun findLibraryName(componentName: String): String {
 private inline fun <reified Lib : Library> loadIndirect(
     componentName: String
 ): Lib {
+    {%- if module_name == "jvmCommon" %}
+    uniffiLoadDynamicLibraryDependencies()
+    {%- endif %}
     {%- for dynamic_library in config.dynamic_library_dependencies(module_name) %}
     com.sun.jna.Native.register(object : com.sun.jna.Library {}::class.java, "{{ dynamic_library }}")
     {%- endfor %}
     return Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
 }
+{%- if module_name == "jvmCommon" %}
+
+// Implemented in the Kotlin/JVM and Android source sets.
+internal expect fun uniffiLoadDynamicLibraryDependencies()
+{%- endif %}
 
 // For large crates we prevent `MethodTooLargeException` (see #2340)
 // N.B. the name of the extension is very misleading, since it is 
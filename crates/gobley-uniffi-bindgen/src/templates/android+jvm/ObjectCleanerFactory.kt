@@ -0,0 +1,56 @@
+{%- if config.disable_java_cleaner %}
+{{ cleaner_factory_modifier }} fun UniffiCleaner.Companion.create(): UniffiCleaner = UniffiJnaCleaner()
+{%- else if module_name == "android" %}
+
+// The SystemCleaner, available from API Level 33.
+// Some API Level 33 OSes do not support using it, so we require API Level 34.
+@RequiresApi(Build.VERSION_CODES.UPSIDE_DOWN_CAKE)
+private class AndroidSystemCleaner : UniffiCleaner {
+    private val cleaner = android.system.SystemCleaner.cleaner()
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable =
+        AndroidSystemCleanable(cleaner.register(resource, UniffiCleanerAction(disposable)))
+}
+
+@RequiresApi(Build.VERSION_CODES.UPSIDE_DOWN_CAKE)
+private class AndroidSystemCleanable(
+    private val cleanable: java.lang.ref.Cleaner.Cleanable,
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+{{ cleaner_factory_modifier }} fun UniffiCleaner.Companion.create(): UniffiCleaner {
+    if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
+        try {
+            return AndroidSystemCleaner()
+        } catch (_: IllegalAccessError) {
+            // (For Compose preview) Fallback to UniffiJnaCleaner if AndroidSystemCleaner is
+            // unavailable, even for API level 34 or higher.
+        }
+    }
+    return UniffiJnaCleaner()
+}
+
+{%- else %}
+
+private class JavaLangRefCleaner : UniffiCleaner {
+    private val cleaner: java.lang.ref.Cleaner = java.lang.ref.Cleaner.create()
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable =
+        JavaLangRefCleanable(cleaner.register(resource, UniffiCleanerAction(disposable)))
+}
+
+private class JavaLangRefCleanable(
+    val cleanable: java.lang.ref.Cleaner.Cleanable
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+{{ cleaner_factory_modifier }} fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    try {
+        JavaLangRefCleaner()
+    } catch (e: ClassNotFoundException) {
+        UniffiJnaCleaner()
+    }
+
+{%- endif %}
\ No newline at end of file
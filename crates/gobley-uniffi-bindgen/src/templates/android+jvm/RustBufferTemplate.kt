@@ -1,80 +1,2 @@
 {% include "ffi/RustBufferTemplate.kt" %}
-
-@Structure.FieldOrder("capacity", "len", "data")
-{{ visibility() }}open class RustBufferStruct(
-    // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
-    // When dealing with these fields, make sure to call `toULong()`.
-    @JvmField {{ visibility() }}var capacity: Long,
-    @JvmField {{ visibility() }}var len: Long,
-    @JvmField {{ visibility() }}var data: Pointer?,
-) : Structure() {
-    {{ visibility() }}constructor(): this(0.toLong(), 0.toLong(), null)
-
-    {{ visibility() }}class ByValue(
-        capacity: Long,
-        len: Long,
-        data: Pointer?,
-    ): RustBuffer(capacity, len, data), Structure.ByValue {
-        {{ visibility() }}constructor(): this(0.toLong(), 0.toLong(), null)
-    }
-
-    /**
-     * The equivalent of the `*mut RustBuffer` type.
-     * Required for callbacks taking in an out pointer.
-     *
-     * Size is the sum of all values in the struct.
-     */
-    {{ visibility() }}class ByReference(
-        capacity: Long,
-        len: Long,
-        data: Pointer?,
-    ): RustBuffer(capacity, len, data), Structure.ByReference {
-        {{ visibility() }}constructor(): this(0.toLong(), 0.toLong(), null)
-    }
-}
-
-{{ visibility() }}typealias RustBuffer = RustBufferStruct
-{{ visibility() }}typealias RustBufferByValue = RustBufferStruct.ByValue
-
-internal fun RustBuffer.asByteBuffer(): ByteBuffer? {
-    {% call kt::check_rust_buffer_length("this.len") %}
-    return ByteBuffer(data?.getByteBuffer(0L, this.len) ?: return null)
-}
-
-internal fun RustBufferByValue.asByteBuffer(): ByteBuffer? {
-    {% call kt::check_rust_buffer_length("this.len") %}
-    return ByteBuffer(data?.getByteBuffer(0L, this.len) ?: return null)
-}
-
-internal class RustBufferByReference : com.sun.jna.ptr.ByReference(16)
-internal fun RustBufferByReference.setValue(value: RustBufferByValue) {
-    // NOTE: The offsets are as they are in the C-like struct.
-    val pointer = getPointer()
-    pointer.setLong(0, value.capacity)
-    pointer.setLong(8, value.len)
-    pointer.setPointer(16, value.data)
-}
-internal fun RustBufferByReference.getValue(): RustBufferByValue {
-    val pointer = getPointer()
-    val value = RustBufferByValue()
-    value.writeField("capacity", pointer.getLong(0))
-    value.writeField("len", pointer.getLong(8))
-    value.writeField("data", pointer.getLong(16))
-    return value
-}
-
-// This is a helper for safely passing byte references into the rust code.
-// It's not actually used at the moment, because there aren't many things that you
-// can take a direct pointer to in the JVM, and if we're going to copy something
-// then we might as well copy it into a `RustBuffer`. But it's here for API
-// completeness.
-
-@Structure.FieldOrder("len", "data")
-internal open class ForeignBytesStruct : Structure() {
-    @JvmField var len: Int = 0
-    @JvmField var data: Pointer? = null
-
-    internal class ByValue : ForeignBytes(), Structure.ByValue
-}
-internal typealias ForeignBytes = ForeignBytesStruct
-internal typealias ForeignBytesByValue = ForeignBytesStruct.ByValue
+{% include "RustBufferStruct.kt" %}
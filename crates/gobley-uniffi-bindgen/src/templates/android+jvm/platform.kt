@@ -0,0 +1,31 @@
+@file:Suppress("RemoveRedundantBackticks")
+
+package {{ config.package_name() }}
+{%- if module_name == "android" && ci.has_object_definitions() && !config.disable_java_cleaner %}
+
+import android.os.Build
+import androidx.annotation.RequiresApi
+{%- endif %}
+{%- if let Some(runtime_package) = config.runtime_package() %}
+
+import {{ runtime_package }}.*
+{%- endif %}
+
+// The parts of the bindings that differ between Kotlin/JVM and Android. The rest of the bindings
+// is in the `{{ source_set_name }}Main` source set.
+
+internal actual fun uniffiLoadDynamicLibraryDependencies() {
+    {%- for dynamic_library in config.dynamic_library_dependencies(module_name) %}
+    com.sun.jna.Native.register(object : com.sun.jna.Library {}::class.java, "{{ dynamic_library }}")
+    {%- endfor %}
+}
+{%- if ci.has_object_definitions() %}
+{%- let cleaner_factory_modifier = "internal actual" %}
+{% include "android+jvm/ObjectCleanerFactory.kt" %}
+{%- endif %}
+{%- if config.generate_parcelable() %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
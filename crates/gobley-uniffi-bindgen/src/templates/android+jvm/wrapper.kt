@@ -23,6 +23,7 @@ import com.sun.jna.Structure
 {%- for req in self.imports() %}
 {{ req.render() }}
 {%- endfor %}
+{{- self.split_output_helpers_section() }}
 
 {% include "PointerHelper.kt" %}
 
@@ -39,14 +40,33 @@ import com.sun.jna.Structure
 
 // Public interface members begin here.
 {{ type_helper_code }}
+{{- self.split_output_section_end() }}
 
 {% import "macros.kt" as kt %}
 
-{%- for func in ci.function_definitions() %}
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
 {%- include "ffi/TopLevelFunctionTemplate.kt" %}
 {%- endfor %}
+{{- self.visibility_section_end() }}
+
+{%- if self::is_gobley_log(ci) %}
+{% include "ffi/LogSink.kt" %}
+{%- endif %}
+{{- self.split_output_helpers_section() }}
 
 // Async support
 {%- if ci.has_async_fns() %}
 {% include "Async.kt" %}
 {%- endif %}
+
+{%- if config.has_suspend_wrappers(ci) %}
+{% include "ffi/BlockingDispatcher.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() && module_name != "jvmCommon" %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() && module_name != "jvmCommon" %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
@@ -0,0 +1,36 @@
+
+/**
+ * The coroutine contexts in which the async calls into Rust are polled and the suspend wrappers
+ * call into Rust.
+ */
+{{ visibility() }}object UniffiAsyncDispatcher {
+    // Set from any thread, so the writes must be visible to the threads making the calls.
+    private val contextRef = kotlinx.atomicfu.atomic<kotlin.coroutines.CoroutineContext?>(null)
+    private val blockingContextRef =
+        kotlinx.atomicfu.atomic<kotlin.coroutines.CoroutineContext?>(null)
+
+    /**
+     * Overrides the context configured with `async_dispatcher`, which is
+     * `{{ config.async_dispatcher() }}`, when set. Set it at startup, for example, to a test
+     * dispatcher, or to [kotlin.coroutines.EmptyCoroutineContext] to poll in the caller's context.
+     */
+    {{ visibility() }}var context: kotlin.coroutines.CoroutineContext?
+        get() = contextRef.value
+        set(value) {
+            contextRef.value = value
+        }
+
+    /**
+     * Overrides the context configured with `blocking_dispatcher` for the suspend wrappers
+     * generated with `suspend_wrappers`, when set.
+     */
+    {{ visibility() }}var blockingContext: kotlin.coroutines.CoroutineContext?
+        get() = blockingContextRef.value
+        set(value) {
+            blockingContextRef.value = value
+        }
+}
+{%- if config.has_suspend_wrappers(ci) && config.kotlin_multiplatform %}
+
+internal expect val uniffiBlockingDispatcher: kotlin.coroutines.CoroutineContext
+{%- endif %}
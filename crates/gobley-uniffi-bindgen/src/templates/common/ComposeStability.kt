@@ -0,0 +1,19 @@
+/**
+ * `androidx.compose.runtime.Stable` on the targets in `compose_targets`, and no annotation on the
+ * other platforms.
+ */
+@OptIn(ExperimentalMultiplatform::class)
+@OptionalExpectation
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}expect annotation class UniffiStable()
+
+/**
+ * `androidx.compose.runtime.Immutable` on the targets in `compose_targets`, and no annotation on
+ * the other platforms.
+ */
+@OptIn(ExperimentalMultiplatform::class)
+@OptionalExpectation
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}expect annotation class UniffiImmutable()
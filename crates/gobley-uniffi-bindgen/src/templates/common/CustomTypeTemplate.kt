@@ -7,9 +7,35 @@
  * is needed because the UDL type name is used in function/method signatures.
  * It's also what we have an external type that references a custom type.
  */
-{{ visibility() }}typealias {{ type_name }} = {{ builtin|type_name(ci) }}
+{{ visibility() }}typealias {{ type_name }} = {{ builtin|type_name(ci, self.config) }}
 
 {%- when Some(config) %}
+{%- if config.value_class %}
+/**
+ * Inline value class wrapping the builtin type, so that custom types with the same builtin type
+ * cannot be used in place of each other.
+ */
+{% if self.config.generate_serializable() && builtin|serializable_type(ci) %}@kotlinx.serialization.Serializable
+{% endif -%}
+@kotlin.jvm.JvmInline
+{{ visibility() }}value class {{ type_name }}({{ visibility() }}val value: {{ builtin|type_name(ci, self.config) }})
+
+{%- match config.imports %}
+{%- when Some(imports) %}
+{%- for import_name in imports %}
+{{ self.add_import(import_name) }}
+{%- endfor %}
+{%- else %}
+{%- endmatch %}
+{%- elif config.has_platform_types() %}
+{%- if self.config.kotlin_multiplatform %}
+/**
+ * The type used in place of `{{ name }}`. Each platform defines it with an `actual typealias`,
+ * since the type differs between the platforms.
+ */
+{{ visibility() }}expect class {{ type_name }}
+{%- endif %}
+{%- else %}
 
 {%- let ffi_type_name=builtin|ffi_type|ref|ffi_type_name_by_value(ci) %}
 
@@ -32,5 +58,6 @@
 {%- endfor %}
 {%- else %}
 {%- endmatch %}
+{%- endif %}
 
 {%- endmatch %}
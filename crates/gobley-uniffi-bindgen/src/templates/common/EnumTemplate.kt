@@ -7,6 +7,7 @@
 #}
 
 {%- let should_generate_serializable = config.generate_serializable() && e|serializable_enum(ci) -%}
+{%- let should_generate_parcelable = config.generate_parcelable() && e|parcelable_enum(ci, config) -%}
 
 {%- if e.is_flat() %}
 
@@ -14,19 +15,21 @@
 {% match e.variant_discr_type() %}
 {% when None %}
 {% if should_generate_serializable %}@kotlinx.serialization.Serializable{% endif %}
-{{ visibility() }}enum class {{ type_name }} {
+{% if config.compose_stability() %}@UniffiStable{% endif %}
+{% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}enum class {{ type_name }}{% if should_generate_parcelable %} : UniffiParcelable{% endif %} {
     {% for variant in e.variants() -%}
     {%- call kt::docstring(variant, 4) %}
-    {{ variant|variant_name(config) }}{% if loop.last %};{% else %},{% endif %}
+    {{ variant|variant_name(name, config) }}{% if loop.last %};{% else %},{% endif %}
     {%- endfor %}
     {{ visibility() }}companion object
 }
 {% when Some(variant_discr_type) %}
 {% if should_generate_serializable %}@kotlinx.serialization.Serializable{% endif %}
-{{ visibility() }}enum class {{ type_name }}(public val value: {{ variant_discr_type|type_name(ci) }}) {
+{% if config.compose_stability() %}@UniffiStable{% endif %}
+{% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}enum class {{ type_name }}(public val value: {{ variant_discr_type|type_name(ci, config) }}){% if should_generate_parcelable %} : UniffiParcelable{% endif %} {
     {% for variant in e.variants() -%}
     {%- call kt::docstring(variant, 4) %}
-    {{ variant|variant_name(config) }}({{ e|variant_discr_literal(loop.index0) }}){% if loop.last %};{% else %},{% endif %}
+    {{ variant|variant_name(name, config) }}({{ e|variant_discr_literal(loop.index0) }}){% if loop.last %};{% else %},{% endif %}
     {%- endfor %}
     {{ visibility() }}companion object
 }
@@ -35,33 +38,35 @@
 
 {%- call kt::docstring(e, 0) %}
 {% if should_generate_serializable %}@kotlinx.serialization.Serializable{% endif %}
-{{ visibility() }}sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% endif %} {
+{% if config.compose_stability() && !contains_object_references %}@UniffiStable{% endif %}
+{{ visibility() }}sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% else if should_generate_parcelable %} : UniffiParcelable{% endif %} {
     {% for variant in e.variants() -%}
-    {%- let variant_type_name = variant|variant_type_name(ci) -%}
+    {%- let variant_type_name = variant|variant_type_name(name, ci, config) -%}
+    {%- let variant_path = "{}::{}"|format(name, variant.name()) -%}
     {%- let should_generate_variant_serializable = config.generate_serializable() && variant|serializable_enum_variant(ci) -%}
     {%- call kt::docstring(variant, 4) %}
     {%- if !variant.has_fields() %}
     {% if should_generate_variant_serializable %}@kotlinx.serialization.Serializable{% endif %}
-    {{ visibility() }}{% if config.use_data_objects() %}data {% endif %}object {{ variant_type_name }} : {{ type_name }}() {% if contains_object_references %} {
+    {% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}{% if config.use_data_objects() %}data {% endif %}object {{ variant_type_name }} : {{ type_name }}() {% if contains_object_references %} {
         override fun destroy(): Unit = Unit
     }
     {% endif %}
     {% else -%}
     {%- let should_generate_equals_hash_code = variant|should_generate_equals_hash_code_enum_variant -%}
     {% if should_generate_variant_serializable %}@kotlinx.serialization.Serializable{% endif %}
-    {{ visibility() }}data class {{ variant_type_name }}(
+    {% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}data class {{ variant_type_name }}(
         {%- for field in variant.fields() -%}
         {%- call kt::docstring(field, 8) %}
-        val {% call kt::field_name(field, loop.index) %}: {{ field|type_name(ci) }},
+        val {% call kt::field_name(field, variant_path, loop.index) %}: {{ field|type_name(ci, config) }},
         {%- endfor %}
     ) : {{ type_name }}() {
         {%- if should_generate_equals_hash_code -%}
-        {%- call kt::generate_equals_hash_code(variant, variant_type_name, 8) -%}
+        {%- call kt::generate_equals_hash_code(variant, variant_path, variant_type_name, 8) -%}
         {%- endif -%}
         {%- if contains_object_references %}
         override fun destroy() {
             {%- if variant.has_fields() -%}
-            {%- call kt::destroy_fields(variant, 12) -%}
+            {%- call kt::destroy_fields(variant, variant_path, 12) -%}
             {%- else %}
             // Nothing to destroy
             {%- endif %}
@@ -1,5 +1,5 @@
 
 {%- let namespace = ci.namespace_for_module_path(module_path)? %}
 {%- let package_name=self.external_type_package_name(module_path, namespace) %}
-{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|class_name(ci)) %}
+{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|external_class_name(module_path, ci, config)) %}
 {{- self.add_import(fully_qualified_type_name) }}
\ No newline at end of file
@@ -0,0 +1,31 @@
+
+/**
+ * Forwards the records of the Rust `log` and `tracing` crates to a [LogSink].
+ */
+{{ visibility() }}object GobleyLog {
+    /**
+     * Starts forwarding the records at [level] or more severe to [sink], replacing the previous
+     * sink.
+     */
+    {{ visibility() }}fun install(sink: LogSink, level: LogLevel = LogLevel.INFO) {
+        setLogLevel(level)
+        setLogSink(sink)
+    }
+
+    /**
+     * Forwards the records at [level] or more severe, or none when [level] is `null`. The records
+     * are filtered in Rust, so the disabled ones cost no call into Kotlin.
+     */
+    {{ visibility() }}fun setLevel(level: LogLevel?) {
+        setLogLevel(level)
+    }
+}
+
+/**
+ * Prints the records to the standard output.
+ */
+{{ visibility() }}object PrintlnLogSink : LogSink {
+    override fun log(record: LogRecord) {
+        println("${record.level} ${record.target}: ${record.message}")
+    }
+}
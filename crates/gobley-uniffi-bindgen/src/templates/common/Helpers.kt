@@ -1 +1,13 @@
-{{ visibility() }}class InternalException(message: String) : kotlin.Exception(message)
\ No newline at end of file
+{%- if config.runtime_package().is_none() %}
+{{ visibility() }}open class InternalException(message: String) : kotlin.Exception(message)
+
+/**
+ * Thrown when the Rust code panics, with the panic message as its message. [rustBacktrace] is the
+ * Rust backtrace of the panic returned by `panic_backtrace_function`, or `null` if the option is
+ * not set or the function returned `null`.
+ */
+{{ visibility() }}class RustPanicException(
+    message: String,
+    {{ visibility() }}val rustBacktrace: String?,
+) : InternalException(message)
+{%- endif %}
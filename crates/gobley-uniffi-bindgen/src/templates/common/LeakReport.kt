@@ -0,0 +1,72 @@
+
+/**
+ * Tracks the objects backed by Rust, since `debug_object_tracking` is set, to find the ones that
+ * are never closed.
+ */
+{{ visibility() }}object UniffiLeakReport {
+    private class Entry(val className: String, val creationStackTrace: String)
+
+    private val lock = kotlinx.atomicfu.locks.ReentrantLock()
+    private var nextId = 0L
+    private val liveObjects = mutableMapOf<Long, Entry>()
+    private val collectedObjects = mutableListOf<Entry>()
+
+    private fun <T> synchronized(block: () -> T): T {
+        lock.lock()
+        try {
+            return block()
+        } finally {
+            lock.unlock()
+        }
+    }
+
+    // Records a new object and returns its tracking ID
+    internal fun track(className: String): Long {
+        val entry = Entry(className, Throwable().stackTraceToString())
+        return synchronized {
+            val id = nextId++
+            liveObjects[id] = entry
+            id
+        }
+    }
+
+    // Called when the object is destroyed with `destroy()` or `close()`
+    internal fun closed(id: Long) {
+        synchronized { liveObjects.remove(id) }
+    }
+
+    // Called when the Rust object is freed, which is after `closed` unless the object was garbage
+    // collected without being closed
+    internal fun freed(id: Long) {
+        synchronized {
+            liveObjects.remove(id)?.let { collectedObjects.add(it) }
+        }
+    }
+
+    /**
+     * Lists the objects that were garbage collected without being closed, and the objects that
+     * are still alive, with the stack traces of their creation. Call it at shutdown to find the
+     * objects that are never closed.
+     */
+    {{ visibility() }}fun dump(): String = synchronized {
+        buildString {
+            appendLine("Garbage collected without being closed: ${collectedObjects.size}")
+            for (entry in collectedObjects) {
+                appendLine("${entry.className} created at:")
+                appendLine(entry.creationStackTrace)
+            }
+            appendLine("Still alive: ${liveObjects.size}")
+            for (entry in liveObjects.values) {
+                appendLine("${entry.className} created at:")
+                appendLine(entry.creationStackTrace)
+            }
+        }
+    }
+
+    /**
+     * Forgets the objects garbage collected without being closed so far.
+     */
+    {{ visibility() }}fun clear() {
+        synchronized { collectedObjects.clear() }
+    }
+}
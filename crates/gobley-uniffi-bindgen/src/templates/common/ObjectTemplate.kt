@@ -1,8 +1,8 @@
 
 {%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let interface_name = self::object_interface_name(ci, obj) %}
-{%- let impl_class_name = self::object_impl_name(ci, obj) %}
-{%- let methods = obj.methods() %}
+{%- let interface_name = self::object_interface_name(ci, config, obj) %}
+{%- let impl_class_name = self::object_impl_name(ci, config, obj) %}
+{%- let methods = config.included_methods(obj) %}
 {%- let interface_docstring = obj.docstring() %}
 {%- let is_error = ci.is_name_used_as_error(name) %}
 {%- let ffi_converter_name = obj|ffi_converter_name %}
@@ -15,7 +15,7 @@
 {% else -%}
 {{ visibility() }}expect open class {{ impl_class_name }}: Disposable, {{ interface_name }}
 {%- for t in obj.trait_impls() -%}
-, {{ self::trait_interface_name(ci, t.trait_name)? }}
+, {{ self::trait_interface_name(ci, config, t.trait_name)? }}
 {%- endfor %} {
 {%- endif %}
     /**
@@ -49,8 +49,8 @@
     override fun destroy()
     override fun close()
 
-    {% for meth in obj.methods() -%}
-    {%- call kt::func_decl("override", meth, 4, false) %}
+    {% for meth in config.included_methods(obj) -%}
+    {%- call kt::func_decl("override", meth, name, 4, false) %}
     {% endfor %}
 
     {%- for tm in obj.uniffi_traits() %}
@@ -67,14 +67,38 @@
     {%- endfor %}
 
     {# XXX - "companion object" confusion? How to have alternate constructors *and* be an error? #}
-    {%- if !obj.alternate_constructors().is_empty() -%}
+    {%- if !config.included_constructors(obj).is_empty() -%}
     {{ visibility() }}companion object {
-        {% for cons in obj.alternate_constructors() -%}
-        {%- call kt::func_decl("", cons, 8, false) %}
+        {% for cons in config.included_constructors(obj) -%}
+        {%- call kt::func_decl("", cons, name, 8, false) %}
         {% endfor %}
     }
     {% else %}
     {{ visibility() }}companion object
     {%- endif %}
 }
-{% endif %}
\ No newline at end of file
+{% endif %}
+{%- match config.async_iterator(obj) %}
+{%- when Some(iterator) %}
+{%- let method_name = iterator.method.name()|callable_name(name, config) %}
+
+/**
+ * Returns a [kotlinx.coroutines.flow.Flow] emitting the values returned by
+ * [{{ impl_class_name }}.{{ method_name }}] until it returns `null`. Cancelling the collector cancels the
+ * pending call, and the object is destroyed when the flow completes, fails, or is cancelled, so the
+ * flow can be collected only once.
+ */
+{{ visibility() }}fun {{ impl_class_name }}.asFlow(): kotlinx.coroutines.flow.Flow<{{ iterator.item_type|type_name(ci, config) }}> = kotlinx.coroutines.flow.flow {
+    try {
+        while (true) {
+            emit(this@asFlow.{{ method_name }}() ?: break)
+        }
+    } finally {
+        this@asFlow.destroy()
+    }
+}
+{%- else %}
+{%- endmatch %}
+{%- for meth in config.suspend_wrapped_methods(obj) %}
+{%- call kt::suspend_wrapper(meth, name, interface_name) %}
+{%- endfor %}
\ No newline at end of file
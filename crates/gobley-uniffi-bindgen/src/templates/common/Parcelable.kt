@@ -0,0 +1,15 @@
+
+/**
+ * Makes the Parcelize compiler plugin generate the `android.os.Parcelable` implementation of the
+ * annotated class on Android.
+ */
+@OptIn(ExperimentalMultiplatform::class)
+@OptionalExpectation
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}expect annotation class UniffiParcelize()
+
+/**
+ * `android.os.Parcelable` on Android, and an empty interface on the other platforms.
+ */
+{{ visibility() }}expect interface UniffiParcelable
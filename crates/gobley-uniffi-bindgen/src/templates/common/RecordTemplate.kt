@@ -2,32 +2,34 @@
 {%- let rec = ci.get_record_definition(name).unwrap() -%}
 {%- let should_generate_equals_hash_code = rec|should_generate_equals_hash_code_record -%}
 {%- let should_generate_serializable = config.generate_serializable() && rec|serializable_record(ci) -%}
+{%- let should_generate_parcelable = config.generate_parcelable() && rec|parcelable_record(ci, config) -%}
 
 {%- if rec.has_fields() %}
 {%- call kt::docstring(rec, 0) %}
 {% if should_generate_serializable %}@kotlinx.serialization.Serializable{% endif %}
-{{ visibility() }}data class {{ type_name }} (
+{% if config.compose_stability() && config.generate_immutable_records() && !contains_object_references %}@UniffiImmutable{% endif %}
+{% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}data class {{ type_name }} {% if config.java_interop && rec|has_default_fields %}@kotlin.jvm.JvmOverloads constructor {% endif %}(
     {%- for field in rec.fields() %}
     {%- call kt::docstring(field, 4) %}
-    {% if config.generate_immutable_records() %}val{% else %}var{% endif %} {{ field.name()|var_name }}: {{ field|type_name(ci) -}}
+    {% if config.generate_immutable_records() %}val{% else %}var{% endif %} {{ field.name()|field_var_name(name, config) }}: {{ field|record_field_type_name(ci, config) -}}
     {%- match field.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|render_literal(field, ci, config) }}
+        {%- when Some with(literal) %} = {{ literal|render_record_field_literal(field, ci, config) }}
         {%- else %}
     {%- endmatch -%}
     {% if !loop.last %}, {% endif %}
     {%- endfor %}
-) {% if contains_object_references %}: Disposable {% endif %}{
+) {% if contains_object_references %}: Disposable {% else if should_generate_parcelable %}: UniffiParcelable {% endif %}{
     {%- if should_generate_equals_hash_code -%}
-    {%- call kt::generate_equals_hash_code(rec, type_name, 4) -%}
+    {%- call kt::generate_equals_hash_code(rec, name, type_name, 4) -%}
     {%- endif -%}
     {%- if contains_object_references %}
     override fun destroy() {
-        {%- call kt::destroy_fields(rec, 8) %}
+        {%- call kt::destroy_fields(rec, name, 8) %}
     }
     {%- endif %}
     {{ visibility() }}companion object
 }
 {%- else -%}
 {%- call kt::docstring(rec, 0) %}
-{{ visibility() }}{% if config.use_data_objects() %}data {% endif %}object {{ type_name }}
+{% if should_generate_parcelable %}@UniffiParcelize {% endif %}{{ visibility() }}{% if config.use_data_objects() %}data {% endif %}object {{ type_name }}{% if should_generate_parcelable %} : UniffiParcelable{% endif %}
 {%- endif %}
@@ -1 +1 @@
-{%- call kt::func_decl("expect", func, 0, false) -%}
\ No newline at end of file
+{%- call kt::func_decl("expect", func, "", 0, false) -%}
\ No newline at end of file
@@ -1,80 +1,14 @@
 
 {%- import "macros.kt" as kt %}
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
-// in memory leaks.
-//
-// The easiest way to ensure this method is called is to use the `.use`
-// helper method to execute a block and destroy the object at the end.
-@OptIn(ExperimentalStdlibApi::class)
-{{ visibility() }}interface Disposable : AutoCloseable {
-    {{ visibility() }}fun destroy()
-    override fun close(): Unit = destroy()
-    {{ visibility() }}companion object {
-        internal fun destroy(vararg args: Any?) {
-            for (arg in args) {
-                when (arg) {
-                    is Disposable -> arg.destroy()
-                    is ArrayList<*> -> {
-                        for (idx in arg.indices) {
-                            val element = arg[idx]
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Map<*, *> -> {
-                        for (element in arg.values) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Array<*> -> {
-                        for (element in arg) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Iterable<*> -> {
-                        for (element in arg) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
-@OptIn(kotlin.contracts.ExperimentalContracts::class)
-{{ visibility() }}inline fun <T : Disposable?, R> T.use(block: (T) -> R): R {
-    kotlin.contracts.contract {
-        callsInPlace(block, kotlin.contracts.InvocationKind.EXACTLY_ONCE)
-    }
-    return try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
-        }
-    }
-}
-
-/** Used to instantiate an interface without an actual pointer, for fakes in tests, mostly. */
-{{ visibility() }}object NoPointer
-
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name(ci) %}
+{%- if config.runtime_package().is_none() %}
+{% include "Disposable.kt" %}
+{%- endif %}
+
+{%- for type_ in self.generated_types() %}
+{{- self.split_output_section(type_) }}
+{{- self.visibility_section(type_) }}
+{%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 {%- let contains_object_references = ci.item_contains_object_references(type_) %}
@@ -114,6 +48,8 @@
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
+{{- self.split_output_section_end() }}
+{{- self.visibility_section_end() }}
 
 {%- for type_ in ci.iter_external_types() %}
 {%- let name = type_.name().unwrap() %}
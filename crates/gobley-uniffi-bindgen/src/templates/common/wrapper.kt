@@ -19,16 +19,48 @@ package {{ config.package_name() }}
 {%- for req in self.imports() %}
 {{ req.render() }}
 {%- endfor %}
+{{- self.split_output_helpers_section() }}
 
 {% include "Helpers.kt" %}
 
+{%- if ci.has_async_fns() || config.has_suspend_wrappers(ci) %}
+{% include "Async.kt" %}
+{%- endif %}
+
 // Public interface members begin here.
 {{ type_helper_code }}
+{{- self.split_output_section_end() }}
 
 {%- if config.kotlin_multiplatform -%}
-{%- for func in ci.function_definitions() %}
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
 {% include "TopLevelFunctionTemplate.kt" %}
 {%- endfor -%}
+{{- self.visibility_section_end() }}
+{%- endif %}
+
+{%- for func in self.generated_functions() %}
+{%- if config.has_suspend_wrapper(func.name(), func) %}
+{{- self.visibility_section(func) }}
+{%- call kt::suspend_wrapper(func, "", "") %}
+{%- endif %}
+{%- endfor %}
+{{- self.visibility_section_end() }}
+
+{%- if self::is_gobley_log(ci) %}
+{% include "GobleyLog.kt" %}
+{%- endif %}
+{{- self.split_output_helpers_section() }}
+
+{%- if config.debug_object_tracking && ci.has_object_definitions() %}
+{% include "LeakReport.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() && config.kotlin_multiplatform %}
+{% include "Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() && config.kotlin_multiplatform %}
+{% include "ComposeStability.kt" %}
 {%- endif %}
 
 {% import "macros.kt" as kt %}
@@ -14,7 +14,7 @@ internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     liftFunc: (F) -> T,
     errorHandler: UniffiRustCallStatusErrorHandler<E>
 ): T {
-    return withContext(Dispatchers.IO) {
+    return withContext(UniffiAsyncDispatcher.context ?: {{ config.async_dispatcher() }}) {
         try {
             do {
                 val pollResult = suspendCancellableCoroutine<Byte> { continuation ->
@@ -0,0 +1,3 @@
+
+// The default context of the suspend wrappers generated with `suspend_wrappers`
+internal {% if config.kotlin_multiplatform %}actual {% endif %}val uniffiBlockingDispatcher: kotlin.coroutines.CoroutineContext = {{ config.blocking_dispatcher(module_name) }}
@@ -1,4 +1,4 @@
-
+{%- if !config.has_shared_platform_runtime(module_name) %}
 {{ visibility() }}object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
     override fun lift(value: Byte): Boolean {
         return value.toInt() != 0
@@ -18,3 +18,4 @@
         buf.put(lower(value))
     }
 }
+{%- endif %}
@@ -0,0 +1,15 @@
+{%- if config.has_compose_runtime(module_name) %}
+
+{{ visibility() }}{% if config.kotlin_multiplatform %}actual {% endif %}typealias UniffiStable = androidx.compose.runtime.Stable
+
+{{ visibility() }}{% if config.kotlin_multiplatform %}actual {% endif %}typealias UniffiImmutable = androidx.compose.runtime.Immutable
+{%- else if !config.kotlin_multiplatform %}
+
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}annotation class UniffiStable
+
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}annotation class UniffiImmutable
+{%- endif %}
@@ -4,11 +4,18 @@
 
 {{ visibility() }}typealias {{ ffi_converter_name }} = {{ builtin|ffi_converter_name }}
 
-{%- when Some(config) %}
+{%- when Some(custom_type) %}
+{%- let config = custom_type.for_module(module_name, type_name) %}
 
 {%- let ffi_type_name=builtin|ffi_type|ref|ffi_type_name_by_value(ci) %}
 
-{# When the config specifies a different type name, create a typealias for it #}
+{# When the type differs between the platforms, define it for this platform #}
+{%- if custom_type.has_platform_types() %}
+/**
+ * Typealias from the type name used in the UDL file to the custom type on this platform.
+ */
+{{ visibility() }}{% if self.config.kotlin_multiplatform %}actual {% endif %}typealias {{ type_name }} = {% match config.type_name %}{% when Some(concrete_type_name) %}{{ concrete_type_name }}{% else %}{{ builtin|type_name(ci, self.config) }}{% endmatch %}
+{%- endif %}
 
 {%- match config.imports %}
 {%- when Some(imports) %}
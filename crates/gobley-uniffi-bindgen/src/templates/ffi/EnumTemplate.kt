@@ -32,7 +32,7 @@
     override fun read(buf: ByteBuffer): {{ type_name }} {
         return when(buf.getInt()) {
             {%- for variant in e.variants() %}
-            {{ loop.index }} -> {{ type_name }}.{{ variant|variant_type_name(ci) }}{% if variant.has_fields() %}(
+            {{ loop.index }} -> {{ type_name }}.{{ variant|variant_type_name(name, ci, config) }}{% if variant.has_fields() %}(
                 {% for field in variant.fields() -%}
                 {{ field|read_fn(ci) }}(buf),
                 {% endfor -%}
@@ -44,12 +44,13 @@
 
     override fun allocationSize(value: {{ type_name }}): ULong = when(value) {
         {%- for variant in e.variants() %}
-        is {{ type_name }}.{{ variant|variant_type_name(ci) }} -> {
+        {%- let variant_path = "{}::{}"|format(name, variant.name()) %}
+        is {{ type_name }}.{{ variant|variant_type_name(name, ci, config) }} -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
                 {%- for field in variant.fields() %}
-                + {{ field|allocation_size_fn }}(value.{%- call kt::field_name(field, loop.index) -%})
+                + {{ field|allocation_size_fn }}(value.{%- call kt::field_name(field, variant_path, loop.index) -%})
                 {%- endfor %}
             )
         }
@@ -59,10 +60,11 @@
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         when(value) {
             {%- for variant in e.variants() %}
-            is {{ type_name }}.{{ variant|variant_type_name(ci) }} -> {
+            {%- let variant_path = "{}::{}"|format(name, variant.name()) %}
+            is {{ type_name }}.{{ variant|variant_type_name(name, ci, config) }} -> {
                 buf.putInt({{ loop.index }})
                 {%- for field in variant.fields() %}
-                {{ field|write_fn(ci) }}(value.{%- call kt::field_name(field, loop.index) -%}, buf)
+                {{ field|write_fn(ci) }}(value.{%- call kt::field_name(field, variant_path, loop.index) -%}, buf)
                 {%- endfor %}
                 Unit
             }
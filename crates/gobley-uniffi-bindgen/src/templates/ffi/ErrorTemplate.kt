@@ -1,5 +1,5 @@
 
-{%- let type_name = type_|type_name(ci) %}
+{%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 
@@ -12,14 +12,14 @@
         {%- if e.is_flat() %}
         return when (buf.getInt()) {
             {%- for variant in e.variants() %}
-            {{ loop.index }} -> {{ type_name }}.{{ variant|error_variant_name }}({{ Type::String.borrow()|read_fn(ci) }}(buf))
+            {{ loop.index }} -> {{ type_name }}.{{ variant|error_variant_name(name, config) }}({{ Type::String.borrow()|read_fn(ci) }}(buf))
             {%- endfor %}
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
         {%- else %}
         return when (buf.getInt()) {
             {%- for variant in e.variants() %}
-            {{ loop.index }} -> {{ type_name }}.{{ variant|error_variant_name }}({% if variant.has_fields() %}
+            {{ loop.index }} -> {{ type_name }}.{{ variant|error_variant_name(name, config) }}({% if variant.has_fields() %}
                 {% for field in variant.fields() -%}
                 {{ field|read_fn(ci) }}(buf),
                 {% endfor -%}
@@ -36,11 +36,12 @@
         {%- else %}
         return when (value) {
             {%- for variant in e.variants() %}
-            is {{ type_name }}.{{ variant|error_variant_name }} -> (
+            {%- let variant_path = "{}::{}"|format(name, variant.name()) %}
+            is {{ type_name }}.{{ variant|error_variant_name(name, config) }} -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
                 {%- for field in variant.fields() %}
-                + {{ field|allocation_size_fn }}(value.{% call kt::field_name(field, loop.index) %})
+                + {{ field|allocation_size_fn }}(value.{% call kt::field_name(field, variant_path, loop.index) %})
                 {%- endfor %}
             )
             {%- endfor %}
@@ -51,10 +52,11 @@
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         when (value) {
             {%- for variant in e.variants() %}
-            is {{ type_name }}.{{ variant|error_variant_name }} -> {
+            {%- let variant_path = "{}::{}"|format(name, variant.name()) %}
+            is {{ type_name }}.{{ variant|error_variant_name(name, config) }} -> {
                 buf.putInt({{ loop.index }})
                 {%- for field in variant.fields() %}
-                {{ field|write_fn(ci) }}(value.{% call kt::field_name(field, loop.index) %}, buf)
+                {{ field|write_fn(ci) }}(value.{% call kt::field_name(field, variant_path, loop.index) %}, buf)
                 {%- endfor %}
                 Unit
             }
@@ -1,26 +1,11 @@
+{%- if !config.has_shared_platform_runtime(module_name) %}
+{% include "ffi/FfiConverter.kt" %}
+{%- endif %}
 
-{{ visibility() }}interface FfiConverter<KotlinType, FfiType> {
-    // Convert an FFI type to a Kotlin type
-    {{ visibility() }}fun lift(value: FfiType): KotlinType
-
-    // Convert an Kotlin type to an FFI type
-    {{ visibility() }}fun lower(value: KotlinType): FfiType
-
-    // Read a Kotlin type from a `ByteBuffer`
-    {{ visibility() }}fun read(buf: ByteBuffer): KotlinType
-
-    // Calculate bytes to allocate when creating a `RustBuffer`
-    //
-    // This must return at least as many bytes as the write() function will
-    // write. It can return more bytes than needed, for example when writing
-    // Strings we can't know the exact bytes needed until we the UTF-8
-    // encoding, so we pessimistically allocate the largest size possible (3
-    // bytes per codepoint).  Allocating extra bytes is not really a big deal
-    // because the `RustBuffer` is short-lived.
-    {{ visibility() }}fun allocationSize(value: KotlinType): ULong
-
-    // Write a Kotlin type to a `ByteBuffer`
-    {{ visibility() }}fun write(value: KotlinType, buf: ByteBuffer)
+// FfiConverter that uses `RustBuffer` as the FfiType
+{{ visibility() }}interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBufferByValue> {
+    override fun lift(value: RustBufferByValue): KotlinType = liftFromRustBuffer(value)
+    override fun lower(value: KotlinType): RustBufferByValue = lowerIntoRustBuffer(value)
 
     // Lower a value into a `RustBuffer`
     //
@@ -42,7 +27,7 @@
     // Lift a value from a `RustBuffer`.
     //
     // This here mostly because of the symmetry with `lowerIntoRustBuffer()`.
-    // It's currently only used by the `FfiConverterRustBuffer` class below.
+    // It's currently only used by `lift()` above.
     {{ visibility() }}fun liftFromRustBuffer(rbuf: RustBufferByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
@@ -56,9 +41,3 @@
         }
     }
 }
-
-// FfiConverter that uses `RustBuffer` as the FfiType
-{{ visibility() }}interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBufferByValue> {
-    override fun lift(value: RustBufferByValue): KotlinType = liftFromRustBuffer(value)
-    override fun lower(value: KotlinType): RustBufferByValue = lowerIntoRustBuffer(value)
-}
@@ -1,4 +1,4 @@
-
+{%- if !config.has_shared_platform_runtime(module_name) %}
 {{ visibility() }}object FfiConverterFloat: FfiConverter<Float, Float> {
     override fun lift(value: Float): Float {
         return value
@@ -18,3 +18,4 @@
         buf.putFloat(value)
     }
 }
+{%- endif %}
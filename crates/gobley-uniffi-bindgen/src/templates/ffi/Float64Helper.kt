@@ -1,4 +1,4 @@
-
+{%- if !config.has_shared_platform_runtime(module_name) %}
 {{ visibility() }}object FfiConverterDouble: FfiConverter<Double, Double> {
     override fun lift(value: Double): Double {
         return value
@@ -18,3 +18,4 @@
         buf.putDouble(value)
     }
 }
+{%- endif %}
@@ -50,16 +50,64 @@ internal fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRust
         // when the rust code sees a panic, it tries to construct a rustbuffer
         // with the message.  but if that code panics, then it just sends back
         // an empty buffer.
-        if (status.errorBuf.len > 0) {
-            throw InternalException({{ Type::String.borrow()|lift_fn }}(status.errorBuf))
+        val exception = if (status.errorBuf.len > 0) {
+            RustPanicException({{ Type::String.borrow()|lift_fn }}(status.errorBuf), uniffiRustBacktrace())
         } else {
-            throw InternalException("Rust panic")
+            RustPanicException("Rust panic", uniffiRustBacktrace())
         }
+        {%- if config.abort_on_panic() %}
+        uniffiAbortOnPanic(exception)
+        {%- else %}
+        throw exception
+        {%- endif %}
     } else {
         throw InternalException("Unknown rust call status: $status.code")
     }
 }
 
+// The Rust backtrace of the last panic, as returned by `panic_backtrace_function`
+{%- match config.panic_backtrace_function() %}
+{%- when Some(name) %}
+{%- let func = ci.get_function_definition(name).unwrap() %}
+// The function is called without `uniffiCheckCallStatus`, so a panic inside it returns `null`
+// instead of looking up the backtrace of that panic again.
+private fun uniffiRustBacktrace(): String? {
+    return UniffiRustCallStatusHelper.withReference() { status ->
+        val returnValue = UniffiLib.INSTANCE.{{ func.ffi_func().name() }}(status)
+        if (status.isSuccess()) {
+            {{ func.return_type().unwrap()|lift_fn }}(returnValue)
+        } else {
+            RustBufferHelper.free(status.errorBuf)
+            null
+        }
+    }
+}
+{%- when None %}
+private fun uniffiRustBacktrace(): String? = null
+{%- endmatch %}
+
+{%- if config.abort_on_panic() %}
+
+// Crash instead of continuing with the state the panic may have corrupted, since `abort_on_panic`
+// is set
+private fun uniffiAbortOnPanic(exception: RustPanicException): Nothing {
+{%- if module_name == "native" %}
+    platform.posix.fputs("Rust panic: ${exception.message}\n", platform.posix.stderr)
+    exception.rustBacktrace?.let { platform.posix.fputs("$it\n", platform.posix.stderr) }
+    platform.posix.abort()
+    throw exception
+{%- else if module_name == "js" || module_name == "wasmJs" %}
+    // There is no way to abort a JavaScript or WebAssembly runtime.
+    throw exception
+{%- else %}
+    System.err.println("Rust panic: ${exception.message}")
+    exception.rustBacktrace?.let { System.err.println(it) }
+    Runtime.getRuntime().halt(134)
+    throw exception
+{%- endif %}
+}
+{%- endif %}
+
 // UniffiRustCallStatusErrorHandler implementation for times when we don't expect a CALL_ERROR
 {{ visibility() }}object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(errorBuf: RustBufferByValue): InternalException {
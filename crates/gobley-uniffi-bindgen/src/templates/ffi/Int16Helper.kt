@@ -1,4 +1,4 @@
-
+{%- if !config.has_shared_platform_runtime(module_name) %}
 {{ visibility() }}object FfiConverterShort: FfiConverter<Short, Short> {
     override fun lift(value: Short): Short {
         return value
@@ -18,3 +18,4 @@
         buf.putShort(value)
     }
 }
+{%- endif %}
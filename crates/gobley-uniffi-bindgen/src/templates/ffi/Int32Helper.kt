@@ -1,4 +1,4 @@
-
+{%- if !config.has_shared_platform_runtime(module_name) %}
 {{ visibility() }}object FfiConverterInt: FfiConverter<Int, Int> {
     override fun lift(value: Int): Int {
         return value
@@ -18,3 +18,4 @@
         buf.putInt(value)
     }
 }
+{%- endif %}
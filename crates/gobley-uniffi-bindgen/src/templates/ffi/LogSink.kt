@@ -0,0 +1,38 @@
+{%- if module_name == "android" %}
+
+/**
+ * Writes the records to Logcat, using their targets as the tags.
+ */
+{{ visibility() }}object LogcatLogSink : LogSink {
+    override fun log(record: LogRecord) {
+        val priority = when (record.level) {
+            LogLevel.ERROR -> android.util.Log.ERROR
+            LogLevel.WARN -> android.util.Log.WARN
+            LogLevel.INFO -> android.util.Log.INFO
+            LogLevel.DEBUG -> android.util.Log.DEBUG
+            LogLevel.TRACE -> android.util.Log.VERBOSE
+        }
+        android.util.Log.println(priority, record.target, record.message)
+    }
+}
+{%- else if module_name == "jvm" %}
+
+/**
+ * Writes the records to the SLF4J loggers named after their targets.
+ *
+ * SLF4J is an optional dependency: the project compiling the bindings needs `org.slf4j:slf4j-api`
+ * as a `compileOnly` dependency, and only the applications using this sink need it at runtime.
+ */
+{{ visibility() }}object Slf4jLogSink : LogSink {
+    override fun log(record: LogRecord) {
+        val logger = org.slf4j.LoggerFactory.getLogger(record.target)
+        when (record.level) {
+            LogLevel.ERROR -> logger.error(record.message)
+            LogLevel.WARN -> logger.warn(record.message)
+            LogLevel.INFO -> logger.info(record.message)
+            LogLevel.DEBUG -> logger.debug(record.message)
+            LogLevel.TRACE -> logger.trace(record.message)
+        }
+    }
+}
+{%- endif %}
@@ -1,3 +1,4 @@
+{%- if config.runtime_package().is_none() %}
 
 // The cleaner interface for Object finalization code to run.
 // This is the entry point to any implementation that we're using.
@@ -14,3 +15,4 @@
 
     {{ visibility() }}companion object
 }
+{%- endif %}
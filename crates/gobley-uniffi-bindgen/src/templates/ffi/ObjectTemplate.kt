@@ -1,8 +1,8 @@
 
 {%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let interface_name = self::object_interface_name(ci, obj) %}
-{%- let impl_class_name = self::object_impl_name(ci, obj) %}
-{%- let methods = obj.methods() %}
+{%- let interface_name = self::object_interface_name(ci, config, obj) %}
+{%- let impl_class_name = self::object_impl_name(ci, config, obj) %}
+{%- let methods = config.included_methods(obj) %}
 {%- let interface_docstring = obj.docstring() %}
 {%- let is_error = ci.is_name_used_as_error(name) %}
 {%- let ffi_converter_name = obj|ffi_converter_name %}
@@ -27,13 +27,18 @@
 {% else -%}
 {{ visibility() }}{% call emit_actual %}open class {{ impl_class_name }}: Disposable, {{ interface_name }}
 {%- for t in obj.trait_impls() -%}
-, {{ self::trait_interface_name(ci, t.trait_name)? }}
+, {{ self::trait_interface_name(ci, config, t.trait_name)? }}
 {%- endfor %} {
 {%- endif %}
 
     {{ visibility() }}constructor(pointer: Pointer) {
         this.pointer = pointer
+        {%- if config.debug_object_tracking %}
+        this.uniffiTrackingId = UniffiLeakReport.track("{{ impl_class_name }}")
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiPointerDestroyer(pointer, uniffiTrackingId))
+        {%- else %}
         this.cleanable = UniffiLib.CLEANER.register(this, UniffiPointerDestroyer(pointer))
+        {%- endif %}
     }
 
     /**
@@ -43,6 +48,9 @@
      */
     {{ visibility() }}{% call emit_actual %}constructor(noPointer: NoPointer) {
         this.pointer = null
+        {%- if config.debug_object_tracking %}
+        this.uniffiTrackingId = null
+        {%- endif %}
         this.cleanable = UniffiLib.CLEANER.register(this, UniffiPointerDestroyer(null))
     }
 
@@ -52,6 +60,9 @@
     // Note no constructor generated for this object as it is async.
     {%-     else %}
     {%- call kt::docstring(cons, 4) %}
+    {%- if config.has_java_interop(module_name) && config.kotlin_multiplatform && cons|has_default_arguments %}
+    @kotlin.jvm.JvmOverloads
+    {%- endif %}
     {{ visibility() }}{% call emit_actual %}constructor({% call kt::arg_list(cons, false) -%}) : this(
         {% call kt::to_ffi_call(cons, 8) %}
     )
@@ -61,6 +72,9 @@
 
     protected val pointer: Pointer?
     protected val cleanable: UniffiCleaner.Cleanable
+    {%- if config.debug_object_tracking %}
+    private val uniffiTrackingId: Long?
+    {%- endif %}
 
     private val wasDestroyed: kotlinx.atomicfu.AtomicBoolean = kotlinx.atomicfu.atomic(false)
     private val callCounter: kotlinx.atomicfu.AtomicLong = kotlinx.atomicfu.atomic(1L)
@@ -80,6 +94,9 @@
         // Only allow a single call to this method.
         // TODO: maybe we should log a warning if called more than once?
         if (this.wasDestroyed.compareAndSet(false, true)) {
+            {%- if config.debug_object_tracking %}
+            uniffiTrackingId?.let { UniffiLeakReport.closed(it) }
+            {%- endif %}
             // This decrement always matches the initial count of 1 given at creation time.
             if (this.callCounter.decrementAndGet() == 0L) {
                 cleanable.clean()
@@ -116,8 +133,16 @@
 
     // Use a static inner class instead of a closure so as not to accidentally
     // capture `this` as part of the cleanable's action.
-    private class UniffiPointerDestroyer(private val pointer: Pointer?) : Disposable {
+    private class UniffiPointerDestroyer(
+        private val pointer: Pointer?,
+        {%- if config.debug_object_tracking %}
+        private val trackingId: Long? = null,
+        {%- endif %}
+    ) : Disposable {
         override fun destroy() {
+            {%- if config.debug_object_tracking %}
+            trackingId?.let { UniffiLeakReport.freed(it) }
+            {%- endif %}
             pointer?.let { ptr ->
                 uniffiRustCall { status ->
                     UniffiLib.INSTANCE.{{ obj.ffi_object_free().name() }}(ptr, status)
@@ -132,10 +157,18 @@
         }!!
     }
 
-    {% for meth in obj.methods() -%}
-    {%- call kt::func_decl_with_body(actual_override, meth, 4) -%}
+    {% for meth in config.included_methods(obj) -%}
+    {%- call kt::func_decl_with_body(actual_override, meth, name, 4) -%}
     {% endfor %}
 
+    {%- if config.has_java_interop_futures(module_name) %}
+    {%- for meth in config.included_methods(obj) %}
+    {%- if meth.is_async() %}
+    {%- call kt::future_wrapper(meth, name, impl_class_name, 4) %}
+    {%- endif %}
+    {%- endfor %}
+    {%- endif %}
+
     {%- for tm in obj.uniffi_traits() %}
     {%-     match tm %}
     {%         when UniffiTrait::Display { fmt } %}
@@ -158,10 +191,10 @@
     {%- endfor %}
 
     {# XXX - "companion object" confusion? How to have alternate constructors *and* be an error? #}
-    {% if !obj.alternate_constructors().is_empty() -%}
+    {% if !config.included_constructors(obj).is_empty() -%}
     {{ visibility() }}{% call emit_actual %}companion object {
-        {% for cons in obj.alternate_constructors() -%}
-        {%- call kt::func_decl_with_body(actual, cons, 8) %}
+        {% for cons in config.included_constructors(obj) -%}
+        {%- call kt::func_decl_with_body(actual, cons, name, 8) %}
         {% endfor %}
     }
     {% else %}
@@ -0,0 +1,15 @@
+{%- if module_name == "android" %}
+
+{{ visibility() }}{% if config.kotlin_multiplatform %}actual {% endif %}typealias UniffiParcelize = kotlinx.parcelize.Parcelize
+
+{{ visibility() }}{% if config.kotlin_multiplatform %}actual {% endif %}typealias UniffiParcelable = android.os.Parcelable
+{%- else %}
+{%- if !config.kotlin_multiplatform %}
+
+@Target(AnnotationTarget.CLASS)
+@Retention(AnnotationRetention.BINARY)
+{{ visibility() }}annotation class UniffiParcelize
+{%- endif %}
+
+{{ visibility() }}{% if config.kotlin_multiplatform %}actual {% endif %}interface UniffiParcelable
+{%- endif %}
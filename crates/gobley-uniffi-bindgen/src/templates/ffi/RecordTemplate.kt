@@ -1,12 +1,16 @@
 
 {%- let rec = ci.get_record_definition(name).unwrap() %}
+{%- if config.compose_immutable_collections() %}
+{{- self.add_import("kotlinx.collections.immutable.toImmutableList") }}
+{{- self.add_import("kotlinx.collections.immutable.toImmutableMap") }}
+{%- endif %}
 
 {{ visibility() }}object {{ rec|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     override fun read(buf: ByteBuffer): {{ type_name }} {
         {%- if rec.has_fields() %}
         return {{ type_name }}(
         {%- for field in rec.fields() %}
-            {{ field|read_fn(ci) }}(buf),
+            {{ field|read_fn(ci) }}(buf){{ field|record_field_conversion(config) }},
         {%- endfor %}
         )
         {%- else %}
@@ -16,13 +20,48 @@
 
     override fun allocationSize(value: {{ type_name }}): ULong = {%- if rec.has_fields() %} (
         {%- for field in rec.fields() %}
-            {{ field|allocation_size_fn }}(value.{{ field.name()|var_name }}){% if !loop.last %} +{% endif %}
+            {{ field|allocation_size_fn }}(value.{{ field.name()|field_var_name(name, config) }}){% if !loop.last %} +{% endif %}
         {%- endfor %}
     ) {%- else %} 0UL {%- endif %}
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         {%- for field in rec.fields() %}
-        {{ field|write_fn(ci) }}(value.{{ field.name()|var_name }}, buf)
+        {{ field|write_fn(ci) }}(value.{{ field.name()|field_var_name(name, config) }}, buf)
         {%- endfor %}
     }
 }
+
+{%- if config.has_java_interop(module_name) && rec|has_default_fields %}
+
+/**
+ * Builds [{{ type_name }}] from Java, where the fields with default values can't be omitted.
+ */
+{{ visibility() }}class {{ type_name }}Builder(
+    {%- for field in rec.fields() %}
+    {%- if field.default_value().is_none() %}
+    private var {{ field.name()|field_var_name(name, config) }}: {{ field|record_field_type_name(ci, config) }},
+    {%- endif %}
+    {%- endfor %}
+) {
+    {%- for field in rec.fields() %}
+    {%- match field.default_value() %}
+    {%- when Some with(literal) %}
+    private var {{ field.name()|field_var_name(name, config) }}: {{ field|record_field_type_name(ci, config) }} = {{ literal|render_record_field_literal(field, ci, config) }}
+    {%- else %}
+    {%- endmatch %}
+    {%- endfor %}
+    {%- for field in rec.fields() %}
+    {%- let field_name = field.name()|field_var_name(name, config) %}
+
+    {{ visibility() }}fun {{ field_name }}({{ field_name }}: {{ field|record_field_type_name(ci, config) }}): {{ type_name }}Builder = apply {
+        this.{{ field_name }} = {{ field_name }}
+    }
+    {%- endfor %}
+
+    {{ visibility() }}fun build(): {{ type_name }} = {{ type_name }}(
+        {%- for field in rec.fields() %}
+        {{ field.name()|field_var_name(name, config) }},
+        {%- endfor %}
+    )
+}
+{%- endif %}
@@ -1,5 +1,8 @@
 {%- if config.kotlin_multiplatform -%}
-{%- call kt::func_decl_with_body("actual", func, 0) -%}
+{%- call kt::func_decl_with_body("actual", func, "", 0) -%}
 {%- else -%}
-{%- call kt::func_decl_with_body("", func, 0) -%}
+{%- call kt::func_decl_with_body("", func, "", 0) -%}
+{%- endif %}
+{%- if func.is_async() && config.has_java_interop_futures(module_name) %}
+{%- call kt::future_wrapper(func, "", "", 0) %}
 {%- endif %}
\ No newline at end of file
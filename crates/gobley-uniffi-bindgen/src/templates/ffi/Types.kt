@@ -0,0 +1,145 @@
+
+{%- import "macros.kt" as kt %}
+
+{#-
+ # The type helpers shared by the bindings calling Rust through the C ABI. The bindings of each
+ # platform extend this template, filling in the blocks with the templates specific to them.
+ #}
+
+{%- block callback_runtime %}{% endblock %}
+
+{%- if ci.has_object_definitions() %}
+{%- block object_cleaner_helper %}{% endblock %}
+{%- endif %}
+
+{%- for type_ in self.generated_types() %}
+{{- self.split_output_section(type_) }}
+{{- self.visibility_section(type_) }}
+{%- let type_name = type_|type_name(ci, config) %}
+{%- let ffi_converter_name = type_|ffi_converter_name %}
+{%- let canonical_type_name = type_|canonical_name %}
+{%- let contains_object_references = ci.item_contains_object_references(type_) %}
+
+{#
+ # Map `Type` instances to an include statement for that type.
+ #
+ # There is a companion match in `KotlinCodeOracle::create_code_type()` which performs a similar function for the
+ # Rust code.
+ #
+ #   - When adding additional types here, make sure to also add a match arm to that function.
+ #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+ #}
+
+{%- match type_ %}
+
+{%- when Type::Boolean %}
+{%- include "ffi/BooleanHelper.kt" %}
+
+{%- when Type::Int8 %}
+{%- include "ffi/Int8Helper.kt" %}
+
+{%- when Type::Int16 %}
+{%- include "ffi/Int16Helper.kt" %}
+
+{%- when Type::Int32 %}
+{%- include "ffi/Int32Helper.kt" %}
+
+{%- when Type::Int64 %}
+{%- include "ffi/Int64Helper.kt" %}
+
+{%- when Type::UInt8 %}
+{%- include "ffi/UInt8Helper.kt" %}
+
+{%- when Type::UInt16 %}
+{%- include "ffi/UInt16Helper.kt" %}
+
+{%- when Type::UInt32 %}
+{%- include "ffi/UInt32Helper.kt" %}
+
+{%- when Type::UInt64 %}
+{%- include "ffi/UInt64Helper.kt" %}
+
+{%- when Type::Float32 %}
+{%- include "ffi/Float32Helper.kt" %}
+
+{%- when Type::Float64 %}
+{%- include "ffi/Float64Helper.kt" %}
+
+{%- when Type::String %}
+{%- include "ffi/StringHelper.kt" %}
+
+{%- when Type::Bytes %}
+{%- include "ffi/ByteArrayHelper.kt" %}
+
+{%- when Type::Enum { name, module_path } %}
+{%- let e = ci.get_enum_definition(name).unwrap() %}
+{%- if !ci.is_name_used_as_error(name) %}
+{% include "ffi/EnumTemplate.kt" %}
+{%- else %}
+{% include "ffi/ErrorTemplate.kt" %}
+{%- endif -%}
+
+{%- when Type::Object { module_path, name, .. } %}
+{% include "ffi/ObjectTemplate.kt" %}
+{%- block trait_interface_impl %}{% endblock %}
+
+{%- when Type::Record { name, module_path } %}
+{% include "ffi/RecordTemplate.kt" %}
+
+{%- when Type::Optional { inner_type } %}
+{% include "ffi/OptionalTemplate.kt" %}
+
+{%- when Type::Sequence { inner_type } %}
+{% include "ffi/SequenceTemplate.kt" %}
+
+{%- when Type::Map { key_type, value_type } %}
+{% include "ffi/MapTemplate.kt" %}
+
+{%- when Type::CallbackInterface { module_path, name } %}
+{%- block callback_interface %}{% endblock %}
+
+{%- when Type::Timestamp %}
+{% include "ffi/TimestampHelper.kt" %}
+
+{%- when Type::Duration %}
+{% include "ffi/DurationHelper.kt" %}
+
+{%- when Type::Custom { module_path, name, builtin } %}
+{% include "ffi/CustomTypeTemplate.kt" %}
+
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
+{{- self.split_output_section_end() }}
+{{- self.visibility_section_end() }}
+
+{%- for type_ in ci.iter_external_types() %}
+{%- let name = type_.name().unwrap() %}
+{%- let module_path = type_.module_path().unwrap() %}
+{%- block external_type %}{% endblock %}
+{%- endfor %}
+
+{%- if ci.has_async_fns() %}
+{# Import types needed for async support #}
+{{ self.add_import("kotlin.coroutines.resume") }}
+{{ self.add_import("kotlinx.coroutines.launch") }}
+{{ self.add_import("kotlinx.coroutines.suspendCancellableCoroutine") }}
+{{ self.add_import("kotlinx.coroutines.CancellableContinuation") }}
+{{ self.add_import("kotlinx.coroutines.DelicateCoroutinesApi") }}
+{{ self.add_import("kotlinx.coroutines.Job") }}
+{{ self.add_import("kotlinx.coroutines.GlobalScope") }}
+{{ self.add_import("kotlinx.coroutines.withContext") }}
+{{ self.add_import("kotlinx.coroutines.IO") }}
+{{ self.add_import("kotlinx.coroutines.Dispatchers") }}
+{%- endif %}
+
+{%- if config.has_suspend_wrappers(ci) && module_name != "js" && module_name != "wasmJs" %}
+{# Import the default dispatcher of the suspend wrappers #}
+{{ self.add_import("kotlinx.coroutines.IO") }}
+{{ self.add_import("kotlinx.coroutines.Dispatchers") }}
+{%- endif %}
+
+{%- if config.has_java_interop_futures(module_name) && ci.has_async_fns() %}
+{# Import the builder of the CompletableFuture variants of async functions #}
+{{ self.add_import("kotlinx.coroutines.future.future") }}
+{%- endif %}
@@ -0,0 +1,111 @@
+{% if module_name == "wasmJs" -%}
+{% for func in ci.iter_ffi_function_definitions() -%}
+{%- if func|ffi_func_supported_js -%}
+{%- let returns_indirectly = func|ffi_returns_indirectly_js -%}
+@JsFun("(exports, ...args) => exports.{{ func.name() }}(...args)")
+private external fun uniffiWasm_{{ func.name() }}(
+    exports: JsAny,
+    {%- if returns_indirectly %}
+    uniffiReturnValue: Int,
+    {%- endif %}
+    {%- for arg in func.arguments() %}
+    {{ arg.name()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_wasm_js }},
+    {%- endfor %}
+    {%- if func.has_rust_call_status_arg() %}
+    uniffiCallStatus: Int,
+    {%- endif %}
+): {% if returns_indirectly -%}
+Unit
+{%- else if let Some(return_type) = func.return_type() -%}
+{{- return_type|ffi_type_name_wasm_js -}}
+{%- else -%}
+Unit
+{%- endif %}
+
+{% endif -%}
+{% endfor %}
+{% endif -%}
+internal interface UniffiLib {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            {% if self.initialization_fns(ci).is_empty() -%}
+            UniffiLibInstance()
+            {%- else -%}
+            UniffiLibInstance().also { lib ->
+            {%- for init_fn in self.initialization_fns(ci) %}
+                {{ init_fn }}
+            {%- endfor %}
+            }
+            {%- endif %}
+        }
+        {% if ci.contains_object_types() %}
+        // The Cleaner for the whole library
+        internal val CLEANER: UniffiCleaner by lazy {
+            UniffiCleaner.create()
+        }
+        {%- endif %}
+    }
+
+    {% for func in ci.iter_ffi_function_definitions() -%}
+    {%- if func|ffi_func_supported_js -%}
+    fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() %}{% when Some(return_type) %}{{ return_type.borrow()|ffi_type_name_by_value(ci) }}{% when None %}Unit{% endmatch %}
+    {% endif -%}
+    {% endfor %}
+}
+
+internal class UniffiLibInstance: UniffiLib {
+    {% for func in ci.iter_ffi_function_definitions() -%}
+    {%- if func|ffi_func_supported_js -%}
+    {%- let returns_indirectly = func|ffi_returns_indirectly_js -%}
+    override fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() -%}
+    {%- when Some(return_type) -%}
+    {{- return_type.borrow()|ffi_type_name_by_value(ci) -}}
+    {%- when None -%}
+    Unit
+    {%- endmatch %} = uniffiWithWasmStackFrame { frame ->
+        {%- if returns_indirectly %}
+        val uniffiReturnValue = frame.alloc(UNIFFI_RUST_BUFFER_SIZE)
+        {%- endif %}
+        {%- if module_name == "wasmJs" %}
+        uniffiWasm_{{ func.name() }}(
+            uniffiWasmExports,
+        {%- else %}
+        uniffiWasmExports.{{ func.name() }}(
+        {%- endif %}
+            {%- if returns_indirectly %}
+            uniffiReturnValue,
+            {%- endif %}
+            {%- for arg in func.arguments() %}
+            {%- let arg_name = arg.name()|var_name %}
+            {%- if module_name == "wasmJs" %}
+            {{ arg.type_().borrow()|ffi_lower_into_wasm_wasm_js(arg_name, ci) }},
+            {%- else %}
+            {{ arg.type_().borrow()|ffi_lower_into_wasm_js(arg_name, ci) }},
+            {%- endif %}
+            {%- endfor %}
+            {%- if func.has_rust_call_status_arg() %}
+            uniffiCallStatus.address,
+            {%- endif %}
+        {%- if module_name == "wasmJs" %}
+        ){{ func|ffi_lift_from_wasm_wasm_js }}
+        {%- else %}
+        ){{ func|ffi_lift_from_wasm_js }}
+        {%- endif %}
+        {%- if returns_indirectly %}
+        RustBuffer(uniffiReturnValue).readValue()
+        {%- if let Some(return_type) = func.return_type() -%}
+        {{- return_type|ffi_cast_to_external_rust_buffer_if_needed(ci) -}}
+        {%- endif %}
+        {%- endif %}
+    }
+    {% endif -%}
+    {% endfor %}
+}
+
+{{ visibility() }}fun uniffiEnsureInitialized() {
+    UniffiLib.INSTANCE
+}
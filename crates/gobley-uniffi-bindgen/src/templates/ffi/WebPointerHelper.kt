@@ -0,0 +1,5 @@
+
+// Addresses in the linear memory of 32-bit WebAssembly modules.
+internal typealias Pointer = Int
+internal val NullPointer: Pointer? = null
+internal fun kotlin.Long.toPointer(): Pointer = toInt()
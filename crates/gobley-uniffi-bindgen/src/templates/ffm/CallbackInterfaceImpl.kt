@@ -0,0 +1 @@
+{% include "android+jvm/CallbackInterfaceImpl.kt" %}
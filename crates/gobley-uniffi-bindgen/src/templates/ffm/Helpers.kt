@@ -0,0 +1,57 @@
+{% include "ffi/Helpers.kt" %}
+
+// struct RustCallStatus { code: i8, error_buf: RustBuffer }
+internal val UNIFFI_RUST_CALL_STATUS_LAYOUT: StructLayout = uniffiStructLayout(
+    ValueLayout.JAVA_BYTE.withName("code"),
+    UNIFFI_RUST_BUFFER_LAYOUT.withName("errorBuf"),
+)
+private val UNIFFI_RUST_CALL_STATUS_CODE_OFFSET = UNIFFI_RUST_CALL_STATUS_LAYOUT.uniffiOffsetOf("code")
+private val UNIFFI_RUST_CALL_STATUS_ERROR_BUF_OFFSET = UNIFFI_RUST_CALL_STATUS_LAYOUT.uniffiOffsetOf("errorBuf")
+
+internal open class UniffiRustCallStatusStruct(
+    internal val segment: MemorySegment,
+) {
+    internal var code: Byte
+        get() = segment.get(ValueLayout.JAVA_BYTE, UNIFFI_RUST_CALL_STATUS_CODE_OFFSET)
+        set(value) = segment.set(ValueLayout.JAVA_BYTE, UNIFFI_RUST_CALL_STATUS_CODE_OFFSET, value)
+
+    internal var errorBuf: RustBufferByValue
+        get() = RustBufferByValue(
+            uniffiCopy(
+                segment.asSlice(UNIFFI_RUST_CALL_STATUS_ERROR_BUF_OFFSET, UNIFFI_RUST_BUFFER_LAYOUT.byteSize()),
+                UNIFFI_RUST_BUFFER_LAYOUT,
+            )
+        )
+        set(value) = MemorySegment.copy(
+            value.segment,
+            0L,
+            segment,
+            UNIFFI_RUST_CALL_STATUS_ERROR_BUF_OFFSET,
+            UNIFFI_RUST_BUFFER_LAYOUT.byteSize(),
+        )
+}
+
+internal class UniffiRustCallStatus(
+    segment: MemorySegment,
+): UniffiRustCallStatusStruct(segment)
+
+internal class UniffiRustCallStatusByValue(
+    segment: MemorySegment,
+): UniffiRustCallStatusStruct(segment) {
+    internal constructor(
+        code: Byte,
+        errorBuf: RustBufferByValue,
+    ): this(uniffiAllocate(UNIFFI_RUST_CALL_STATUS_LAYOUT)) {
+        this.code = code
+        this.errorBuf = errorBuf
+    }
+}
+
+internal object UniffiRustCallStatusHelper {
+    internal fun allocValue() = UniffiRustCallStatusByValue(uniffiAllocate(UNIFFI_RUST_CALL_STATUS_LAYOUT))
+    internal fun <U> withReference(block: (UniffiRustCallStatus) -> U): U {
+        return Arena.ofConfined().use { arena ->
+            block(UniffiRustCallStatus(arena.allocate(UNIFFI_RUST_CALL_STATUS_LAYOUT)))
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// Define FFI callback types
+
+{%- for def in ci.ffi_definitions() %}
+{%- match def %}
+{%- when FfiDefinition::CallbackFunction(callback) %}
+{%- let callback_name = callback.name()|ffi_callback_name %}
+internal interface {{ callback_name }} {
+    public fun callback(
+        {%- for arg in callback.arguments() -%}
+        {{ arg.name().borrow()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) }},
+        {%- endfor -%}
+        {%- if callback.has_rust_call_status_arg() -%}
+        uniffiCallStatus: UniffiRustCallStatus,
+        {%- endif -%}
+    )
+    {%- if let Some(return_type) = callback.return_type() -%}
+    : {{ return_type|ffi_type_name_by_value(ci) }}
+    {%- endif %}
+}
+
+// Adapts the callback to the carrier types of the upcall stub.
+internal class {{ callback_name }}Upcall(private val callback: {{ callback_name }}) {
+    fun invoke(
+        {%- for arg in callback.arguments() %}
+        {{ arg.name().borrow()|var_name }}: {{ arg.type_().borrow()|ffm_carrier }},
+        {%- endfor %}
+        {%- if callback.has_rust_call_status_arg() %}
+        uniffiCallStatus: MemorySegment,
+        {%- endif %}
+    )
+    {%- if let Some(return_type) = callback.return_type() -%}
+    : {{ return_type|ffm_carrier }}
+    {%- endif %} {
+        {% if callback.return_type().is_some() %}val uniffiResult = {% endif -%}
+        callback.callback(
+            {%- for arg in callback.arguments() %}
+            {%- let arg_name = arg.name().borrow()|var_name %}
+            {{ arg.type_().borrow()|ffm_lift_copied(arg_name, ci) }},
+            {%- endfor %}
+            {%- if callback.has_rust_call_status_arg() %}
+            UniffiRustCallStatus(uniffiCallStatus.reinterpret(UNIFFI_RUST_CALL_STATUS_LAYOUT.byteSize())),
+            {%- endif %}
+        )
+        {%- if let Some(return_type) = callback.return_type() %}
+        return {{ return_type|ffm_lower("uniffiResult") }}
+        {%- endif %}
+    }
+
+    internal companion object {
+        val DESCRIPTOR: FunctionDescriptor = {{ callback|ffm_callback_descriptor }}
+        val HANDLE: MethodHandle = MethodHandles.lookup().findVirtual(
+            {{ callback_name }}Upcall::class.java,
+            "invoke",
+            {{ callback|ffm_callback_method_type }},
+        )
+    }
+}
+
+// Calls a function pointer received from Rust.
+internal class {{ callback_name }}Downcall(internal val pointer: MemorySegment) : {{ callback_name }} {
+    private val handle: MethodHandle by lazy {
+        uniffiLinker.downcallHandle(pointer, {{ callback_name }}Upcall.DESCRIPTOR)
+    }
+
+    override fun callback(
+        {%- for arg in callback.arguments() -%}
+        {{ arg.name().borrow()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) }},
+        {%- endfor -%}
+        {%- if callback.has_rust_call_status_arg() -%}
+        uniffiCallStatus: UniffiRustCallStatus,
+        {%- endif -%}
+    )
+    {%- if let Some(return_type) = callback.return_type() -%}
+    : {{ return_type|ffi_type_name_by_value(ci) }}
+    {%- endif %}
+    {%- let returns_struct = callback.return_type()|ffm_returns_struct %}
+    {%- if returns_struct %} = Arena.ofConfined().use { uniffiArena ->{% else %} { {%- endif %}
+        {% if callback.return_type().is_some() %}val uniffiResult = {% endif -%}
+        handle.invoke(
+            {%- if returns_struct %}
+            uniffiArena,
+            {%- endif %}
+            {%- for arg in callback.arguments() %}
+            {%- let arg_name = arg.name().borrow()|var_name %}
+            {{ arg.type_().borrow()|ffm_lower(arg_name) }},
+            {%- endfor %}
+            {%- if callback.has_rust_call_status_arg() %}
+            uniffiCallStatus.segment,
+            {%- endif %}
+        )
+        {%- if let Some(return_type) = callback.return_type() %}
+        {%- let carrier = "(uniffiResult as {})"|format(return_type|ffm_carrier) %}
+        {% if !returns_struct %}return {% endif %}{{ return_type|ffm_lift_copied(carrier, ci) }}
+        {%- endif %}
+    }
+}
+
+internal fun {{ callback_name }}.uniffiUpcallStub(): MemorySegment {
+    if (this is {{ callback_name }}Downcall) {
+        return pointer
+    }
+    return uniffiCreateUpcallStub(this, {{ callback_name }}Upcall.DESCRIPTOR) {
+        {{ callback_name }}Upcall.HANDLE.bindTo({{ callback_name }}Upcall(this))
+    }
+}
+{%- when FfiDefinition::Struct(ffi_struct) %}
+{%- let struct_name = ffi_struct.name()|ffi_struct_name %}
+internal open class {{ struct_name }}Struct(
+    internal val segment: MemorySegment,
+) {
+    {%- if ffi_struct|ffm_is_vtable(ci) %}
+    internal constructor(): this(Arena.global().allocate(LAYOUT))
+    {%- else %}
+    internal constructor(): this(uniffiAllocate(LAYOUT))
+    {%- endif %}
+
+    internal constructor(
+        {%- for field in ffi_struct.fields() %}
+        {{ field.name()|var_name }}: {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(ci) }},
+        {%- endfor %}
+    ): this() {
+        {%- for field in ffi_struct.fields() %}
+        this.{{ field.name()|var_name }} = {{ field.name()|var_name }}
+        {%- endfor %}
+    }
+    {%- for field in ffi_struct.fields() %}
+    {%- let offset = "{}Offset"|format(field.name()|var_name_raw) %}
+
+    internal var {{ field.name()|var_name }}: {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(ci) }}
+        get() = {{ field.type_().borrow()|ffm_get_field(offset, ci) }}
+        set(value) {
+            {{ field.type_().borrow()|ffm_set_field(offset) }}
+        }
+    {%- endfor %}
+
+    // The fields are written to the native memory as soon as they are set.
+    internal fun write() {}
+
+    internal companion object {
+        val LAYOUT: StructLayout = uniffiStructLayout(
+            {%- for field in ffi_struct.fields() %}
+            {{ field.type_().borrow()|ffm_layout }}.withName("{{ field.name()|var_name_raw }}"),
+            {%- endfor %}
+        )
+        {%- for field in ffi_struct.fields() %}
+        private val {{ field.name()|var_name_raw }}Offset = LAYOUT.uniffiOffsetOf("{{ field.name()|var_name_raw }}")
+        {%- endfor %}
+    }
+}
+
+internal typealias {{ struct_name }} = {{ struct_name }}Struct
+internal typealias {{ struct_name }}UniffiByValue = {{ struct_name }}Struct
+
+internal fun {{ struct_name }}.uniffiSetValue(other: {{ struct_name }}) {
+    MemorySegment.copy(other.segment, 0L, segment, 0L, {{ struct_name }}Struct.LAYOUT.byteSize())
+}
+
+{%- when FfiDefinition::Function(_) %}
+{# functions are handled below #}
+{%- endmatch %}
+{%- endfor %}
+
+{% include "android+jvm/LibraryLoader.kt" %}
+
+// The symbols of the libraries loaded by the class loader of the bindings.
+private val uniffiSymbolLookup: SymbolLookup by lazy {
+    {%- for dynamic_library in config.dynamic_library_dependencies(module_name) %}
+    uniffiLoadLibrary("{{ dynamic_library }}")
+    {%- endfor %}
+    uniffiLoadLibrary(findLibraryName("{{ ci.namespace() }}"))
+    SymbolLookup.loaderLookup()
+}
+
+private fun uniffiDowncallHandle(name: String, descriptor: FunctionDescriptor): MethodHandle {
+    val symbol = uniffiSymbolLookup.find(name).orElseThrow {
+        UnsatisfiedLinkError("Symbol $name not found in {{ config.cdylib_name() }}")
+    }
+    return uniffiLinker.downcallHandle(symbol, descriptor)
+}
+
+internal interface UniffiLib {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            uniffiCheckContractApiVersion()
+            {%- if !config.omit_checksums %}
+            uniffiCheckApiChecksums()
+            {%- endif %}
+            {% if self.initialization_fns(ci).is_empty() -%}
+            UniffiLibInstance()
+            {%- else -%}
+            UniffiLibInstance().also { lib ->
+            {%- for init_fn in self.initialization_fns(ci) %}
+                {{ init_fn }}
+            {%- endfor %}
+            }
+            {%- endif %}
+        }
+        {% if ci.contains_object_types() %}
+        // The Cleaner for the whole library
+        internal val CLEANER: UniffiCleaner by lazy {
+            UniffiCleaner.create()
+        }
+        {%- endif %}
+    }
+
+    {% for func in ci.iter_ffi_function_definitions_excluding_integrity_checks() -%}
+    fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() %}{% when Some(return_type) %}{{ return_type.borrow()|ffi_type_name_by_value(ci) }}{% when None %}Unit{% endmatch %}
+    {% endfor %}
+}
+
+// Method handles are created on first use, since there may be thousands of them in large crates.
+internal class UniffiLibInstance: UniffiLib {
+    {% for func in ci.iter_ffi_function_definitions_excluding_integrity_checks() -%}
+    private val {{ func.name() }}Handle: MethodHandle by lazy {
+        uniffiDowncallHandle("{{ func.name() }}", {{ func|ffm_function_descriptor }})
+    }
+
+    override fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() -%}
+    {%- when Some(return_type) -%}
+    {{- return_type.borrow()|ffi_type_name_by_value(ci) -}}
+    {%- when None -%}
+    Unit
+    {%- endmatch %}
+    {%- let returns_struct = func.return_type()|ffm_returns_struct %}
+    {%- if returns_struct %} = Arena.ofConfined().use { uniffiArena ->{% else %} { {%- endif %}
+        {% if func.return_type().is_some() %}val uniffiResult = {% endif -%}
+        {{ func.name() }}Handle.invoke(
+            {%- if returns_struct %}
+            uniffiArena,
+            {%- endif %}
+            {%- for arg in func.arguments() %}
+            {%- let arg_name = arg.name()|var_name %}
+            {{ arg.type_().borrow()|ffm_lower(arg_name) }},
+            {%- endfor %}
+            {%- if func.has_rust_call_status_arg() %}
+            uniffiCallStatus.segment,
+            {%- endif %}
+        )
+        {%- if let Some(return_type) = func.return_type() %}
+        {%- let carrier = "(uniffiResult as {})"|format(return_type|ffm_carrier) %}
+        {% if !returns_struct %}return {% endif %}{{ return_type|ffm_lift_copied(carrier, ci) }}
+        {%- endif %}
+    }
+    {% endfor %}
+}
+
+private fun uniffiCheckContractApiVersion() {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = {{ ci.uniffi_contract_version() }}
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = uniffiDowncallHandle(
+        "{{ ci.ffi_uniffi_contract_version().name() }}",
+        FunctionDescriptor.of(ValueLayout.JAVA_INT),
+    ).invoke() as Int
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+{% if !config.omit_checksums -%}
+private fun uniffiCheckApiChecksums() {
+    {%- for (name, expected_checksum) in ci.iter_checksums() %}
+    if (uniffiDowncallHandle("{{ name }}", FunctionDescriptor.of(ValueLayout.JAVA_SHORT)).invoke() as Short != {{ expected_checksum }}.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    {%- endfor %}
+}
+{%- endif %}
+
+{{ visibility() }}fun uniffiEnsureInitialized() {
+    UniffiLib.INSTANCE
+}
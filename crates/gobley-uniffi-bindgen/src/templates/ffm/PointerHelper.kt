@@ -0,0 +1,59 @@
+// Native addresses are represented by zero-length memory segments.
+internal typealias Pointer = MemorySegment
+internal val NullPointer: Pointer? = null
+internal fun Pointer.toLong(): Long = address()
+internal fun kotlin.Long.toPointer(): Pointer = MemorySegment.ofAddress(this)
+
+internal val uniffiLinker: Linker = Linker.nativeLinker()
+
+// Structs created by the bindings are backed by the Java heap, so they are freed by the garbage
+// collector like any other object. Method handles copy the structs passed by value, so they don't
+// have to be in the native memory. The memory of the structs returned from Rust is allocated in a
+// confined arena for each call, and copied to the heap before the arena is closed.
+//
+// Values passed by reference must be in the native memory. The only ones passed to Rust are
+// vtables, which are allocated in the global arena since Rust keeps them.
+internal fun uniffiAllocate(layout: MemoryLayout): MemorySegment =
+    // `long[]` segments are 8-byte aligned, which is enough for any field of an FFI struct.
+    MemorySegment.ofArray(LongArray(((layout.byteSize() + 7) / 8).toInt())).asSlice(0L, layout.byteSize())
+
+// Structs passed to upcalls, returned from downcalls, or read from other structs are only valid
+// while the memory they are read from is, so they are copied.
+internal fun uniffiCopy(segment: MemorySegment, layout: MemoryLayout): MemorySegment =
+    uniffiAllocate(layout).copyFrom(segment)
+
+// Lay out the members of a C struct, inserting the padding required by their alignments.
+internal fun uniffiStructLayout(vararg members: MemoryLayout): StructLayout {
+    val elements = mutableListOf<MemoryLayout>()
+    var offset = 0L
+    var alignment = 1L
+    for (member in members) {
+        val padding = (member.byteAlignment() - offset % member.byteAlignment()) % member.byteAlignment()
+        if (padding != 0L) {
+            elements.add(MemoryLayout.paddingLayout(padding))
+        }
+        elements.add(member)
+        offset += padding + member.byteSize()
+        alignment = maxOf(alignment, member.byteAlignment())
+    }
+    val tailPadding = (alignment - offset % alignment) % alignment
+    if (tailPadding != 0L) {
+        elements.add(MemoryLayout.paddingLayout(tailPadding))
+    }
+    return MemoryLayout.structLayout(*elements.toTypedArray())
+}
+
+internal fun StructLayout.uniffiOffsetOf(name: String): Long =
+    byteOffset(MemoryLayout.PathElement.groupElement(name))
+
+// Rust may call a function pointer at any time after receiving it, so upcall stubs are never
+// freed. Callbacks are usually singletons, so there is one stub per callback object.
+private val uniffiUpcallStubs = java.util.concurrent.ConcurrentHashMap<Any, MemorySegment>()
+
+internal fun uniffiCreateUpcallStub(
+    callback: Any,
+    descriptor: FunctionDescriptor,
+    target: () -> MethodHandle,
+): MemorySegment = uniffiUpcallStubs.computeIfAbsent(callback) {
+    uniffiLinker.upcallStub(target(), descriptor, Arena.global())
+}
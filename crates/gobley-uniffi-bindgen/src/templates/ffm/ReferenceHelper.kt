@@ -0,0 +1,42 @@
+// The equivalents of the `com.sun.jna.ptr.*ByReference` classes.
+internal class ByteByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_BYTE))
+    fun getValue(): Byte = segment.get(ValueLayout.JAVA_BYTE, 0L)
+    fun setValue(value: Byte) = segment.set(ValueLayout.JAVA_BYTE, 0L, value)
+}
+
+internal class DoubleByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_DOUBLE))
+    fun getValue(): Double = segment.get(ValueLayout.JAVA_DOUBLE, 0L)
+    fun setValue(value: Double) = segment.set(ValueLayout.JAVA_DOUBLE, 0L, value)
+}
+
+internal class FloatByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_FLOAT))
+    fun getValue(): Float = segment.get(ValueLayout.JAVA_FLOAT, 0L)
+    fun setValue(value: Float) = segment.set(ValueLayout.JAVA_FLOAT, 0L, value)
+}
+
+internal class IntByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_INT))
+    fun getValue(): Int = segment.get(ValueLayout.JAVA_INT, 0L)
+    fun setValue(value: Int) = segment.set(ValueLayout.JAVA_INT, 0L, value)
+}
+
+internal class LongByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_LONG))
+    fun getValue(): Long = segment.get(ValueLayout.JAVA_LONG, 0L)
+    fun setValue(value: Long) = segment.set(ValueLayout.JAVA_LONG, 0L, value)
+}
+
+internal class PointerByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.ADDRESS))
+    fun getValue(): Pointer? = segment.get(ValueLayout.ADDRESS, 0L).takeUnless { it.address() == 0L }
+    fun setValue(value: Pointer?) = segment.set(ValueLayout.ADDRESS, 0L, value ?: MemorySegment.NULL)
+}
+
+internal class ShortByReference(internal val segment: MemorySegment) {
+    internal constructor(): this(uniffiAllocate(ValueLayout.JAVA_SHORT))
+    fun getValue(): Short = segment.get(ValueLayout.JAVA_SHORT, 0L)
+    fun setValue(value: Short) = segment.set(ValueLayout.JAVA_SHORT, 0L, value)
+}
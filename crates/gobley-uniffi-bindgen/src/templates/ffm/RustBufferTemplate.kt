@@ -0,0 +1,80 @@
+{% include "ffi/RustBufferTemplate.kt" %}
+
+// struct RustBuffer { capacity: u64, len: u64, data: *mut u8 }
+internal val UNIFFI_RUST_BUFFER_LAYOUT: StructLayout = uniffiStructLayout(
+    ValueLayout.JAVA_LONG.withName("capacity"),
+    ValueLayout.JAVA_LONG.withName("len"),
+    ValueLayout.ADDRESS.withName("data"),
+)
+private val UNIFFI_RUST_BUFFER_CAPACITY_OFFSET = UNIFFI_RUST_BUFFER_LAYOUT.uniffiOffsetOf("capacity")
+private val UNIFFI_RUST_BUFFER_LEN_OFFSET = UNIFFI_RUST_BUFFER_LAYOUT.uniffiOffsetOf("len")
+private val UNIFFI_RUST_BUFFER_DATA_OFFSET = UNIFFI_RUST_BUFFER_LAYOUT.uniffiOffsetOf("data")
+
+{{ visibility() }}open class RustBufferStruct(
+    {{ visibility() }}val segment: MemorySegment,
+) {
+    {{ visibility() }}constructor(): this(uniffiAllocate(UNIFFI_RUST_BUFFER_LAYOUT))
+
+    // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
+    // When dealing with these fields, make sure to call `toULong()`.
+    {{ visibility() }}var capacity: Long
+        get() = segment.get(ValueLayout.JAVA_LONG, UNIFFI_RUST_BUFFER_CAPACITY_OFFSET)
+        set(value) = segment.set(ValueLayout.JAVA_LONG, UNIFFI_RUST_BUFFER_CAPACITY_OFFSET, value)
+
+    {{ visibility() }}var len: Long
+        get() = segment.get(ValueLayout.JAVA_LONG, UNIFFI_RUST_BUFFER_LEN_OFFSET)
+        set(value) = segment.set(ValueLayout.JAVA_LONG, UNIFFI_RUST_BUFFER_LEN_OFFSET, value)
+
+    {{ visibility() }}var data: Pointer?
+        get() = segment.get(ValueLayout.ADDRESS, UNIFFI_RUST_BUFFER_DATA_OFFSET)
+            .takeUnless { it.address() == 0L }
+        set(value) = segment.set(ValueLayout.ADDRESS, UNIFFI_RUST_BUFFER_DATA_OFFSET, value ?: MemorySegment.NULL)
+
+    {{ visibility() }}class ByValue(
+        segment: MemorySegment,
+    ): RustBufferStruct(segment) {
+        {{ visibility() }}constructor(): this(uniffiAllocate(UNIFFI_RUST_BUFFER_LAYOUT))
+
+        {{ visibility() }}constructor(
+            capacity: Long,
+            len: Long,
+            data: Pointer?,
+        ): this() {
+            this.capacity = capacity
+            this.len = len
+            this.data = data
+        }
+    }
+}
+
+{{ visibility() }}typealias RustBuffer = RustBufferStruct
+{{ visibility() }}typealias RustBufferByValue = RustBufferStruct.ByValue
+
+internal fun RustBuffer.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("this.len") %}
+    return ByteBuffer((data ?: return null).reinterpret(this.len).asByteBuffer())
+}
+
+internal fun RustBufferByValue.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("this.len") %}
+    return ByteBuffer((data ?: return null).reinterpret(this.len).asByteBuffer())
+}
+
+// struct ForeignBytes { len: i32, data: *const u8 }
+internal val UNIFFI_FOREIGN_BYTES_LAYOUT: StructLayout = uniffiStructLayout(
+    ValueLayout.JAVA_INT.withName("len"),
+    ValueLayout.ADDRESS.withName("data"),
+)
+
+internal class ForeignBytesByValue(
+    internal val segment: MemorySegment,
+) {
+    internal constructor(len: Int, data: Pointer?): this(uniffiAllocate(UNIFFI_FOREIGN_BYTES_LAYOUT)) {
+        segment.set(ValueLayout.JAVA_INT, UNIFFI_FOREIGN_BYTES_LAYOUT.uniffiOffsetOf("len"), len)
+        segment.set(
+            ValueLayout.ADDRESS,
+            UNIFFI_FOREIGN_BYTES_LAYOUT.uniffiOffsetOf("data"),
+            data ?: MemorySegment.NULL,
+        )
+    }
+}
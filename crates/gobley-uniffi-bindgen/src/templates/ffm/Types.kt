@@ -0,0 +1,29 @@
+{%- extends "ffi/Types.kt" %}
+
+{%- block callback_runtime %}
+{%- if ci.has_callback_definitions() %}
+{%- include "ffi/CallbackInterfaceRuntime.kt" %}
+{%- endif %}
+{%- endblock %}
+
+{%- block object_cleaner_helper %}
+{%- include "ObjectCleanerHelper.kt" %}
+{%- endblock %}
+
+{%- block trait_interface_impl %}
+{%- let obj = ci.get_object_definition(name).unwrap() %}
+{%- if obj.has_callback_interface() %}
+{%- let vtable = obj.vtable().expect("trait interface should have a vtable") %}
+{%- let vtable_methods = obj.vtable_methods() %}
+{%- let ffi_init_callback = obj.ffi_init_callback() %}
+{% include "CallbackInterfaceImpl.kt" %}
+{%- endif %}
+{%- endblock %}
+
+{%- block callback_interface %}
+{% include "android+jvm/CallbackInterfaceTemplate.kt" %}
+{%- endblock %}
+
+{%- block external_type %}
+{% include "android+jvm/ExternalTypeTemplate.kt" %}
+{%- endblock %}
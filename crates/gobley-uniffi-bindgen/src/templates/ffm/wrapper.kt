@@ -0,0 +1,80 @@
+{%- call kt::docstring_value(ci.namespace_docstring(), 0) %}
+
+@file:Suppress("RemoveRedundantBackticks")
+
+package {{ config.package_name() }}
+
+// Common helper code.
+//
+// Ideally this would live in a separate .kt file where it can be unittested etc
+// in isolation, and perhaps even published as a re-useable package.
+//
+// However, it's important that the details of how this helper code works (e.g. the
+// way that different builtin types are passed across the FFI) exactly match what's
+// expected by the Rust code on the other side of the interface. In practice right
+// now that means coming from the exact some version of `uniffi` that was used to
+// compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
+// helpers directly inline like we're doing here.
+
+import java.lang.foreign.Arena
+import java.lang.foreign.FunctionDescriptor
+import java.lang.foreign.Linker
+import java.lang.foreign.MemoryLayout
+import java.lang.foreign.MemorySegment
+import java.lang.foreign.StructLayout
+import java.lang.foreign.SymbolLookup
+import java.lang.foreign.ValueLayout
+import java.lang.invoke.MethodHandle
+import java.lang.invoke.MethodHandles
+import java.lang.invoke.MethodType
+
+{%- for req in self.imports() %}
+{{ req.render() }}
+{%- endfor %}
+{{- self.split_output_helpers_section() }}
+
+{% include "PointerHelper.kt" %}
+
+{% include "android+jvm/ByteBuffer.kt" %}
+{% include "RustBufferTemplate.kt" %}
+{% include "ffi/FfiConverterTemplate.kt" %}
+{% include "Helpers.kt" %}
+{% include "android+jvm/HandleMap.kt" %}
+{% include "ReferenceHelper.kt" %}
+
+// Contains loading, initialization code,
+// and the FFI Function declarations as method handles of the Java FFM API.
+{% include "NamespaceLibraryTemplate.kt" %}
+
+// Public interface members begin here.
+{{ type_helper_code }}
+{{- self.split_output_section_end() }}
+
+{% import "macros.kt" as kt %}
+
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
+{%- include "ffi/TopLevelFunctionTemplate.kt" %}
+{%- endfor %}
+{{- self.visibility_section_end() }}
+
+{%- if self::is_gobley_log(ci) %}
+{% include "ffi/LogSink.kt" %}
+{%- endif %}
+{{- self.split_output_helpers_section() }}
+
+// Async support
+{%- if ci.has_async_fns() %}
+{% include "android+jvm/Async.kt" %}
+{%- endif %}
+
+{%- if config.has_suspend_wrappers(ci) %}
+{% include "ffi/BlockingDispatcher.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
@@ -0,0 +1,17 @@
+// This file is generated by gobley-uniffi-bindgen. Do not edit.
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=UNIFFI_JNI_LIB_DIR");
+    // The directory containing `{{ config.cdylib_name() }}`.
+    if let Ok(lib_dir) = std::env::var("UNIFFI_JNI_LIB_DIR") {
+        println!("cargo:rustc-link-search=native={lib_dir}");
+    }
+    println!("cargo:rustc-link-lib=dylib={{ config.cdylib_name() }}");
+
+    // Find `{{ config.cdylib_name() }}` next to the shim library at runtime.
+    match std::env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("linux" | "android") => println!("cargo:rustc-link-arg=-Wl,-rpath,$ORIGIN"),
+        Ok("macos") => println!("cargo:rustc-link-arg=-Wl,-rpath,@loader_path"),
+        _ => {}
+    }
+}
@@ -0,0 +1,118 @@
+// This file is generated by gobley-uniffi-bindgen. Do not edit.
+//
+// Exports the `external` functions of `{{ config.package_name() }}.UniffiJniLib`. Each function
+// forwards its arguments to the UniFFI scaffolding of `{{ config.cdylib_name() }}`. Since JNI
+// can only pass primitive values, structs are flattened into their fields, and `RustBuffer`s
+// returned by value and `RustCallStatus`es are written back to the `long[]`s passed from Kotlin.
+
+#![allow(
+    non_snake_case,
+    unused_imports,
+    unused_variables,
+    clippy::let_unit_value,
+    clippy::missing_safety_doc
+)]
+
+use std::ffi::c_void;
+
+use jni_sys::{jbyte, jclass, jdouble, jfloat, jint, jlong, jlongArray, jobject, jshort, JNIEnv};
+
+#[repr(C)]
+pub struct RustBuffer {
+    capacity: u64,
+    len: u64,
+    data: *mut u8,
+}
+
+impl RustBuffer {
+    fn from_jni(capacity: jlong, len: jlong, data: jlong) -> Self {
+        Self {
+            capacity: capacity as u64,
+            len: len as u64,
+            data: data as usize as *mut u8,
+        }
+    }
+
+    unsafe fn write_to(&self, env: *mut JNIEnv, array: jlongArray, start: jint) {
+        let values = [
+            self.capacity as jlong,
+            self.len as jlong,
+            self.data as usize as jlong,
+        ];
+        (**env).SetLongArrayRegion.unwrap()(env, array, start, 3, values.as_ptr());
+    }
+}
+
+#[repr(C)]
+pub struct ForeignBytes {
+    len: i32,
+    data: *const u8,
+}
+
+#[repr(C)]
+pub struct RustCallStatus {
+    code: i8,
+    error_buf: RustBuffer,
+}
+
+impl RustCallStatus {
+    fn new() -> Self {
+        Self {
+            code: 0,
+            error_buf: RustBuffer::from_jni(0, 0, 0),
+        }
+    }
+
+    unsafe fn write_to(&self, env: *mut JNIEnv, array: jlongArray) {
+        let code = self.code as jlong;
+        (**env).SetLongArrayRegion.unwrap()(env, array, 0, 1, &code);
+        self.error_buf.write_to(env, array, 1);
+    }
+}
+
+extern "C" {
+    {%- for func in ci.iter_ffi_function_definitions() %}
+    {%- if func|ffi_func_supported_jni %}
+    fn {{ func.name() }}({{ func|jni_shim_extern_params|join(", ") }}){{ func|jni_shim_extern_return_type }};
+    {%- endif %}
+    {%- endfor %}
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn {{ self.symbol_prefix() }}uniffiNewDirectByteBuffer(
+    env: *mut JNIEnv,
+    _class: jclass,
+    address: jlong,
+    capacity: jlong,
+) -> jobject {
+    (**env).NewDirectByteBuffer.unwrap()(env, address as usize as *mut c_void, capacity)
+}
+{%- for func in ci.iter_ffi_function_definitions() %}
+{%- if func|ffi_func_supported_jni %}
+{%- let returns_indirectly = func|ffi_returns_indirectly_jni %}
+
+#[no_mangle]
+pub unsafe extern "system" fn {{ self.symbol_prefix() }}{{ func.name()|jni_mangle }}(
+    {%- for param in func|jni_shim_params %}
+    {{ param }},
+    {%- endfor %}
+){{ func|jni_shim_return_type }} {
+    {%- if func.has_rust_call_status_arg() %}
+    let mut uniffi_status = RustCallStatus::new();
+    {%- endif %}
+    let uniffi_result = {{ func.name() }}(
+        {%- for arg in func|jni_shim_call_args %}
+        {{ arg }},
+        {%- endfor %}
+    );
+    {%- if func.has_rust_call_status_arg() %}
+    uniffi_status.write_to(env, uniffi_call_status);
+    {%- endif %}
+    {%- if returns_indirectly %}
+    uniffi_result.write_to(env, uniffi_return_value, 0);
+    {%- else if func.return_type().is_some() %}
+    uniffi_result{{ func|jni_shim_return_conversion }}
+    {%- endif %}
+}
+{%- endif %}
+{%- endfor %}
@@ -0,0 +1,31 @@
+{% include "ffi/Helpers.kt" %}
+
+// struct RustCallStatus { code: i8, error_buf: RustBuffer }
+//
+// The JNI shim writes the status back to `raw` as `[code, capacity, len, data]`.
+internal class UniffiRustCallStatus {
+    internal val raw = LongArray(4)
+}
+internal var UniffiRustCallStatus.code: Byte
+    get() = raw[0].toByte()
+    set(value) { raw[0] = value.toLong() }
+internal var UniffiRustCallStatus.errorBuf: RustBufferByValue
+    get() = RustBufferByValue(raw[1], raw[2], raw[3].takeIf { it != 0L })
+    set(value) {
+        raw[1] = value.capacity
+        raw[2] = value.len
+        raw[3] = value.data ?: 0L
+    }
+
+internal class UniffiRustCallStatusByValue(
+    internal val code: Byte,
+    internal val errorBuf: RustBufferByValue,
+)
+
+internal object UniffiRustCallStatusHelper {
+    fun allocValue() = UniffiRustCallStatusByValue(UNIFFI_CALL_SUCCESS, RustBufferByValue(0L, 0L, null))
+    fun <U> withReference(block: (UniffiRustCallStatus) -> U): U {
+        val status = UniffiRustCallStatus()
+        return block(status)
+    }
+}
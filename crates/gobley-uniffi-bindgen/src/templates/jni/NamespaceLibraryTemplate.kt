@@ -0,0 +1,124 @@
+
+{% include "android+jvm/LibraryLoader.kt" %}
+
+// The `external` functions are implemented by the generated JNI shim library
+// `{{ config.jni_shim_name(ci.namespace()) }}`, which forwards them to the UniFFI scaffolding.
+// Structs are flattened into their fields, and the values returned through pointers are written
+// back to the `LongArray`s passed to the functions.
+internal object UniffiJniLib {
+    init {
+        {%- for dynamic_library in config.dynamic_library_dependencies(module_name) %}
+        uniffiLoadLibrary("{{ dynamic_library }}")
+        {%- endfor %}
+        uniffiLoadLibrary(findLibraryName("{{ ci.namespace() }}"))
+        uniffiLoadLibrary("{{ config.jni_shim_name(ci.namespace()) }}")
+    }
+
+    @JvmStatic
+    external fun uniffiNewDirectByteBuffer(address: Long, capacity: Long): java.nio.ByteBuffer
+
+    {% for func in ci.iter_ffi_function_definitions() -%}
+    {%- if func|ffi_func_supported_jni -%}
+    @JvmStatic
+    external fun {{ func.name() }}(
+        {%- for param in func|jni_external_params %}
+        {{ param }},
+        {%- endfor %}
+    ): {{ func|jni_external_return_type }}
+    {% endif -%}
+    {% endfor %}
+}
+
+internal interface UniffiLib {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            uniffiCheckContractApiVersion()
+            {%- if !config.omit_checksums %}
+            uniffiCheckApiChecksums()
+            {%- endif %}
+            {% if self.initialization_fns(ci).is_empty() -%}
+            UniffiLibInstance()
+            {%- else -%}
+            UniffiLibInstance().also { lib ->
+            {%- for init_fn in self.initialization_fns(ci) %}
+                {{ init_fn }}
+            {%- endfor %}
+            }
+            {%- endif %}
+        }
+        {% if ci.contains_object_types() %}
+        // The Cleaner for the whole library
+        internal val CLEANER: UniffiCleaner by lazy {
+            UniffiCleaner.create()
+        }
+        {%- endif %}
+    }
+
+    {% for func in ci.iter_ffi_function_definitions_excluding_integrity_checks() -%}
+    {%- if func|ffi_func_supported_jni -%}
+    fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() %}{% when Some(return_type) %}{{ return_type.borrow()|ffi_type_name_by_value(ci) }}{% when None %}Unit{% endmatch %}
+    {% endif -%}
+    {% endfor %}
+}
+
+internal class UniffiLibInstance: UniffiLib {
+    {% for func in ci.iter_ffi_function_definitions_excluding_integrity_checks() -%}
+    {%- if func|ffi_func_supported_jni -%}
+    {%- let returns_indirectly = func|ffi_returns_indirectly_jni -%}
+    override fun {{ func.name() }}(
+        {%- call kt::arg_list_ffi_decl(func, 8) %}
+    ): {% match func.return_type() -%}
+    {%- when Some(return_type) -%}
+    {{- return_type.borrow()|ffi_type_name_by_value(ci) -}}
+    {%- when None -%}
+    Unit
+    {%- endmatch %} {
+        {%- if returns_indirectly %}
+        val uniffiReturnValue = LongArray(3)
+        {%- endif %}
+        {% if !returns_indirectly && func.return_type().is_some() %}return {% endif -%}
+        UniffiJniLib.{{ func.name() }}(
+            {%- for arg in func|jni_call_args %}
+            {{ arg }},
+            {%- endfor %}
+        ){{ func|jni_lift_return_value }}
+        {%- if returns_indirectly %}
+        return RustBufferByValue(
+            uniffiReturnValue[0],
+            uniffiReturnValue[1],
+            uniffiReturnValue[2].takeIf { it != 0L },
+        )
+        {%- if let Some(return_type) = func.return_type() -%}
+        {{- return_type|ffi_cast_to_external_rust_buffer_if_needed(ci) -}}
+        {%- endif %}
+        {%- endif %}
+    }
+    {% endif -%}
+    {% endfor %}
+}
+
+private fun uniffiCheckContractApiVersion() {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = {{ ci.uniffi_contract_version() }}
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = UniffiJniLib.{{ ci.ffi_uniffi_contract_version().name() }}()
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+{% if !config.omit_checksums -%}
+private fun uniffiCheckApiChecksums() {
+    {%- for (name, expected_checksum) in ci.iter_checksums() %}
+    if (UniffiJniLib.{{ name }}() != {{ expected_checksum }}.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    {%- endfor %}
+}
+{%- endif %}
+
+{{ visibility() }}fun uniffiEnsureInitialized() {
+    UniffiLib.INSTANCE
+}
@@ -0,0 +1,109 @@
+{% include "ffi/ObjectCleanerHelper.kt" %}
+// The fallback cleaner, which is available for both Android, and the JVM. JNA is not available
+// with the JNI backend, so a daemon thread polls a `ReferenceQueue` instead.
+private class UniffiReferenceQueueCleaner : UniffiCleaner {
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable =
+        UniffiReferenceQueueCleanable(resource, UniffiCleanerAction(disposable))
+
+    private class UniffiReferenceQueueCleanable(
+        resource: Any,
+        private val action: Runnable,
+    ) : java.lang.ref.PhantomReference<Any>(resource, queue), UniffiCleaner.Cleanable {
+        init {
+            cleanables.add(this)
+        }
+
+        override fun clean() {
+            if (cleanables.remove(this)) {
+                clear()
+                action.run()
+            }
+        }
+    }
+
+    private companion object {
+        val queue = java.lang.ref.ReferenceQueue<Any>()
+        // Keeps the phantom references reachable until they are enqueued.
+        val cleanables: MutableSet<UniffiReferenceQueueCleanable> =
+            java.util.concurrent.ConcurrentHashMap.newKeySet()
+
+        init {
+            val thread = Thread({
+                while (true) {
+                    try {
+                        (queue.remove() as UniffiReferenceQueueCleanable).clean()
+                    } catch (_: InterruptedException) {
+                        // Keep polling.
+                    }
+                }
+            }, "UniFFI Cleaner")
+            thread.isDaemon = true
+            thread.start()
+        }
+    }
+}
+
+private class UniffiCleanerAction(private val disposable: Disposable): Runnable {
+    override fun run() {
+        disposable.destroy()
+    }
+}
+
+{%- if config.disable_java_cleaner %}
+private fun UniffiCleaner.Companion.create(): UniffiCleaner = UniffiReferenceQueueCleaner()
+{%- else if module_name == "android" %}
+{{- self.add_import("android.os.Build") }}
+{{- self.add_import("androidx.annotation.RequiresApi") }}
+
+// The SystemCleaner, available from API Level 33.
+// Some API Level 33 OSes do not support using it, so we require API Level 34.
+@RequiresApi(Build.VERSION_CODES.UPSIDE_DOWN_CAKE)
+private class AndroidSystemCleaner : UniffiCleaner {
+    private val cleaner = android.system.SystemCleaner.cleaner()
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable =
+        AndroidSystemCleanable(cleaner.register(resource, UniffiCleanerAction(disposable)))
+}
+
+@RequiresApi(Build.VERSION_CODES.UPSIDE_DOWN_CAKE)
+private class AndroidSystemCleanable(
+    private val cleanable: java.lang.ref.Cleaner.Cleanable,
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+private fun UniffiCleaner.Companion.create(): UniffiCleaner {
+    if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
+        try {
+            return AndroidSystemCleaner()
+        } catch (_: IllegalAccessError) {
+            // (For Compose preview) Fallback to UniffiReferenceQueueCleaner if AndroidSystemCleaner is
+            // unavailable, even for API level 34 or higher.
+        }
+    }
+    return UniffiReferenceQueueCleaner()
+}
+
+{%- else %}
+
+private class JavaLangRefCleaner : UniffiCleaner {
+    private val cleaner: java.lang.ref.Cleaner = java.lang.ref.Cleaner.create()
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable =
+        JavaLangRefCleanable(cleaner.register(resource, UniffiCleanerAction(disposable)))
+}
+
+private class JavaLangRefCleanable(
+    val cleanable: java.lang.ref.Cleaner.Cleanable
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+private fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    try {
+        JavaLangRefCleaner()
+    } catch (e: ClassNotFoundException) {
+        UniffiReferenceQueueCleaner()
+    }
+
+{%- endif %}
\ No newline at end of file
@@ -0,0 +1,5 @@
+
+// Native addresses are passed through JNI as `long` values.
+internal typealias Pointer = Long
+internal val NullPointer: Pointer? = null
+internal fun kotlin.Long.toPointer(): Pointer = this
@@ -0,0 +1,36 @@
+{% include "ffi/RustBufferTemplate.kt" %}
+
+// struct RustBuffer { capacity: u64, len: u64, data: *mut u8 }
+{{ visibility() }}class RustBuffer(
+    // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
+    // When dealing with these fields, make sure to call `toULong()`.
+    {{ visibility() }}var capacity: Long,
+    {{ visibility() }}var len: Long,
+    {{ visibility() }}var data: Pointer?,
+) {
+    {{ visibility() }}constructor(): this(0L, 0L, null)
+}
+
+{{ visibility() }}class RustBufferByValue(
+    {{ visibility() }}val capacity: Long,
+    {{ visibility() }}val len: Long,
+    {{ visibility() }}val data: Pointer?,
+) {
+    {{ visibility() }}constructor(): this(0L, 0L, null)
+}
+
+internal fun RustBuffer.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("this.len") %}
+    return ByteBuffer(UniffiJniLib.uniffiNewDirectByteBuffer(data ?: return null, this.len))
+}
+
+internal fun RustBufferByValue.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("this.len") %}
+    return ByteBuffer(UniffiJniLib.uniffiNewDirectByteBuffer(data ?: return null, this.len))
+}
+
+// struct ForeignBytes { len: i32, data: *const u8 }
+internal class ForeignBytesByValue(
+    internal val len: Int,
+    internal val data: Pointer?,
+)
@@ -0,0 +1,12 @@
+{%- extends "ffi/Types.kt" %}
+
+{#- Components with callbacks are rejected before rendering these bindings, so only the object
+ # cleaner and the external types are specific to them. -#}
+
+{%- block object_cleaner_helper %}
+{%- include "ObjectCleanerHelper.kt" %}
+{%- endblock %}
+
+{%- block external_type %}
+{% include "android+jvm/ExternalTypeTemplate.kt" %}
+{%- endblock %}
@@ -0,0 +1,103 @@
+
+{{ visibility() }}class ByteBuffer(
+    internal val pointer: Pointer,
+    internal val capacity: Int,
+    internal var position: Int = 0,
+) {
+    {{ visibility() }}fun position(): Int = position
+
+    {{ visibility() }}fun hasRemaining(): Boolean = capacity != position
+
+    private fun checkRemaining(bytes: Int) {
+        val remaining = capacity - position
+        require(bytes <= remaining) { 
+            "buffer is exhausted: required: $bytes, remaining: $remaining, capacity: $capacity, position: $position" 
+        }
+    }
+
+    {{ visibility() }}fun get(): Byte {
+        checkRemaining(1)
+        return uniffiWasmMemoryView().getInt8(pointer + position++)
+    }
+
+    {{ visibility() }}fun get(bytesToRead: Int): ByteArray {
+        checkRemaining(bytesToRead)
+        val result = ByteArray(bytesToRead)
+        if (result.isNotEmpty()) {
+            // ByteArray is backed by an Int8Array in Kotlin/JS.
+            result.unsafeCast<Int8Array>().set(uniffiWasmMemoryBytes(pointer + position, bytesToRead))
+            position += bytesToRead
+        }
+        return result
+    }
+
+    {{ visibility() }}fun getShort(): Short {
+        checkRemaining(2)
+        return uniffiWasmMemoryView().getInt16(pointer + position, false).also { position += 2 }
+    }
+
+    {{ visibility() }}fun getInt(): Int {
+        checkRemaining(4)
+        return uniffiWasmMemoryView().getInt32(pointer + position, false).also { position += 4 }
+    }
+
+    {{ visibility() }}fun getLong(): Long {
+        checkRemaining(8)
+        val high = getInt().toLong()
+        val low = getInt().toLong() and 0xffffffffL
+        return (high shl 32) or low
+    }
+
+    {{ visibility() }}fun getFloat(): Float {
+        checkRemaining(4)
+        return uniffiWasmMemoryView().getFloat32(pointer + position, false).also { position += 4 }
+    }
+
+    {{ visibility() }}fun getDouble(): Double {
+        checkRemaining(8)
+        return uniffiWasmMemoryView().getFloat64(pointer + position, false).also { position += 8 }
+    }
+
+    {{ visibility() }}fun put(value: Byte) {
+        checkRemaining(1)
+        uniffiWasmMemoryView().setInt8(pointer + position++, value)
+    }
+
+    {{ visibility() }}fun put(src: ByteArray) {
+        checkRemaining(src.size)
+        if (src.isNotEmpty()) {
+            uniffiWasmMemoryBytes(pointer + position, src.size).set(src.unsafeCast<Int8Array>())
+            position += src.size
+        }
+    }
+
+    {{ visibility() }}fun putShort(value: Short) {
+        checkRemaining(2)
+        uniffiWasmMemoryView().setInt16(pointer + position, value, false)
+        position += 2
+    }
+
+    {{ visibility() }}fun putInt(value: Int) {
+        checkRemaining(4)
+        uniffiWasmMemoryView().setInt32(pointer + position, value, false)
+        position += 4
+    }
+
+    {{ visibility() }}fun putLong(value: Long) {
+        checkRemaining(8)
+        putInt((value ushr 32).toInt())
+        putInt(value.toInt())
+    }
+
+    {{ visibility() }}fun putFloat(value: Float) {
+        checkRemaining(4)
+        uniffiWasmMemoryView().setFloat32(pointer + position, value, false)
+        position += 4
+    }
+
+    {{ visibility() }}fun putDouble(value: Double) {
+        checkRemaining(8)
+        uniffiWasmMemoryView().setFloat64(pointer + position, value, false)
+        position += 8
+    }
+}
@@ -0,0 +1,38 @@
+{% include "ffi/ObjectCleanerHelper.kt" %}
+{{- self.add_import("kotlinx.atomicfu.atomic") }}
+
+private external class FinalizationRegistry(cleanupCallback: (heldValue: Any) -> Unit) {
+    fun register(target: Any, heldValue: Any, unregisterToken: Any)
+    fun unregister(unregisterToken: Any): Boolean
+}
+
+private class JsFinalizationRegistryCleaner : UniffiCleaner {
+    private val registry = FinalizationRegistry { heldValue ->
+        (heldValue as Disposable).destroy()
+    }
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable {
+        // The held value must not reference the resource, or the resource will never be
+        // collected.
+        val cleanable = JsFinalizationRegistryCleanable(registry, disposable)
+        registry.register(resource, disposable, cleanable)
+        return cleanable
+    }
+
+    private class JsFinalizationRegistryCleanable(
+        private val registry: FinalizationRegistry,
+        private val disposable: Disposable,
+    ) : UniffiCleaner.Cleanable {
+        private val cleaned = atomic(false)
+
+        override fun clean() {
+            if (cleaned.compareAndSet(false, true)) {
+                registry.unregister(this)
+                disposable.destroy()
+            }
+        }
+    }
+}
+
+private fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    JsFinalizationRegistryCleaner()
@@ -0,0 +1,57 @@
+{% include "ffi/RustBufferTemplate.kt" %}
+
+// struct RustBuffer { capacity: u64, len: u64, data: *mut u8 }
+internal const val UNIFFI_RUST_BUFFER_SIZE = 24
+
+{{ visibility() }}class RustBuffer internal constructor(internal val address: Pointer)
+
+{{ visibility() }}var RustBuffer.capacity: Long
+    get() = uniffiWasmMemoryView().getLongLittleEndian(address)
+    set(value) { uniffiWasmMemoryView().setLongLittleEndian(address, value) }
+{{ visibility() }}var RustBuffer.len: Long
+    get() = uniffiWasmMemoryView().getLongLittleEndian(address + 8)
+    set(value) { uniffiWasmMemoryView().setLongLittleEndian(address + 8, value) }
+{{ visibility() }}var RustBuffer.data: Pointer?
+    get() = uniffiWasmMemoryView().getPointer(address + 16)
+    set(value) { uniffiWasmMemoryView().setPointer(address + 16, value) }
+{{ visibility() }}fun RustBuffer.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("len") %}
+    return ByteBuffer(
+        data ?: return null,
+        len.toInt(),
+    )
+}
+internal fun RustBuffer.readValue(): RustBufferByValue = RustBufferByValue(
+    capacity = capacity,
+    len = len,
+    data = data,
+)
+
+{{ visibility() }}class RustBufferByValue(
+    {{ visibility() }}val capacity: Long,
+    {{ visibility() }}val len: Long,
+    {{ visibility() }}val data: Pointer?,
+)
+{{ visibility() }}fun RustBufferByValue.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("len") %}
+    return ByteBuffer(
+        data ?: return null,
+        len.toInt(),
+    )
+}
+internal fun RustBufferByValue.write(address: Pointer) {
+    RustBuffer(address).setValue(this)
+}
+
+// struct ForeignBytes { len: i32, data: *const u8 }
+internal const val UNIFFI_FOREIGN_BYTES_SIZE = 8
+
+internal class ForeignBytesByValue(
+    internal val len: Int,
+    internal val data: Pointer?,
+)
+internal fun ForeignBytesByValue.write(address: Pointer) {
+    val view = uniffiWasmMemoryView()
+    view.setInt32(address, len, true)
+    view.setPointer(address + 4, data)
+}
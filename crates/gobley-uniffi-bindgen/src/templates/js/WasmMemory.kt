@@ -0,0 +1,67 @@
+
+internal val uniffiWasmExports: RustWebAssemblyExports
+    get() = uniffiWasmInstance.exports
+
+// The memory buffer is replaced whenever the memory grows, so always make a fresh view.
+internal fun uniffiWasmMemoryView(): DataView = DataView(uniffiWasmExports.memory.buffer)
+
+internal fun uniffiWasmMemoryBytes(pointer: Pointer, length: Int): Int8Array =
+    Int8Array(uniffiWasmExports.memory.buffer, pointer, length)
+
+// Structs in the linear memory are laid out in little-endian order.
+internal fun DataView.getPointer(byteOffset: Int): Pointer? =
+    getInt32(byteOffset, true).takeIf { it != 0 }
+
+internal fun DataView.setPointer(byteOffset: Int, value: Pointer?) =
+    setInt32(byteOffset, value ?: 0, true)
+
+internal fun DataView.getLongLittleEndian(byteOffset: Int): Long =
+    (getInt32(byteOffset, true).toLong() and 0xffffffffL) or
+        (getInt32(byteOffset + 4, true).toLong() shl 32)
+
+internal fun DataView.setLongLittleEndian(byteOffset: Int, value: Long) {
+    setInt32(byteOffset, value.toInt(), true)
+    setInt32(byteOffset + 4, (value ushr 32).toInt(), true)
+}
+
+// 64-bit integers are passed to and returned from WebAssembly functions as BigInt values.
+private fun uniffiBigIntFromString(value: String): Any = js("BigInt(value)")
+
+internal fun Long.toBigInt(): Any = uniffiBigIntFromString(toString())
+
+internal fun Any.bigIntToLong(): Long = toString().toLong()
+
+// Structs passed by value are copied to the shadow stack of the WebAssembly module, and a
+// pointer to the copy is passed instead.
+internal class UniffiWasmStackFrame {
+    private var allocated = 0
+
+    fun alloc(size: Int): Pointer {
+        // Keep the stack pointer aligned to 16 bytes.
+        val alignedSize = (size + 15) and 15.inv()
+        allocated += alignedSize
+        return uniffiWasmExports.__gobley_add_to_stack_pointer(-alignedSize)
+    }
+
+    fun push(value: RustBufferByValue): Pointer =
+        alloc(UNIFFI_RUST_BUFFER_SIZE).also { value.write(it) }
+
+    fun push(value: ForeignBytesByValue): Pointer =
+        alloc(UNIFFI_FOREIGN_BYTES_SIZE).also { value.write(it) }
+
+    fun release() {
+        if (allocated != 0) {
+            uniffiWasmExports.__gobley_add_to_stack_pointer(allocated)
+            allocated = 0
+        }
+    }
+}
+
+internal inline fun <T> uniffiWithWasmStackFrame(block: (UniffiWasmStackFrame) -> T): T {
+    val frame = UniffiWasmStackFrame()
+    try {
+        return block(frame)
+    } finally {
+        frame.release()
+    }
+}
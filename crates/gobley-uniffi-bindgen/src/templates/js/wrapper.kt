@@ -0,0 +1,63 @@
+{%- call kt::docstring_value(ci.namespace_docstring(), 0) %}
+
+@file:Suppress("RemoveRedundantBackticks", "UNUSED_ANONYMOUS_PARAMETER")
+
+package {{ config.package_name() }}
+
+// Common helper code.
+//
+// Ideally this would live in a separate .kt file where it can be unittested etc
+// in isolation, and perhaps even published as a re-useable package.
+//
+// However, it's important that the details of how this helper code works (e.g. the
+// way that different builtin types are passed across the FFI) exactly match what's
+// expected by the Rust code on the other side of the interface. In practice right
+// now that means coming from the exact some version of `uniffi` that was used to
+// compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
+// helpers directly inline like we're doing here.
+
+import org.khronos.webgl.DataView
+import org.khronos.webgl.Int8Array
+import {{ config.wasm_package_name() }}.RustWebAssemblyExports
+import {{ config.wasm_package_name() }}.instance as uniffiWasmInstance
+
+{%- for req in self.imports() %}
+{{ req.render() }}
+{%- endfor %}
+{{- self.split_output_helpers_section() }}
+
+{% include "ffi/WebPointerHelper.kt" %}
+{% include "WasmMemory.kt" %}
+
+{% include "ByteBuffer.kt" %}
+{% include "RustBufferTemplate.kt" %}
+{% include "ffi/FfiConverterTemplate.kt" %}
+{% include "Helpers.kt" %}
+
+// Contains loading, initialization code,
+// and the FFI Function declarations.
+{% include "ffi/WebNamespaceLibraryTemplate.kt" %}
+
+// Public interface members begin here.
+{{ type_helper_code }}
+{{- self.split_output_section_end() }}
+
+{% import "macros.kt" as kt %}
+
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
+{%- include "ffi/TopLevelFunctionTemplate.kt" %}
+{%- endfor %}
+{{- self.visibility_section_end() }}
+{{- self.split_output_helpers_section() }}
+
+{%- if config.has_suspend_wrappers(ci) %}
+{% include "ffi/BlockingDispatcher.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
@@ -25,7 +25,7 @@
 {%- macro to_raw_ffi_call(func, indent) -%}
                         {%- match func.throws_type() -%}
                         {%- when Some(e) -%}
-                        uniffiRustCallWithError({{ e|type_name(ci) }}ErrorHandler)
+                        uniffiRustCallWithError({{ e|type_name(ci, config) }}ErrorHandler)
                         {%- else -%}
                         uniffiRustCall
                         {%- endmatch %} { uniffiRustCallStatus ->
@@ -47,38 +47,46 @@
                         {%- endif %}
 {%- endmacro -%}
 
-{%- macro func_decl(func_decl, callable, indent, is_decl_override) %}
+{%- macro func_decl(func_decl, callable, owner, indent, is_decl_override) %}
                         {%- call docstring(callable, indent) -%}
                         {%- match callable.throws_type() -%}
                         {%-     when Some(throwable) %}
-{{ " "|repeat(indent) }}@Throws({{ throwable|type_name(ci) }}::class {%- if callable.is_async() -%}, kotlin.coroutines.cancellation.CancellationException::class{%- endif -%})
+{{ " "|repeat(indent) }}@Throws({{ throwable|type_name(ci, config) }}::class {%- if callable.is_async() -%}, kotlin.coroutines.cancellation.CancellationException::class{%- endif -%})
                         {%-     else -%}
                         {%- endmatch %}
 {{ " "|repeat(indent) }}{{ visibility() }}{% if func_decl.len() != 0 -%}{{ func_decl }} {% endif -%}
                         {%- if callable.is_async() -%}suspend {% endif -%}
-                        fun {{ callable.name()|fn_name }}(
+                        fun {{ callable.name()|callable_name(owner, config) }}(
                             {%- call arg_list(callable, is_decl_override || !callable.takes_self()) -%}
                         )
                         {%- match callable.return_type() -%}
-                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci) -}}
+                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci, config) -}}
                         {%-     else -%}
                         {%- endmatch -%}
 {% endmacro %}
 
-{%- macro func_decl_with_body(func_decl, callable, indent) %}
+{%- macro func_decl_with_body(func_decl, callable, owner, indent) %}
                         {%- call docstring(callable, indent) -%}
                         {%- match callable.throws_type() -%}
                         {%-     when Some(throwable) %}
-{{ " "|repeat(indent) }}@Throws({{ throwable|type_name(ci) }}::class {%- if callable.is_async() -%}, kotlin.coroutines.cancellation.CancellationException::class{%- endif -%})
+{{ " "|repeat(indent) }}@Throws({{ throwable|type_name(ci, config) }}::class {%- if callable.is_async() -%}, kotlin.coroutines.cancellation.CancellationException::class{%- endif -%})
                         {%-     else -%}
                         {%- endmatch %}
+                        {%- if config.has_java_interop(module_name) && !callable.takes_self() %}
+                        {%- if owner.len() != 0 %}
+{{ " "|repeat(indent) }}@kotlin.jvm.JvmStatic
+                        {%- endif %}
+                        {%- if config.kotlin_multiplatform && callable|has_default_arguments %}
+{{ " "|repeat(indent) }}@kotlin.jvm.JvmOverloads
+                        {%- endif %}
+                        {%- endif %}
 {{ " "|repeat(indent) }}{{ visibility() }}{% if func_decl.len() != 0 -%}{{ func_decl }} {% endif -%}
                         {%- if callable.is_async() -%}suspend {% endif -%}
-                        fun {{ callable.name()|fn_name }}(
+                        fun {{ callable.name()|callable_name(owner, config) }}(
                             {%- call arg_list(callable, false) -%}
                         )
                         {%- match callable.return_type() -%}
-                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci) -}}
+                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci, config) -}}
                         {%-     else -%}
                         {%- endmatch %} {
                             {%- if callable.is_async() %}
@@ -94,21 +102,91 @@
 {{ " "|repeat(indent) }}{{ '}' }}
 {% endmacro %}
 
-{%- macro func_decl_with_stub(func_decl, callable, indent) %}
+{%- macro func_decl_with_stub(func_decl, callable, owner, indent) %}
                         {%- call docstring(callable, indent) %}
 {{ " "|repeat(indent) }}{{ visibility() }}{% if func_decl.len() != 0 -%}{{ func_decl }} {% endif -%}
                         {%- if callable.is_async() -%}suspend {% endif -%}
-                        fun {{ callable.name()|fn_name }}(
+                        fun {{ callable.name()|callable_name(owner, config) }}(
                             {%- call arg_list(callable, false) -%}
                         )
                         {%- match callable.return_type() -%}
-                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci) -}}
+                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci, config) -}}
                         {%-     else -%}
                         {%- endmatch %} {
 {{ " "|repeat(indent) }}    TODO()
 {{ " "|repeat(indent) }}{{ '}' }}
 {% endmacro %}
 
+{#-
+// A suspend function calling `callable` in the blocking dispatcher, generated according to
+// `suspend_wrappers`. `receiver` is the type the method is called on, or empty for top-level
+// functions.
+-#}
+{%- macro suspend_wrapper(callable, owner, receiver) %}
+{%- let callable_name = callable.name()|callable_name(owner, config) %}
+
+/**
+ * Calls [{% if receiver.len() != 0 %}{{ receiver }}.{% endif %}{{ callable_name }}] in [UniffiAsyncDispatcher.blockingContext], or in the
+ * dispatcher configured with `blocking_dispatcher`, so that it doesn't block the calling thread.
+ */
+                        {%- match callable.throws_type() %}
+                        {%- when Some(throwable) %}
+@Throws({{ throwable|type_name(ci, config) }}::class, kotlin.coroutines.cancellation.CancellationException::class)
+                        {%- else %}
+                        {%- endmatch %}
+{{ visibility() }}suspend fun {% if receiver.len() != 0 %}{{ receiver }}.{% endif %}{{ callable.name()|suffixed_callable_name(owner, "Async", config) }}(
+                            {%- call arg_list(callable, true) -%}
+                        )
+                        {%- match callable.return_type() -%}
+                        {%-     when Some(return_type) %}: {{ return_type|type_name(ci, config) -}}
+                        {%-     else -%}
+                        {%- endmatch %} {
+                        {%- if receiver.len() != 0 %}
+    val uniffiReceiver = this
+                        {%- endif %}
+    return kotlinx.coroutines.withContext(UniffiAsyncDispatcher.blockingContext ?: uniffiBlockingDispatcher) {
+        {% if receiver.len() != 0 %}uniffiReceiver{% else %}{{ config.package_name() }}{% endif %}.{{ callable_name }}(
+                            {%- for arg in callable.arguments() -%}
+                            {{ arg.name()|var_name }}
+                            {%- if !loop.last %}, {% endif -%}
+                            {%- endfor -%}
+                        )
+    }
+}
+{%- endmacro %}
+
+{#-
+// A function calling the async `callable` and returning its result as a `CompletableFuture`,
+// generated for Java callers according to `java_interop_futures`. `receiver` is the class the
+// method is a member of, or empty for top-level functions.
+-#}
+{%- macro future_wrapper(callable, owner, receiver, indent) %}
+{%- let callable_name = callable.name()|callable_name(owner, config) %}
+
+{{ " "|repeat(indent) }}/**
+{{ " "|repeat(indent) }} * Calls [{{ callable_name }}] and returns its result as a [java.util.concurrent.CompletableFuture],
+{{ " "|repeat(indent) }} * which Java can use unlike `suspend` functions. Cancelling the future cancels the call.
+{{ " "|repeat(indent) }} */
+{{ " "|repeat(indent) }}@OptIn(kotlinx.coroutines.DelicateCoroutinesApi::class)
+                        {%- if callable|has_default_arguments %}
+{{ " "|repeat(indent) }}@kotlin.jvm.JvmOverloads
+                        {%- endif %}
+{{ " "|repeat(indent) }}{{ visibility() }}fun {{ callable.name()|suffixed_callable_name(owner, "Future", config) }}(
+                            {%- call arg_list(callable, true) -%}
+                        ): java.util.concurrent.CompletableFuture<
+                        {%- match callable.return_type() -%}
+                        {%-     when Some(return_type) %}{{ return_type|type_name(ci, config) -}}
+                        {%-     else %}Unit
+                        {%- endmatch %}> = kotlinx.coroutines.GlobalScope.future {
+{{ " "|repeat(indent) }}    {% if receiver.len() != 0 %}this@{{ receiver }}{% else %}{{ config.package_name() }}{% endif %}.{{ callable_name }}(
+                            {%- for arg in callable.arguments() -%}
+                            {{ arg.name()|var_name }}
+                            {%- if !loop.last %}, {% endif -%}
+                            {%- endfor -%}
+                        )
+{{ " "|repeat(indent) }}{{ '}' }}
+{%- endmacro %}
+
 {%- macro call_async(callable, indent) -%}
                         uniffiRustCallAsync(
                             {%- if callable.takes_self() %}
@@ -141,7 +219,7 @@
 {{ " "|repeat(indent) }}    // Error FFI converter
                             {%- match callable.throws_type() -%}
                             {%- when Some(e) %}
-{{ " "|repeat(indent) }}    {{ e|type_name(ci) }}ErrorHandler,
+{{ " "|repeat(indent) }}    {{ e|type_name(ci, config) }}ErrorHandler,
                             {%- when None %}
 {{ " "|repeat(indent) }}    UniffiNullRustCallStatusErrorHandler,
                             {%- endmatch %}
@@ -162,7 +240,7 @@
 
 {% macro arg_list(func, is_decl) %}
 {%- for arg in func.arguments() -%}
-        {{ arg.name()|var_name }}: {{ arg|type_name(ci) }}
+        {{ arg.name()|var_name }}: {{ arg|type_name(ci, config) }}
 {%-     if is_decl %}
 {%-         match arg.default_value() %}
 {%-             when Some with(literal) %} = {{ literal|render_literal(arg, ci, config) }}
@@ -203,33 +281,33 @@
     {%- endif -%}
 {%- endmacro -%}
 
-{% macro field_name(field, field_num) %}
+{% macro field_name(field, owner, field_num) %}
 {%- if field.name().is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name }}
+{{ field.name()|field_var_name(owner, config) }}
 {%- endif -%}
 {%- endmacro %}
 
-{% macro field_name_unquoted(field, field_num) %}
+{% macro field_name_unquoted(field, owner, field_num) %}
 {%- if field.name().is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name|unquote }}
+{{ field.name()|field_var_name(owner, config)|unquote }}
 {%- endif -%}
 {%- endmacro %}
 
 {#- Macro for destroying fields -#}
-{%- macro destroy_fields(member, indent) %}
+{%- macro destroy_fields(member, owner, indent) %}
 {{ " "|repeat(indent) }}Disposable.destroy(
                             {%- for field in member.fields() %}
-{{ " "|repeat(indent) }}    this.{%- call field_name(field, loop.index) -%},
+{{ " "|repeat(indent) }}    this.{%- call field_name(field, owner, loop.index) -%},
                             {%- endfor %}
 {{ " "|repeat(indent) }})
 {%- endmacro -%}
 
 {#- Macro for generating equals() ans hashCode() -#}
-{%- macro generate_equals_hash_code(data_class, type_name, indent) %}
+{%- macro generate_equals_hash_code(data_class, owner, type_name, indent) %}
 {{ " "|repeat(indent) }}override fun equals(other: Any?): Boolean {
 {{ " "|repeat(indent) }}    if (this === other) return true
 {{ " "|repeat(indent) }}    if (other == null || this::class != other::class) return false
@@ -240,30 +318,30 @@ v{{- field_num -}}
                             {%-     for field in data_class.fields() %}
                             {%-         match field|as_data_class_field_type -%}
                             {%-             when DataClassFieldType::Bytes %}
-{{ " "|repeat(indent) }}    return {{ field.name()|var_name }}.contentEquals(other.{{ field.name()|var_name }})
+{{ " "|repeat(indent) }}    return {{ field.name()|field_var_name(owner, config) }}.contentEquals(other.{{ field.name()|field_var_name(owner, config) }})
                             {%-             when DataClassFieldType::NullableBytes %}
-{{ " "|repeat(indent) }}    if ({{ field.name()|var_name }} != null) {
-{{ " "|repeat(indent) }}        if (other.{{ field.name()|var_name }} == null) return false
-{{ " "|repeat(indent) }}        if (!{{ field.name()|var_name }}.contentEquals(other.{{ field.name()|var_name }})) return false
+{{ " "|repeat(indent) }}    if ({{ field.name()|field_var_name(owner, config) }} != null) {
+{{ " "|repeat(indent) }}        if (other.{{ field.name()|field_var_name(owner, config) }} == null) return false
+{{ " "|repeat(indent) }}        if (!{{ field.name()|field_var_name(owner, config) }}.contentEquals(other.{{ field.name()|field_var_name(owner, config) }})) return false
 {{ " "|repeat(indent) }}    }
 
 {{ " "|repeat(indent) }}    return true
                             {%-             else %}
-{{ " "|repeat(indent) }}    return {{ field.name()|var_name }} == other.{{ field.name()|var_name }}
+{{ " "|repeat(indent) }}    return {{ field.name()|field_var_name(owner, config) }} == other.{{ field.name()|field_var_name(owner, config) }}
                             {%-         endmatch -%}
                             {%-     endfor -%}
                             {%- else -%}
                             {%-     for field in data_class.fields() -%}
                             {%-         match field|as_data_class_field_type -%}
                             {%-             when DataClassFieldType::Bytes %}
-{{ " "|repeat(indent) }}    if (!{{ field.name()|var_name }}.contentEquals(other.{{ field.name()|var_name }})) return false
+{{ " "|repeat(indent) }}    if (!{{ field.name()|field_var_name(owner, config) }}.contentEquals(other.{{ field.name()|field_var_name(owner, config) }})) return false
                             {%-             when DataClassFieldType::NullableBytes %}
-{{ " "|repeat(indent) }}    if ({{ field.name()|var_name }} != null) {
-{{ " "|repeat(indent) }}        if (other.{{ field.name()|var_name }} == null) return false
-{{ " "|repeat(indent) }}        if (!{{ field.name()|var_name }}.contentEquals(other.{{ field.name()|var_name }})) return false
+{{ " "|repeat(indent) }}    if ({{ field.name()|field_var_name(owner, config) }} != null) {
+{{ " "|repeat(indent) }}        if (other.{{ field.name()|field_var_name(owner, config) }} == null) return false
+{{ " "|repeat(indent) }}        if (!{{ field.name()|field_var_name(owner, config) }}.contentEquals(other.{{ field.name()|field_var_name(owner, config) }})) return false
 {{ " "|repeat(indent) }}    }
                             {%-             else %}
-{{ " "|repeat(indent) }}    if ({{ field.name()|var_name }} != other.{{ field.name()|var_name }}) return false
+{{ " "|repeat(indent) }}    if ({{ field.name()|field_var_name(owner, config) }} != other.{{ field.name()|field_var_name(owner, config) }}) return false
                             {%-         endmatch -%}
                             {%-     endfor %}
 
@@ -283,13 +361,13 @@ v{{- field_num -}}
                             {%-     endif -%}
                             {%-     match field|as_data_class_field_type -%}
                             {%-         when DataClassFieldType::Bytes -%}
-                            {{ field.name()|var_name }}.contentHashCode()
+                            {{ field.name()|field_var_name(owner, config) }}.contentHashCode()
                             {%-         when DataClassFieldType::NullableBytes -%}
-                            ({{ field.name()|var_name }}?.contentHashCode() ?: 0)
+                            ({{ field.name()|field_var_name(owner, config) }}?.contentHashCode() ?: 0)
                             {%-         when DataClassFieldType::NonNullableNonBytes -%}
-                            {{ field.name()|var_name }}.hashCode()
+                            {{ field.name()|field_var_name(owner, config) }}.hashCode()
                             {%-         when DataClassFieldType::NullableNonBytes -%}
-                            ({{ field.name()|var_name }}?.hashCode() ?: 0)
+                            ({{ field.name()|field_var_name(owner, config) }}?.hashCode() ?: 0)
                             {%-     endmatch -%}
                             {%- endfor -%}
                             {%- if data_class.fields().len() > 1 %}
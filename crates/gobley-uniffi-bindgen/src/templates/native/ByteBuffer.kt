@@ -1,3 +1,4 @@
+{%- if !config.has_shared_platform_runtime(module_name) %}
 
 {{ visibility() }}class ByteBuffer(
     internal val pointer: CPointer<kotlinx.cinterop.ByteVar>,
@@ -107,3 +108,4 @@
 
     {{ visibility() }}fun putDouble(value: Double): Unit = putLong(value.toRawBits())
 }
+{%- endif %}
@@ -3,7 +3,7 @@
 {%- let package_name=self.external_type_package_name(module_path, namespace) %}
 {%- include "ffi/ExternalTypeTemplate.kt" %}
 
-{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|class_name(ci)) %}
+{%- let fully_qualified_type_name = "{}.{}"|format(package_name, name|external_class_name(module_path, ci, config)) %}
 {%- let fully_qualified_ffi_converter_name = "{}.FfiConverterType{}"|format(package_name, name) %}
 {%- let fully_qualified_capacity = "{}.capacity"|format(package_name) %}
 {%- let fully_qualified_len = "{}.len"|format(package_name) %}
@@ -37,7 +37,19 @@ internal fun {{ local_rustbuffer_by_value_name }}.from{{ name }}ToLocal(): RustB
     )
 }
 
-internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|class_name(ci) }} {
+{%- if config.has_shared_platform_runtime(module_name) && config.shares_runtime_with(package_name) %}
+
+// The `ByteBuffer` class is shared with {{ package_name }}.
+internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|external_class_name(module_path, ci, config) }} {
+    return read(buf)
+}
+
+internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|external_class_name(module_path, ci, config) }}, buf: ByteBuffer) {
+    write(value, buf)
+}
+{%- else %}
+
+internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBuffer): {{ name|external_class_name(module_path, ci, config) }} {
     val externalBuffer = {{ package_name }}.ByteBuffer(
         pointer = buf.pointer,
         capacity = buf.capacity,
@@ -48,7 +60,7 @@ internal fun {{ fully_qualified_ffi_converter_name }}.read{{ name }}(buf: ByteBu
     return result
 }
 
-internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|class_name(ci) }}, buf: ByteBuffer) {
+internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{ name|external_class_name(module_path, ci, config) }}, buf: ByteBuffer) {
     val externalBuffer = {{ package_name }}.ByteBuffer(
         pointer = buf.pointer,
         capacity = buf.capacity,
@@ -56,4 +68,5 @@ internal fun {{ fully_qualified_ffi_converter_name }}.write{{ name }}(value: {{
     )
     write(value, externalBuffer)
     buf.position = externalBuffer.position()
-}
\ No newline at end of file
+}
+{%- endif %}
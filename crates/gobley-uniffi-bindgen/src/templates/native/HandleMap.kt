@@ -1,3 +1,4 @@
+{%- if !config.has_shared_platform_runtime(module_name) %}
 
 internal class UniffiHandleMap<T: Any> {
     private val mapLock = kotlinx.atomicfu.locks.ReentrantLock()
@@ -35,3 +36,4 @@ internal class UniffiHandleMap<T: Any> {
         }
     }
 }
+{%- endif %}
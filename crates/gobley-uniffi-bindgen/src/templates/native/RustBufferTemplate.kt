@@ -1,4 +1,9 @@
 {% include "ffi/RustBufferTemplate.kt" %}
+{#-
+ The `RustBuffer` of Kotlin/Native is a cinterop struct generated from the header of each
+ component, so each component has its own `RustBuffer` type even if the layout is identical. These
+ declarations are not shared through `runtime_package` for this reason.
+#}
 
 {{ visibility() }}typealias RustBuffer = CPointer<{{ ci.namespace() }}.cinterop.RustBuffer>
 
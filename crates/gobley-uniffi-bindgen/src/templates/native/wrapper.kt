@@ -54,6 +54,7 @@ import platform.posix.memcpy
 {%- for req in self.imports() %}
 {{ req.render() }}
 {%- endfor %}
+{{- self.split_output_helpers_section() }}
 
 {% include "PointerHelper.kt" %}
 
@@ -70,14 +71,29 @@ import platform.posix.memcpy
 
 // Public interface members begin here.
 {{ type_helper_code }}
+{{- self.split_output_section_end() }}
 
 {% import "macros.kt" as kt %}
 
-{%- for func in ci.function_definitions() %}
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
 {%- include "ffi/TopLevelFunctionTemplate.kt" %}
 {%- endfor %}
+{{- self.visibility_section_end() }}
+{{- self.split_output_helpers_section() }}
 
 // Async support
 {%- if ci.has_async_fns() %}
 {% include "Async.kt" %}
 {%- endif %}
+
+{%- if config.has_suspend_wrappers(ci) %}
+{% include "ffi/BlockingDispatcher.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
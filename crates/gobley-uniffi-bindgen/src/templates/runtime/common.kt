@@ -0,0 +1,14 @@
+@file:Suppress("RemoveRedundantBackticks")
+
+package {{ package_name }}
+
+// Helper code shared by the components using `runtime_package = "{{ package_name }}"`.
+//
+// The helpers in this package don't depend on the FFI of any component, so they can be shared
+// across components generated by the same version of the bindgen.
+
+{% include "common/Helpers.kt" %}
+
+{% include "common/Disposable.kt" %}
+
+{% include "ffi/ObjectCleanerHelper.kt" %}
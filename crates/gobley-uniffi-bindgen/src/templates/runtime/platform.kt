@@ -0,0 +1,51 @@
+@file:Suppress("RemoveRedundantBackticks")
+{%- if module_name == "native" %}
+@file:OptIn(ExperimentalForeignApi::class)
+{%- endif %}
+
+package {{ package_name }}
+
+// Platform-specific helper code shared by the components using
+// `runtime_package = "{{ package_name }}"`.
+{%- if module_name == "native" %}
+
+import kotlinx.cinterop.CPointer
+import kotlinx.cinterop.ExperimentalForeignApi
+import kotlinx.cinterop.addressOf
+import kotlinx.cinterop.convert
+import kotlinx.cinterop.get
+import kotlinx.cinterop.plus
+import kotlinx.cinterop.set
+import kotlinx.cinterop.usePinned
+import platform.posix.memcpy
+
+{% include "native/ByteBuffer.kt" %}
+{% include "native/HandleMap.kt" %}
+{%- else %}
+{%- if jna %}
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+{%- endif %}
+
+{% include "android+jvm/ByteBuffer.kt" %}
+{%- if jna %}
+{% include "android+jvm/RustBufferStruct.kt" %}
+{%- endif %}
+{% include "android+jvm/HandleMap.kt" %}
+{%- endif %}
+{% include "ffi/FfiConverter.kt" %}
+
+{% include "ffi/BooleanHelper.kt" %}
+{% include "ffi/Int8Helper.kt" %}
+{% include "ffi/Int16Helper.kt" %}
+{% include "ffi/Int32Helper.kt" %}
+{% include "ffi/Int64Helper.kt" %}
+{% include "ffi/UInt8Helper.kt" %}
+{% include "ffi/UInt16Helper.kt" %}
+{% include "ffi/UInt32Helper.kt" %}
+{% include "ffi/UInt64Helper.kt" %}
+{% include "ffi/Float32Helper.kt" %}
+{% include "ffi/Float64Helper.kt" %}
+
+{% import "macros.kt" as kt %}
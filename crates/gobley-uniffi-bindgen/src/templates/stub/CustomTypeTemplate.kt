@@ -0,0 +1,21 @@
+
+{%- match config.custom_types.get(name.as_str()) %}
+{%- when Some(custom_type) %}
+{%- if custom_type.has_platform_types() %}
+{%- let config = custom_type.for_module(module_name, type_name) %}
+
+{%- match config.imports %}
+{%- when Some(imports) %}
+{%- for import_name in imports %}
+{{ self.add_import(import_name) }}
+{%- endfor %}
+{%- else %}
+{%- endmatch %}
+
+/**
+ * Typealias from the type name used in the UDL file to the custom type on this platform.
+ */
+{{ visibility() }}actual typealias {{ type_name }} = {% match config.type_name %}{% when Some(concrete_type_name) %}{{ concrete_type_name }}{% else %}{{ builtin|type_name(ci, self.config) }}{% endmatch %}
+{%- endif %}
+{%- else %}
+{%- endmatch %}
@@ -1,8 +1,8 @@
 
 {%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let interface_name = self::object_interface_name(ci, obj) %}
-{%- let impl_class_name = self::object_impl_name(ci, obj) %}
-{%- let methods = obj.methods() %}
+{%- let interface_name = self::object_interface_name(ci, config, obj) %}
+{%- let impl_class_name = self::object_impl_name(ci, config, obj) %}
+{%- let methods = config.included_methods(obj) %}
 {%- let interface_docstring = obj.docstring() %}
 {%- let is_error = ci.is_name_used_as_error(name) %}
 {%- let ffi_converter_name = obj|ffi_converter_name %}
@@ -13,7 +13,7 @@
 {% else -%}
 {{ visibility() }}actual open class {{ impl_class_name }}: Disposable, {{ interface_name }}
 {%- for t in obj.trait_impls() -%}
-, {{ self::trait_interface_name(ci, t.trait_name)? }}
+, {{ self::trait_interface_name(ci, config, t.trait_name)? }}
 {%- endfor %} {
 {%- endif %}
 
@@ -46,8 +46,8 @@
         TODO()
     }
 
-    {% for meth in obj.methods() -%}
-    {%- call kt::func_decl_with_stub("actual override", meth, 4) -%}
+    {% for meth in config.included_methods(obj) -%}
+    {%- call kt::func_decl_with_stub("actual override", meth, name, 4) -%}
     {% endfor %}
 
     {%- for tm in obj.uniffi_traits() %}
@@ -70,10 +70,10 @@
     {%- endfor %}
 
     {# XXX - "companion object" confusion? How to have alternate constructors *and* be an error? #}
-    {% if !obj.alternate_constructors().is_empty() -%}
+    {% if !config.included_constructors(obj).is_empty() -%}
     {{ visibility() }}actual companion object {
-        {% for cons in obj.alternate_constructors() -%}
-        {%- call kt::func_decl_with_stub("actual", cons, 8) %}
+        {% for cons in config.included_constructors(obj) -%}
+        {%- call kt::func_decl_with_stub("actual", cons, name, 8) %}
         {% endfor %}
     }
     {% else %}
@@ -1 +1 @@
-{%- call kt::func_decl_with_stub("actual", func, 0) %}
\ No newline at end of file
+{%- call kt::func_decl_with_stub("actual", func, "", 0) %}
\ No newline at end of file
@@ -1,8 +1,10 @@
 
 {%- import "macros.kt" as kt %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name(ci) %}
+{%- for type_ in self.generated_types() %}
+{{- self.split_output_section(type_) }}
+{{- self.visibility_section(type_) }}
+{%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 {%- let contains_object_references = ci.item_contains_object_references(type_) %}
@@ -22,6 +24,11 @@
 {%- when Type::Object { module_path, name, imp } %}
 {% include "ObjectTemplate.kt" %}
 
+{%- when Type::Custom { module_path, name, builtin } %}
+{% include "CustomTypeTemplate.kt" %}
+
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
+{{- self.split_output_section_end() }}
+{{- self.visibility_section_end() }}
@@ -4,11 +4,28 @@
 
 package {{ config.package_name() }}
 
+{{- self.split_output_helpers_section() }}
+
 // Public interface members begin here.
 {{ type_helper_code }}
+{{- self.split_output_section_end() }}
 
 {% import "macros.kt" as kt %}
 
-{%- for func in ci.function_definitions() %}
+{%- for func in self.generated_functions() %}
+{{- self.visibility_section(func) }}
 {%- include "TopLevelFunctionTemplate.kt" %}
 {%- endfor %}
+{{- self.visibility_section_end() }}
+{{- self.split_output_helpers_section() }}
+
+{%- if config.has_suspend_wrappers(ci) %}
+{% include "ffi/BlockingDispatcher.kt" %}
+{%- endif %}
+
+{%- if config.generate_parcelable() %}
+{% include "ffi/Parcelable.kt" %}
+{%- endif %}
+{%- if config.compose_stability() %}
+{% include "ffi/ComposeStability.kt" %}
+{%- endif %}
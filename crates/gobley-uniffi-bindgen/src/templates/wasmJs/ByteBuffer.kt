@@ -0,0 +1,96 @@
+
+{{ visibility() }}class ByteBuffer(
+    internal val pointer: Pointer,
+    internal val capacity: Int,
+    internal var position: Int = 0,
+) {
+    {{ visibility() }}fun position(): Int = position
+
+    {{ visibility() }}fun hasRemaining(): Boolean = capacity != position
+
+    private fun checkRemaining(bytes: Int) {
+        val remaining = capacity - position
+        require(bytes <= remaining) { 
+            "buffer is exhausted: required: $bytes, remaining: $remaining, capacity: $capacity, position: $position" 
+        }
+    }
+
+    {{ visibility() }}fun get(): Byte {
+        checkRemaining(1)
+        return UniffiWasmMemory.getByte(pointer + position++)
+    }
+
+    {{ visibility() }}fun get(bytesToRead: Int): ByteArray {
+        checkRemaining(bytesToRead)
+        val result = ByteArray(bytesToRead)
+        UniffiWasmMemory.getBytes(pointer + position, result)
+        position += bytesToRead
+        return result
+    }
+
+    {{ visibility() }}fun getShort(): Short {
+        checkRemaining(2)
+        return UniffiWasmMemory.getShort(pointer + position).also { position += 2 }
+    }
+
+    {{ visibility() }}fun getInt(): Int {
+        checkRemaining(4)
+        return UniffiWasmMemory.getInt(pointer + position).also { position += 4 }
+    }
+
+    {{ visibility() }}fun getLong(): Long {
+        checkRemaining(8)
+        return UniffiWasmMemory.getLong(pointer + position).also { position += 8 }
+    }
+
+    {{ visibility() }}fun getFloat(): Float {
+        checkRemaining(4)
+        return UniffiWasmMemory.getFloat(pointer + position).also { position += 4 }
+    }
+
+    {{ visibility() }}fun getDouble(): Double {
+        checkRemaining(8)
+        return UniffiWasmMemory.getDouble(pointer + position).also { position += 8 }
+    }
+
+    {{ visibility() }}fun put(value: Byte) {
+        checkRemaining(1)
+        UniffiWasmMemory.setByte(pointer + position++, value)
+    }
+
+    {{ visibility() }}fun put(src: ByteArray) {
+        checkRemaining(src.size)
+        UniffiWasmMemory.setBytes(pointer + position, src)
+        position += src.size
+    }
+
+    {{ visibility() }}fun putShort(value: Short) {
+        checkRemaining(2)
+        UniffiWasmMemory.setShort(pointer + position, value)
+        position += 2
+    }
+
+    {{ visibility() }}fun putInt(value: Int) {
+        checkRemaining(4)
+        UniffiWasmMemory.setInt(pointer + position, value)
+        position += 4
+    }
+
+    {{ visibility() }}fun putLong(value: Long) {
+        checkRemaining(8)
+        UniffiWasmMemory.setLong(pointer + position, value)
+        position += 8
+    }
+
+    {{ visibility() }}fun putFloat(value: Float) {
+        checkRemaining(4)
+        UniffiWasmMemory.setFloat(pointer + position, value)
+        position += 4
+    }
+
+    {{ visibility() }}fun putDouble(value: Double) {
+        checkRemaining(8)
+        UniffiWasmMemory.setDouble(pointer + position, value)
+        position += 8
+    }
+}
@@ -0,0 +1,31 @@
+{% include "ffi/Helpers.kt" %}
+
+// struct RustCallStatus { code: i8, error_buf: RustBuffer }
+internal const val UNIFFI_RUST_CALL_STATUS_SIZE = 32
+
+internal class UniffiRustCallStatus(internal val address: Pointer)
+internal var UniffiRustCallStatus.code: Byte
+    get() = UniffiWasmMemory.getByte(address)
+    set(value) { UniffiWasmMemory.setByte(address, value) }
+internal var UniffiRustCallStatus.errorBuf: RustBufferByValue
+    get() = RustBuffer(address + 8).readValue()
+    set(value) { value.write(address + 8) }
+
+internal class UniffiRustCallStatusByValue(
+    internal val code: Byte,
+    internal val errorBuf: RustBufferByValue,
+)
+
+internal object UniffiRustCallStatusHelper {
+    fun allocValue() = UniffiRustCallStatusByValue(UNIFFI_CALL_SUCCESS, RustBufferByValue(0L, 0L, null))
+    fun <U> withReference(
+        block: (UniffiRustCallStatus) -> U
+    ): U {
+        return uniffiWithWasmStackFrame { frame ->
+            val status = UniffiRustCallStatus(frame.alloc(UNIFFI_RUST_CALL_STATUS_SIZE))
+            status.code = UNIFFI_CALL_SUCCESS
+            status.errorBuf = RustBufferByValue(0L, 0L, null)
+            block(status)
+        }
+    }
+}
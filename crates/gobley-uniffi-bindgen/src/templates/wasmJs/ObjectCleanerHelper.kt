@@ -0,0 +1,42 @@
+{% include "ffi/ObjectCleanerHelper.kt" %}
+{{- self.add_import("kotlinx.atomicfu.atomic") }}
+
+private external class FinalizationRegistry(cleanupCallback: (heldValue: JsAny) -> Unit) : JsAny {
+    fun register(target: JsAny, heldValue: JsAny, unregisterToken: JsAny)
+    fun unregister(unregisterToken: JsAny): Boolean
+}
+
+private class WasmJsFinalizationRegistryCleaner : UniffiCleaner {
+    private val registry = FinalizationRegistry { heldValue ->
+        heldValue.unsafeCast<JsReference<Disposable>>().get().destroy()
+    }
+
+    override fun register(resource: Any, disposable: Disposable): UniffiCleaner.Cleanable {
+        // The held value must not reference the resource, or the resource will never be
+        // collected.
+        val cleanable = WasmJsFinalizationRegistryCleanable(registry, disposable)
+        registry.register(
+            resource.toJsReference(),
+            disposable.toJsReference(),
+            cleanable.toJsReference(),
+        )
+        return cleanable
+    }
+
+    private class WasmJsFinalizationRegistryCleanable(
+        private val registry: FinalizationRegistry,
+        private val disposable: Disposable,
+    ) : UniffiCleaner.Cleanable {
+        private val cleaned = atomic(false)
+
+        override fun clean() {
+            if (cleaned.compareAndSet(false, true)) {
+                registry.unregister(toJsReference())
+                disposable.destroy()
+            }
+        }
+    }
+}
+
+private fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    WasmJsFinalizationRegistryCleaner()
@@ -0,0 +1,56 @@
+{% include "ffi/RustBufferTemplate.kt" %}
+
+// struct RustBuffer { capacity: u64, len: u64, data: *mut u8 }
+internal const val UNIFFI_RUST_BUFFER_SIZE = 24
+
+{{ visibility() }}class RustBuffer internal constructor(internal val address: Pointer)
+
+{{ visibility() }}var RustBuffer.capacity: Long
+    get() = UniffiWasmMemory.getLong(address, true)
+    set(value) { UniffiWasmMemory.setLong(address, value, true) }
+{{ visibility() }}var RustBuffer.len: Long
+    get() = UniffiWasmMemory.getLong(address + 8, true)
+    set(value) { UniffiWasmMemory.setLong(address + 8, value, true) }
+{{ visibility() }}var RustBuffer.data: Pointer?
+    get() = UniffiWasmMemory.getPointer(address + 16)
+    set(value) { UniffiWasmMemory.setPointer(address + 16, value) }
+{{ visibility() }}fun RustBuffer.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("len") %}
+    return ByteBuffer(
+        data ?: return null,
+        len.toInt(),
+    )
+}
+internal fun RustBuffer.readValue(): RustBufferByValue = RustBufferByValue(
+    capacity = capacity,
+    len = len,
+    data = data,
+)
+
+{{ visibility() }}class RustBufferByValue(
+    {{ visibility() }}val capacity: Long,
+    {{ visibility() }}val len: Long,
+    {{ visibility() }}val data: Pointer?,
+)
+{{ visibility() }}fun RustBufferByValue.asByteBuffer(): ByteBuffer? {
+    {% call kt::check_rust_buffer_length("len") %}
+    return ByteBuffer(
+        data ?: return null,
+        len.toInt(),
+    )
+}
+internal fun RustBufferByValue.write(address: Pointer) {
+    RustBuffer(address).setValue(this)
+}
+
+// struct ForeignBytes { len: i32, data: *const u8 }
+internal const val UNIFFI_FOREIGN_BYTES_SIZE = 8
+
+internal class ForeignBytesByValue(
+    internal val len: Int,
+    internal val data: Pointer?,
+)
+internal fun ForeignBytesByValue.write(address: Pointer) {
+    UniffiWasmMemory.setInt(address, len, true)
+    UniffiWasmMemory.setPointer(address + 4, data)
+}
@@ -0,0 +1,126 @@
+internal val uniffiWasmExports: JsAny
+    get() = uniffiWasmInstance.exports
+
+// The memory buffer is replaced whenever the memory grows, so always make a fresh view.
+@JsFun("(exports, byteOffset) => new DataView(exports.memory.buffer).getInt8(byteOffset)")
+private external fun uniffiWasmGetInt8(exports: JsAny, byteOffset: Int): Byte
+
+@JsFun("(exports, byteOffset, value) => new DataView(exports.memory.buffer).setInt8(byteOffset, value)")
+private external fun uniffiWasmSetInt8(exports: JsAny, byteOffset: Int, value: Byte)
+
+@JsFun("(exports, byteOffset, littleEndian) => new DataView(exports.memory.buffer).getInt16(byteOffset, littleEndian)")
+private external fun uniffiWasmGetInt16(exports: JsAny, byteOffset: Int, littleEndian: Boolean): Short
+
+@JsFun("(exports, byteOffset, value, littleEndian) => new DataView(exports.memory.buffer).setInt16(byteOffset, value, littleEndian)")
+private external fun uniffiWasmSetInt16(exports: JsAny, byteOffset: Int, value: Short, littleEndian: Boolean)
+
+@JsFun("(exports, byteOffset, littleEndian) => new DataView(exports.memory.buffer).getInt32(byteOffset, littleEndian)")
+private external fun uniffiWasmGetInt32(exports: JsAny, byteOffset: Int, littleEndian: Boolean): Int
+
+@JsFun("(exports, byteOffset, value, littleEndian) => new DataView(exports.memory.buffer).setInt32(byteOffset, value, littleEndian)")
+private external fun uniffiWasmSetInt32(exports: JsAny, byteOffset: Int, value: Int, littleEndian: Boolean)
+
+@JsFun("(exports, byteOffset, littleEndian) => new DataView(exports.memory.buffer).getBigInt64(byteOffset, littleEndian)")
+private external fun uniffiWasmGetInt64(exports: JsAny, byteOffset: Int, littleEndian: Boolean): Long
+
+@JsFun("(exports, byteOffset, value, littleEndian) => new DataView(exports.memory.buffer).setBigInt64(byteOffset, value, littleEndian)")
+private external fun uniffiWasmSetInt64(exports: JsAny, byteOffset: Int, value: Long, littleEndian: Boolean)
+
+@JsFun("(exports, byteOffset, littleEndian) => new DataView(exports.memory.buffer).getFloat32(byteOffset, littleEndian)")
+private external fun uniffiWasmGetFloat32(exports: JsAny, byteOffset: Int, littleEndian: Boolean): Float
+
+@JsFun("(exports, byteOffset, value, littleEndian) => new DataView(exports.memory.buffer).setFloat32(byteOffset, value, littleEndian)")
+private external fun uniffiWasmSetFloat32(exports: JsAny, byteOffset: Int, value: Float, littleEndian: Boolean)
+
+@JsFun("(exports, byteOffset, littleEndian) => new DataView(exports.memory.buffer).getFloat64(byteOffset, littleEndian)")
+private external fun uniffiWasmGetFloat64(exports: JsAny, byteOffset: Int, littleEndian: Boolean): Double
+
+@JsFun("(exports, byteOffset, value, littleEndian) => new DataView(exports.memory.buffer).setFloat64(byteOffset, value, littleEndian)")
+private external fun uniffiWasmSetFloat64(exports: JsAny, byteOffset: Int, value: Double, littleEndian: Boolean)
+
+@JsFun("(exports, delta) => exports.__gobley_add_to_stack_pointer(delta)")
+private external fun uniffiWasmAddToStackPointer(exports: JsAny, delta: Int): Int
+
+// Accessors to the linear memory of the WebAssembly module. Multi-byte values are read in
+// big-endian order by default, as serialized by UniFFI. Structs in the linear memory are laid out
+// in little-endian order.
+internal object UniffiWasmMemory {
+    fun getByte(pointer: Pointer): Byte = uniffiWasmGetInt8(uniffiWasmExports, pointer)
+    fun setByte(pointer: Pointer, value: Byte) = uniffiWasmSetInt8(uniffiWasmExports, pointer, value)
+
+    fun getShort(pointer: Pointer, littleEndian: Boolean = false): Short =
+        uniffiWasmGetInt16(uniffiWasmExports, pointer, littleEndian)
+    fun setShort(pointer: Pointer, value: Short, littleEndian: Boolean = false) =
+        uniffiWasmSetInt16(uniffiWasmExports, pointer, value, littleEndian)
+
+    fun getInt(pointer: Pointer, littleEndian: Boolean = false): Int =
+        uniffiWasmGetInt32(uniffiWasmExports, pointer, littleEndian)
+    fun setInt(pointer: Pointer, value: Int, littleEndian: Boolean = false) =
+        uniffiWasmSetInt32(uniffiWasmExports, pointer, value, littleEndian)
+
+    fun getLong(pointer: Pointer, littleEndian: Boolean = false): Long =
+        uniffiWasmGetInt64(uniffiWasmExports, pointer, littleEndian)
+    fun setLong(pointer: Pointer, value: Long, littleEndian: Boolean = false) =
+        uniffiWasmSetInt64(uniffiWasmExports, pointer, value, littleEndian)
+
+    fun getFloat(pointer: Pointer, littleEndian: Boolean = false): Float =
+        uniffiWasmGetFloat32(uniffiWasmExports, pointer, littleEndian)
+    fun setFloat(pointer: Pointer, value: Float, littleEndian: Boolean = false) =
+        uniffiWasmSetFloat32(uniffiWasmExports, pointer, value, littleEndian)
+
+    fun getDouble(pointer: Pointer, littleEndian: Boolean = false): Double =
+        uniffiWasmGetFloat64(uniffiWasmExports, pointer, littleEndian)
+    fun setDouble(pointer: Pointer, value: Double, littleEndian: Boolean = false) =
+        uniffiWasmSetFloat64(uniffiWasmExports, pointer, value, littleEndian)
+
+    fun getPointer(pointer: Pointer): Pointer? = getInt(pointer, true).takeIf { it != 0 }
+    fun setPointer(pointer: Pointer, value: Pointer?) = setInt(pointer, value ?: 0, true)
+
+    // Kotlin/Wasm arrays live in the GC heap, so bytes have to be copied one by one.
+    fun getBytes(pointer: Pointer, destination: ByteArray) {
+        for (idx in destination.indices) {
+            destination[idx] = getByte(pointer + idx)
+        }
+    }
+
+    fun setBytes(pointer: Pointer, source: ByteArray) {
+        for (idx in source.indices) {
+            setByte(pointer + idx, source[idx])
+        }
+    }
+}
+
+// Structs passed by value are copied to the shadow stack of the WebAssembly module, and a
+// pointer to the copy is passed instead.
+internal class UniffiWasmStackFrame {
+    private var allocated = 0
+
+    fun alloc(size: Int): Pointer {
+        // Keep the stack pointer aligned to 16 bytes.
+        val alignedSize = (size + 15) and 15.inv()
+        allocated += alignedSize
+        return uniffiWasmAddToStackPointer(uniffiWasmExports, -alignedSize)
+    }
+
+    fun push(value: RustBufferByValue): Pointer =
+        alloc(UNIFFI_RUST_BUFFER_SIZE).also { value.write(it) }
+
+    fun push(value: ForeignBytesByValue): Pointer =
+        alloc(UNIFFI_FOREIGN_BYTES_SIZE).also { value.write(it) }
+
+    fun release() {
+        if (allocated != 0) {
+            uniffiWasmAddToStackPointer(uniffiWasmExports, allocated)
+            allocated = 0
+        }
+    }
+}
+
+internal inline fun <T> uniffiWithWasmStackFrame(block: (UniffiWasmStackFrame) -> T): T {
+    val frame = UniffiWasmStackFrame()
+    try {
+        return block(frame)
+    } finally {
+        frame.release()
+    }
+}
@@ -32,6 +32,13 @@ pub struct KotlinJsRenderer<'a> {
     module: &'a Module,
 }
 
+#[derive(Template)]
+#[template(syntax = "kt", escape = "none", path = "wasm_js.kt")]
+pub struct KotlinWasmJsRenderer<'a> {
+    package_name: Option<&'a str>,
+    base64: &'a str,
+}
+
 impl<'a> KotlinJsRenderer<'a> {
     fn import_modules(&self) -> Vec<String> {
         let import_modules = self
@@ -206,4 +213,22 @@ impl Transformer {
         };
         Ok(renderer.render()?)
     }
+
+    pub fn render_into_wasm_js_kt(mut self, package_name: Option<&str>) -> anyhow::Result<String> {
+        use base64::prelude::BASE64_STANDARD;
+
+        self.transform()?;
+
+        if self.module.imports.iter().next().is_some() {
+            anyhow::bail!("WebAssembly modules with imports are not supported in Kotlin/Wasm yet");
+        }
+
+        let wasm = self.module.emit_wasm();
+        let wasm_base64 = BASE64_STANDARD.encode(&wasm);
+        let renderer = KotlinWasmJsRenderer {
+            package_name,
+            base64: &wasm_base64,
+        };
+        Ok(renderer.render()?)
+    }
 }
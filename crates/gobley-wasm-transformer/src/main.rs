@@ -10,7 +10,7 @@ use std::str::FromStr as _;
 
 use anyhow::Context;
 use camino::Utf8PathBuf;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use gobley_wasm_transformer::import::WasmFunctionImport;
 use gobley_wasm_transformer::Transformer;
 
@@ -35,6 +35,16 @@ struct Cli {
     /// inserted into the transformed WASM module.
     #[clap(long, short)]
     function_imports_file: Option<Utf8PathBuf>,
+
+    /// The Kotlin target the resulting .kt file will be compiled for.
+    #[clap(long, value_enum, default_value_t = KotlinTarget::Js)]
+    kotlin_target: KotlinTarget,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum KotlinTarget {
+    Js,
+    WasmJs,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -43,6 +53,7 @@ fn main() -> anyhow::Result<()> {
         output,
         package_name,
         function_imports_file: function_imports_file_path,
+        kotlin_target,
     } = Cli::parse();
     let input = fs::read(&input).with_context(|| format!("failed to read `{input}`"))?;
 
@@ -67,7 +78,10 @@ fn main() -> anyhow::Result<()> {
     }
 
     let transformer = Transformer::new(&input, function_imports)?;
-    let output_kt = transformer.render_into_kt(package_name.as_deref())?;
+    let output_kt = match kotlin_target {
+        KotlinTarget::Js => transformer.render_into_kt(package_name.as_deref())?,
+        KotlinTarget::WasmJs => transformer.render_into_wasm_js_kt(package_name.as_deref())?,
+    };
     fs::write(&output, output_kt).with_context(|| format!("failed to write `{output}`"))?;
     Ok(())
 }
@@ -94,4 +94,25 @@ internal fun createInstance(
     imports: RustWebAssemblyImports,
 ): WebAssembly.Instance<RustWebAssemblyExports> {
     return WebAssembly.Instance(module, imports)
-}
\ No newline at end of file
+}
+{%- if import_modules().is_empty() %}
+
+// The instance shared by all generated bindings for this module.
+internal val instance: WebAssembly.Instance<RustWebAssemblyExports> by lazy {
+    createInstance(RustWebAssemblyImports())
+}
+{%- else %}
+
+private var initializedInstance: WebAssembly.Instance<RustWebAssemblyExports>? = null
+
+// Creates the instance shared by all generated bindings for this module. Since the module imports
+// functions, this must be called with their implementations before calling into Rust.
+internal fun initializeInstance(imports: RustWebAssemblyImports) {
+    initializedInstance = createInstance(imports)
+}
+
+// The instance shared by all generated bindings for this module.
+internal val instance: WebAssembly.Instance<RustWebAssemblyExports>
+    get() = initializedInstance
+        ?: error("the WebAssembly module imports functions, so initializeInstance() must be called first")
+{%- endif %}
\ No newline at end of file
@@ -0,0 +1,22 @@
+{%- if let Some(package_name) = package_name -%}
+package {{ package_name }}
+
+{% endif -%}
+private const val BASE64 = "{{ base64 }}"
+
+internal external interface RustWebAssemblyInstance : JsAny {
+    val exports: JsAny
+}
+
+@JsFun("""(string) => {
+    const buffer = typeof Buffer === "undefined"
+        ? Uint8Array.from(atob(string), (c) => c.charCodeAt(0))
+        : Buffer.from(string, "base64");
+    return new WebAssembly.Instance(new WebAssembly.Module(buffer), {});
+}""")
+private external fun instanceFromBase64(string: String): RustWebAssemblyInstance
+
+// The instance shared by all generated bindings for this module.
+internal val instance: RustWebAssemblyInstance by lazy {
+    instanceFromBase64(BASE64)
+}
@@ -38,8 +38,14 @@ if (ext.propertyIsTrue("gobley.projects.gradleTests")) {
 }
 
 if (ext.propertyIsTrue("gobley.projects.uniffiTests")) {
+    include(":tests:uniffi:async-dispatcher")
+    include(":tests:uniffi:async-iterators")
+    include(":tests:uniffi:bindgen-check")
+    include(":tests:uniffi:bindgen-manifest")
     include(":tests:uniffi:callbacks")
     include(":tests:uniffi:chronological")
+    include(":tests:uniffi:compose-stability")
+    include(":tests:uniffi:config-validation")
     include(":tests:uniffi:coverall")
     include(":tests:uniffi:coverall-android")
     include(":tests:uniffi:coverall-jvm")
@@ -56,16 +62,34 @@ if (ext.propertyIsTrue("gobley.projects.uniffiTests")) {
     include(":tests:uniffi:ext-types:http-headermap")
     include(":tests:uniffi:ext-types:sub-lib")
     include(":tests:uniffi:ext-types:uniffi-one")
+    include(":tests:uniffi:ffm-backend")
     include(":tests:uniffi:futures")
+    include(":tests:uniffi:include-exclude")
+    include(":tests:uniffi:java-interop")
+    include(":tests:uniffi:jni-backend")
+    include(":tests:uniffi:js-target")
+    include(":tests:uniffi:jvm-common-source-set")
     include(":tests:uniffi:keywords")
     include(":tests:uniffi:large-enum")
     include(":tests:uniffi:large-error")
+    include(":tests:uniffi:leak-tracking")
+    include(":tests:uniffi:log-forwarding")
+    include(":tests:uniffi:parcelable")
+    include(":tests:uniffi:platform-custom-types")
     include(":tests:uniffi:proc-macro")
+    include(":tests:uniffi:rename")
+    include(":tests:uniffi:runtime-package")
+    include(":tests:uniffi:rust-panics")
     include(":tests:uniffi:simple-fns")
     include(":tests:uniffi:simple-iface")
+    include(":tests:uniffi:split-output")
     include(":tests:uniffi:struct-default-values")
+    include(":tests:uniffi:suspend-wrappers")
     include(":tests:uniffi:trait-methods")
     include(":tests:uniffi:type-limits")
+    include(":tests:uniffi:value-class")
+    include(":tests:uniffi:visibility")
+    include(":tests:uniffi:wasm-js-target")
 }
 
 if (ext.propertyIsTrue("gobley.projects.examples")) {
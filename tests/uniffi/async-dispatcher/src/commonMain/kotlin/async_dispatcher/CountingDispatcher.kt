@@ -0,0 +1,28 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+package async_dispatcher
+
+import kotlinx.atomicfu.atomic
+import kotlinx.coroutines.CoroutineDispatcher
+import kotlinx.coroutines.Dispatchers
+import kotlinx.coroutines.Runnable
+import kotlin.coroutines.CoroutineContext
+
+/**
+ * The dispatcher configured with `async_dispatcher`, counting the blocks dispatched to it.
+ */
+public object CountingDispatcher : CoroutineDispatcher() {
+    private val dispatchCount = atomic(0)
+
+    public val dispatches: Int
+        get() = dispatchCount.value
+
+    override fun dispatch(context: CoroutineContext, block: Runnable) {
+        dispatchCount.incrementAndGet()
+        Dispatchers.Default.dispatch(context, block)
+    }
+}
@@ -0,0 +1,42 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future returning `Pending` once, so the foreign side has to poll it again.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[uniffi::export]
+async fn sum(values: Vec<u32>) -> u32 {
+    let mut total = 0;
+    for value in values {
+        YieldNow(false).await;
+        total += value;
+    }
+    total
+}
+
+#[uniffi::export]
+async fn greet(name: String) -> String {
+    YieldNow(false).await;
+    format!("Hello, {name}!")
+}
+
+uniffi::include_scaffolding!("async-dispatcher");
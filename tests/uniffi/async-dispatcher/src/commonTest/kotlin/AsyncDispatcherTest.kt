@@ -0,0 +1,53 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import async_dispatcher.*
+import io.kotest.matchers.comparables.shouldBeGreaterThan
+import io.kotest.matchers.shouldBe
+import kotlinx.coroutines.test.StandardTestDispatcher
+import kotlinx.coroutines.test.runTest
+import kotlin.coroutines.CoroutineContext
+import kotlin.coroutines.EmptyCoroutineContext
+import kotlin.test.Test
+
+class AsyncDispatcherTest {
+    private suspend fun <T> withAsyncDispatcher(context: CoroutineContext, block: suspend () -> T): T {
+        UniffiAsyncDispatcher.context = context
+        try {
+            return block()
+        } finally {
+            UniffiAsyncDispatcher.context = null
+        }
+    }
+
+    @Test
+    fun testConfiguredDispatcher() = runTest {
+        val dispatches = CountingDispatcher.dispatches
+        sum(listOf(1u, 2u, 3u)) shouldBe 6u
+        greet("Kotlin") shouldBe "Hello, Kotlin!"
+        CountingDispatcher.dispatches shouldBeGreaterThan dispatches
+    }
+
+    @Test
+    fun testTestDispatcher() = runTest {
+        val dispatches = CountingDispatcher.dispatches
+        withAsyncDispatcher(StandardTestDispatcher(testScheduler)) {
+            sum(listOf(1u, 2u, 3u)) shouldBe 6u
+            greet("Kotlin") shouldBe "Hello, Kotlin!"
+        }
+        CountingDispatcher.dispatches shouldBe dispatches
+    }
+
+    @Test
+    fun testCallerContext() = runTest {
+        val dispatches = CountingDispatcher.dispatches
+        withAsyncDispatcher(EmptyCoroutineContext) {
+            sum(listOf()) shouldBe 0u
+            greet("Rust") shouldBe "Hello, Rust!"
+        }
+        CountingDispatcher.dispatches shouldBe dispatches
+    }
+}
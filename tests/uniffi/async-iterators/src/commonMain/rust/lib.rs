@@ -0,0 +1,121 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+static LIVE_STREAMS: AtomicU32 = AtomicU32::new(0);
+static STARTED_POLLS: AtomicU32 = AtomicU32::new(0);
+static CANCELLED_POLLS: AtomicU32 = AtomicU32::new(0);
+
+/// Counts the objects that are still alive, so the tests can check that the flows destroy them.
+struct LiveStream;
+
+impl LiveStream {
+    fn new() -> Self {
+        LIVE_STREAMS.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for LiveStream {
+    fn drop(&mut self) {
+        LIVE_STREAMS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Counts the futures dropped before they complete.
+struct PollGuard;
+
+impl Drop for PollGuard {
+    fn drop(&mut self) {
+        CANCELLED_POLLS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct EventStream {
+    events: Mutex<VecDeque<String>>,
+    _live: LiveStream,
+}
+
+#[uniffi::export]
+impl EventStream {
+    #[uniffi::constructor]
+    fn new(events: Vec<String>) -> Arc<Self> {
+        Arc::new(Self {
+            events: Mutex::new(events.into()),
+            _live: LiveStream::new(),
+        })
+    }
+
+    async fn next(&self) -> Option<String> {
+        self.events.lock().unwrap().pop_front()
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Subscription {
+    remaining: Mutex<u32>,
+    _live: LiveStream,
+}
+
+#[uniffi::export]
+impl Subscription {
+    #[uniffi::constructor]
+    fn new(count: u32) -> Arc<Self> {
+        Arc::new(Self {
+            remaining: Mutex::new(count),
+            _live: LiveStream::new(),
+        })
+    }
+
+    async fn poll(&self) -> Option<u32> {
+        let mut remaining = self.remaining.lock().unwrap();
+        let value = *remaining;
+        *remaining = value.checked_sub(1)?;
+        Some(value)
+    }
+}
+
+/// A stream whose `next` never completes.
+#[derive(uniffi::Object)]
+pub struct StalledStream {
+    _live: LiveStream,
+}
+
+#[uniffi::export]
+impl StalledStream {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            _live: LiveStream::new(),
+        })
+    }
+
+    async fn next(&self) -> Option<u32> {
+        STARTED_POLLS.fetch_add(1, Ordering::SeqCst);
+        let _guard = PollGuard;
+        std::future::pending::<()>().await;
+        None
+    }
+}
+
+#[uniffi::export]
+fn live_streams() -> u32 {
+    LIVE_STREAMS.load(Ordering::SeqCst)
+}
+
+#[uniffi::export]
+fn started_polls() -> u32 {
+    STARTED_POLLS.load(Ordering::SeqCst)
+}
+
+#[uniffi::export]
+fn cancelled_polls() -> u32 {
+    CANCELLED_POLLS.load(Ordering::SeqCst)
+}
+
+uniffi::include_scaffolding!("async-iterators");
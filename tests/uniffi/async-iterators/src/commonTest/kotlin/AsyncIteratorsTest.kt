@@ -0,0 +1,54 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import async_iterators.*
+import io.kotest.matchers.shouldBe
+import kotlinx.coroutines.Dispatchers
+import kotlinx.coroutines.cancelAndJoin
+import kotlinx.coroutines.delay
+import kotlinx.coroutines.flow.map
+import kotlinx.coroutines.flow.take
+import kotlinx.coroutines.flow.toList
+import kotlinx.coroutines.launch
+import kotlinx.coroutines.test.runTest
+import kotlinx.coroutines.withContext
+import kotlin.test.Test
+
+class AsyncIteratorsTest {
+    @Test
+    fun testFlowCollectsUntilNull() = runTest {
+        EventStream(listOf("a", "b", "c")).asFlow().toList() shouldBe listOf("a", "b", "c")
+        EventStream(listOf()).asFlow().toList() shouldBe listOf()
+        liveStreams() shouldBe 0u
+    }
+
+    @Test
+    fun testCustomMethod() = runTest {
+        Subscription(3u).asFlow().map { it * 2u }.toList() shouldBe listOf(6u, 4u, 2u)
+        liveStreams() shouldBe 0u
+    }
+
+    @Test
+    fun testFlowDestroysObjectWhenAborted() = runTest {
+        Subscription(10u).asFlow().take(2).toList() shouldBe listOf(10u, 9u)
+        liveStreams() shouldBe 0u
+    }
+
+    @Test
+    fun testCancellationCancelsRustFuture() = runTest {
+        withContext(Dispatchers.Default) {
+            val job = launch {
+                StalledStream().asFlow().collect {}
+            }
+            while (startedPolls() == 0u) {
+                delay(10)
+            }
+            job.cancelAndJoin()
+        }
+        cancelledPolls() shouldBe 1u
+        liveStreams() shouldBe 0u
+    }
+}
@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[derive(uniffi::Enum)]
+pub enum Scale {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+#[derive(uniffi::Record)]
+pub struct Temperature {
+    pub value: f64,
+    pub scale: Scale,
+}
+
+#[uniffi::export]
+fn convert(temperature: Temperature, scale: Scale) -> Temperature {
+    let celsius = match temperature.scale {
+        Scale::Celsius => temperature.value,
+        Scale::Fahrenheit => (temperature.value - 32.0) * 5.0 / 9.0,
+        Scale::Kelvin => temperature.value - 273.15,
+    };
+    let value = match scale {
+        Scale::Celsius => celsius,
+        Scale::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        Scale::Kelvin => celsius + 273.15,
+    };
+    Temperature { value, scale }
+}
+
+uniffi::include_scaffolding!("bindgen-check");
@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.shouldNotBe
+import io.kotest.matchers.string.shouldContain
+import io.kotest.matchers.string.shouldNotContain
+import java.io.File
+import kotlin.io.path.createTempDirectory
+import kotlin.test.Test
+
+class BindgenCheckTest {
+    private class BindgenResult(val exitCode: Int, val output: String)
+
+    private fun bindgen(outDir: File, vararg arguments: String): BindgenResult {
+        val crateName = System.getProperty("gobley.crateName")
+        val config = System.getProperty("gobley.config")
+        val process = ProcessBuilder(
+            System.getProperty("gobley.bindgen"),
+            "--library",
+            "--crate", crateName,
+            "--config", config,
+            "--crate-configs", "$crateName=$config",
+            "--crate-paths", "$crateName=${System.getProperty("gobley.packageRoot")}",
+            "--out-dir", outDir.path,
+            *arguments,
+            System.getProperty("gobley.library"),
+        ).redirectErrorStream(true).start()
+        val output = process.inputStream.bufferedReader().readText()
+        return BindgenResult(process.waitFor(), output)
+    }
+
+    private fun generatedJvmFile(outDir: File) =
+        outDir.resolve("jvmMain/kotlin/bindgen_check/bindgen_check.jvm.kt")
+
+    @Test
+    fun testUpToDateBindingsPass() {
+        val outDir = createTempDirectory("bindgen-check").toFile()
+        bindgen(outDir).exitCode shouldBe 0
+
+        val result = bindgen(outDir, "--check")
+        result.exitCode shouldBe 0
+        result.output shouldNotContain "+++"
+    }
+
+    @Test
+    fun testChangedBindingsFail() {
+        val outDir = createTempDirectory("bindgen-check").toFile()
+        bindgen(outDir).exitCode shouldBe 0
+        val jvmFile = generatedJvmFile(outDir)
+        val editedContent = jvmFile.readText().replace("fun `convert`(", "fun `convertTemperature`(")
+        jvmFile.writeText(editedContent)
+
+        val result = bindgen(outDir, "--check")
+        result.exitCode shouldNotBe 0
+        result.output shouldContain "--- ${jvmFile.path}"
+        result.output shouldContain "+++ ${jvmFile.path}"
+        result.output shouldContain "-public actual fun `convertTemperature`("
+        result.output shouldContain "+public actual fun `convert`("
+        result.output shouldContain "1 generated file(s) are out of date"
+        // --check never overwrites the files on disk.
+        jvmFile.readText() shouldBe editedContent
+    }
+
+    @Test
+    fun testMissingBindingsFail() {
+        val outDir = createTempDirectory("bindgen-check").toFile()
+        bindgen(outDir).exitCode shouldBe 0
+        val jvmFile = generatedJvmFile(outDir)
+        jvmFile.delete()
+
+        val result = bindgen(outDir, "--check")
+        result.exitCode shouldNotBe 0
+        result.output shouldContain "--- /dev/null"
+        result.output shouldContain "+++ ${jvmFile.path}"
+        jvmFile.exists() shouldBe false
+    }
+
+    @Test
+    fun testStaleBindingsFail() {
+        val outDir = createTempDirectory("bindgen-check").toFile()
+        val manifest = outDir.resolve("manifest.json")
+        bindgen(outDir, "--manifest", manifest.path).exitCode shouldBe 0
+
+        // A file listed in the previous manifest that is not generated anymore is out of date.
+        val staleFile = outDir.resolve("jvmMain/kotlin/removed/removed.jvm.kt")
+        staleFile.parentFile.mkdirs()
+        staleFile.writeText("package removed\n")
+        manifest.writeText(
+            manifest.readText().replaceFirst(
+                "\"files\": [",
+                "\"files\": [{\"path\": ${quote(staleFile.path)}, \"target\": \"jvm\", \"source_set\": \"jvmMain\"},",
+            )
+        )
+
+        val result = bindgen(outDir, "--check", "--manifest", manifest.path)
+        result.exitCode shouldNotBe 0
+        result.output shouldContain "--- ${staleFile.path}"
+        result.output shouldContain "+++ /dev/null"
+        staleFile.exists() shouldBe true
+    }
+
+    private fun quote(value: String) = "\"" + value.replace("\\", "\\\\") + "\""
+}
@@ -0,0 +1,36 @@
+import gobley.gradle.uniffi.tasks.BuildUniffiBindingsTask
+
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+kotlin {
+    sourceSets {
+        jvmTest {
+            dependencies {
+                implementation(libs.kotlinx.serialization.json)
+            }
+        }
+    }
+}
+
+val buildUniffiBindings = tasks.named<BuildUniffiBindingsTask>("buildUniffiBindings")
+val bindgen = buildUniffiBindings.flatMap { it.bindgen }
+val libraryFile = buildUniffiBindings.flatMap { it.source }
+val mergedConfig = buildUniffiBindings.flatMap { it.config }
+val libraryCrateName = buildUniffiBindings.flatMap { it.libraryCrateName }
+val packageRoot = layout.projectDirectory.asFile.path
+
+// The tests run the bindgen the plugin installed against the library it built.
+tasks.named<Test>("jvmTest") {
+    dependsOn(buildUniffiBindings)
+    jvmArgumentProviders.add(CommandLineArgumentProvider {
+        listOf(
+            "-Dgobley.bindgen=${bindgen.get().asFile.path}",
+            "-Dgobley.library=${libraryFile.get().asFile.path}",
+            "-Dgobley.config=${mergedConfig.get().asFile.path}",
+            "-Dgobley.crateName=${libraryCrateName.get()}",
+            "-Dgobley.packageRoot=$packageRoot",
+        )
+    })
+}
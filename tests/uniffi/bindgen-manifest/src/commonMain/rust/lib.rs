@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[derive(uniffi::Record)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+#[derive(uniffi::Object)]
+pub struct Release {
+    name: String,
+    version: Version,
+}
+
+#[uniffi::export]
+impl Release {
+    #[uniffi::constructor]
+    fn new(name: String, version: Version) -> Arc<Self> {
+        Arc::new(Self { name, version })
+    }
+
+    fn tag(&self) -> String {
+        let Version {
+            major,
+            minor,
+            patch,
+        } = self.version;
+        format!("{}-v{major}.{minor}.{patch}", self.name)
+    }
+}
+
+uniffi::include_scaffolding!("bindgen-manifest");
@@ -0,0 +1,108 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.collections.shouldContainAll
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldContain
+import kotlinx.serialization.json.Json
+import kotlinx.serialization.json.JsonArray
+import kotlinx.serialization.json.JsonNull
+import kotlinx.serialization.json.JsonObject
+import kotlinx.serialization.json.JsonPrimitive
+import kotlinx.serialization.json.contentOrNull
+import kotlinx.serialization.json.jsonArray
+import kotlinx.serialization.json.jsonObject
+import kotlinx.serialization.json.jsonPrimitive
+import java.io.File
+import kotlin.io.path.createTempDirectory
+import kotlin.test.Test
+
+class BindgenManifestTest {
+    private val library = System.getProperty("gobley.library")
+    private val config = System.getProperty("gobley.config")
+    private val packageRoot = System.getProperty("gobley.packageRoot")
+
+    private fun bindgen(outDir: File, vararg arguments: String): Int {
+        val crateName = System.getProperty("gobley.crateName")
+        return ProcessBuilder(
+            System.getProperty("gobley.bindgen"),
+            "--library",
+            "--crate", crateName,
+            "--config", config,
+            "--crate-configs", "$crateName=$config",
+            "--crate-paths", "$crateName=$packageRoot",
+            "--out-dir", outDir.path,
+            *arguments,
+            library,
+        ).inheritIO().start().waitFor()
+    }
+
+    private fun manifestFiles(manifest: File): List<JsonObject> =
+        Json.parseToJsonElement(manifest.readText()).jsonObject["files"]!!.jsonArray
+            .map { it.jsonObject }
+
+    @Test
+    fun testManifestListsGeneratedFiles() {
+        val outDir = createTempDirectory("bindgen-manifest").toFile()
+        val manifest = outDir.resolve("manifest.json")
+        bindgen(outDir, "--manifest", manifest.path) shouldBe 0
+
+        val files = manifestFiles(manifest)
+        for (file in files) {
+            File(file["path"]!!.jsonPrimitive.content).isFile shouldBe true
+        }
+        files.map { it["target"]!!.jsonPrimitive.content } shouldContainAll listOf("common", "jvm", "native")
+        files.mapNotNull { it["source_set"]!!.jsonPrimitive.contentOrNull } shouldContainAll listOf(
+            "commonMain",
+            "jvmMain",
+            "nativeMain",
+        )
+        // The C header is not a Kotlin source.
+        files.single { it["path"]!!.jsonPrimitive.content.endsWith(".h") }["source_set"] shouldBe JsonNull
+    }
+
+    @Test
+    fun testManifestRemovesStaleFiles() {
+        val outDir = createTempDirectory("bindgen-manifest").toFile()
+        val manifest = outDir.resolve("manifest.json")
+        bindgen(outDir, "--manifest", manifest.path) shouldBe 0
+
+        // Pretend that the previous run generated the bindings of a namespace removed since.
+        val staleFile = outDir.resolve("jvmMain/kotlin/removed/removed.jvm.kt")
+        staleFile.parentFile.mkdirs()
+        staleFile.writeText("package removed\n")
+        val staleEntry = JsonObject(
+            mapOf(
+                "path" to JsonPrimitive(staleFile.path),
+                "target" to JsonPrimitive("jvm"),
+                "source_set" to JsonPrimitive("jvmMain"),
+            )
+        )
+        manifest.writeText(
+            JsonObject(mapOf("files" to JsonArray(manifestFiles(manifest) + staleEntry))).toString()
+        )
+
+        bindgen(outDir, "--manifest", manifest.path) shouldBe 0
+        staleFile.exists() shouldBe false
+        manifestFiles(manifest).none { it["path"]!!.jsonPrimitive.content == staleFile.path } shouldBe true
+        for (file in manifestFiles(manifest)) {
+            File(file["path"]!!.jsonPrimitive.content).isFile shouldBe true
+        }
+    }
+
+    @Test
+    fun testDepfileListsInputs() {
+        val outDir = createTempDirectory("bindgen-manifest").toFile()
+        val depfile = outDir.resolve("bindings.d")
+        bindgen(outDir, "--depfile", depfile.path) shouldBe 0
+
+        val (targets, dependencies) = depfile.readText().split(": ", limit = 2)
+        targets shouldContain "jvmMain"
+        dependencies shouldContain library
+        dependencies shouldContain config
+        dependencies shouldContain File(packageRoot, "src/bindgen-manifest.udl").path
+    }
+}
@@ -0,0 +1,37 @@
+import org.jetbrains.kotlin.gradle.dsl.JvmTarget
+
+plugins {
+    id("uniffi-tests-from-library")
+    alias(libs.plugins.android.library)
+}
+
+uniffi {
+    generateFromLibrary {
+        generateImmutableRecords = true
+        composeStability = true
+        composeImmutableCollections = true
+    }
+}
+
+kotlin {
+    androidTarget {
+        compilerOptions {
+            jvmTarget = JvmTarget.JVM_17
+        }
+    }
+}
+
+android {
+    namespace = "dev.gobley.uniffi.tests.uniffi.composestability"
+    compileSdk = libs.versions.android.compileSdk.get().toInt()
+
+    defaultConfig {
+        minSdk = 24
+        ndk.abiFilters.add("arm64-v8a")
+    }
+
+    compileOptions {
+        sourceCompatibility = JavaVersion.VERSION_17
+        targetCompatibility = JavaVersion.VERSION_17
+    }
+}
@@ -0,0 +1,79 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(uniffi::Record)]
+pub struct Card {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub scores: HashMap<String, i32>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Layout {
+    Single,
+    Grid { columns: u32, titles: Vec<String> },
+}
+
+#[derive(uniffi::Object)]
+pub struct Session;
+
+#[uniffi::export]
+impl Session {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+// Records holding objects are left unannotated, since the objects can change.
+#[derive(uniffi::Record)]
+pub struct Screen {
+    pub session: Arc<Session>,
+    pub theme: Theme,
+}
+
+#[uniffi::export]
+fn make_card(title: String, tags: Vec<String>) -> Card {
+    let scores = tags
+        .iter()
+        .map(|tag| (tag.clone(), tag.len() as i32))
+        .collect();
+    Card {
+        title,
+        tags,
+        scores,
+    }
+}
+
+#[uniffi::export]
+fn total_score(card: Card) -> i32 {
+    card.scores.values().sum()
+}
+
+#[uniffi::export]
+fn grid_of(titles: Vec<String>) -> Layout {
+    Layout::Grid {
+        columns: titles.len().min(3) as u32,
+        titles,
+    }
+}
+
+#[uniffi::export]
+fn open_screen(theme: Theme) -> Screen {
+    Screen {
+        session: Session::new(),
+        theme,
+    }
+}
+
+uniffi::include_scaffolding!("compose-stability");
@@ -0,0 +1,41 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import compose_stability.*
+import io.kotest.matchers.shouldBe
+import kotlinx.collections.immutable.ImmutableList
+import kotlinx.collections.immutable.ImmutableMap
+import kotlinx.collections.immutable.persistentListOf
+import kotlinx.collections.immutable.persistentMapOf
+import kotlin.test.Test
+
+class ComposeStabilityTest {
+    @Test
+    fun testRecordsUseImmutableCollections() {
+        val card = makeCard("Inbox", listOf("a", "bb"))
+        val tags: ImmutableList<String> = card.tags
+        val scores: ImmutableMap<String, Int> = card.scores
+        tags shouldBe listOf("a", "bb")
+        scores shouldBe mapOf("a" to 1, "bb" to 2)
+
+        totalScore(Card("Drafts", persistentListOf("abc"), persistentMapOf("abc" to 3, "d" to 1))) shouldBe 4
+    }
+
+    @Test
+    fun testEnumFieldsKeepLists() {
+        val layout = gridOf(listOf("a", "b", "c", "d"))
+        layout shouldBe Layout.Grid(3u, listOf("a", "b", "c", "d"))
+        val titles: List<String> = (layout as Layout.Grid).titles
+        titles.size shouldBe 4
+    }
+
+    @Test
+    fun testRecordsWithObjects() {
+        openScreen(Theme.DARK).use { screen ->
+            screen.theme shouldBe Theme.DARK
+        }
+    }
+}
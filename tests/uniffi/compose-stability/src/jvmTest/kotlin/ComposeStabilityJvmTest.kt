@@ -0,0 +1,26 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import compose_stability.*
+import io.kotest.matchers.collections.shouldBeEmpty
+import io.kotest.matchers.shouldBe
+import kotlinx.collections.immutable.ImmutableList
+import kotlinx.collections.immutable.ImmutableMap
+import kotlin.test.Test
+
+class ComposeStabilityJvmTest {
+    @Test
+    fun testRecordsHaveNoSetters() {
+        Card::class.java.methods.filter { it.name.startsWith("set") }.shouldBeEmpty()
+    }
+
+    @Test
+    fun testFieldTypes() {
+        Card::class.java.getMethod("getTags").returnType shouldBe ImmutableList::class.java
+        Card::class.java.getMethod("getScores").returnType shouldBe ImmutableMap::class.java
+        Layout.Grid::class.java.getMethod("getTitles").returnType shouldBe List::class.java
+    }
+}
@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+pub struct Ratio(f64);
+
+uniffi::custom_newtype!(Ratio, f64);
+
+#[derive(uniffi::Record)]
+pub struct Progress {
+    pub done: u32,
+    pub total: u32,
+}
+
+#[uniffi::export]
+fn completion(progress: Progress) -> Ratio {
+    if progress.total == 0 {
+        Ratio(1.0)
+    } else {
+        Ratio(progress.done as f64 / progress.total as f64)
+    }
+}
+
+#[uniffi::export]
+fn scale(ratio: Ratio, value: u32) -> u32 {
+    (ratio.0 * value as f64).round() as u32
+}
+
+uniffi::include_scaffolding!("config-validation");
@@ -0,0 +1,19 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import config_validation.*
+import io.kotest.matchers.shouldBe
+import kotlin.test.Test
+
+class ConfigValidationTest {
+    @Test
+    fun testCustomType() {
+        val ratio: Float = completion(Progress(1u, 4u))
+        ratio shouldBe 0.25f
+        completion(Progress(0u, 0u)) shouldBe 1.0f
+        scale(0.5f, 10u) shouldBe 5u
+    }
+}
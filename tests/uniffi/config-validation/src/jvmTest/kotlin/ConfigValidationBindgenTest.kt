@@ -0,0 +1,117 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.shouldNotBe
+import io.kotest.matchers.string.shouldContain
+import io.kotest.matchers.string.shouldNotContain
+import java.io.File
+import kotlin.io.path.createTempDirectory
+import kotlin.test.Test
+
+class ConfigValidationBindgenTest {
+    private class BindgenResult(val exitCode: Int, val output: String)
+
+    private val ratioConfig = """
+        type_name = "Float"
+        lift = "{}.toFloat()"
+        lower = "{}.toDouble()"
+    """.trimIndent()
+
+    /**
+     * Runs the bindgen with `[bindings.kotlin]` containing [config], or with [config] as the whole
+     * file when [wrap] is `false`.
+     */
+    private fun bindgen(config: String, wrap: Boolean = true): BindgenResult {
+        val workDir = createTempDirectory("config-validation").toFile()
+        val configFile = workDir.resolve("uniffi.toml")
+        configFile.writeText(
+            if (wrap) {
+                """
+                |[bindings.kotlin]
+                |package_name = "config_validation"
+                |$config
+                """.trimMargin()
+            } else {
+                config
+            }
+        )
+        val crateName = System.getProperty("gobley.crateName")
+        val process = ProcessBuilder(
+            System.getProperty("gobley.bindgen"),
+            "--library",
+            "--crate", crateName,
+            "--config", configFile.path,
+            "--crate-configs", "$crateName=${configFile.path}",
+            "--crate-paths", "$crateName=${System.getProperty("gobley.packageRoot")}",
+            "--out-dir", workDir.resolve("out").path,
+            System.getProperty("gobley.library"),
+        ).redirectErrorStream(true).start()
+        val output = process.inputStream.bufferedReader().readText()
+        return BindgenResult(process.waitFor(), output)
+    }
+
+    @Test
+    fun testValidConfig() {
+        val result = bindgen("[bindings.kotlin.custom_types.Ratio]\n$ratioConfig")
+        result.exitCode shouldBe 0
+        result.output shouldNotContain "Warning"
+    }
+
+    @Test
+    fun testMergedConfigKeepsUnknownKeys() {
+        val mergedConfig = File(System.getProperty("gobley.mergedConfig")).readText()
+        mergedConfig shouldContain "into_custom"
+        mergedConfig shouldContain "from_custom"
+        mergedConfig shouldContain "module_name"
+        // The plugin passes the keys it doesn't know to the bindgen, which reports the typos.
+        val result = bindgen(mergedConfig.replace("package_name", "pakage_name"), wrap = false)
+        result.exitCode shouldNotBe 0
+        result.output shouldContain
+            "unknown key `pakage_name` in [bindings.kotlin]; did you mean `package_name`?"
+    }
+
+    @Test
+    fun testUnknownKeys() {
+        val result = bindgen("generate_immutable_record = true")
+        result.exitCode shouldNotBe 0
+        result.output shouldContain
+            "unknown key `generate_immutable_record` in [bindings.kotlin]; did you mean `generate_immutable_records`?"
+
+        bindgen("kotlin_target = [\"jvm\"]").output shouldContain
+            "unknown key `kotlin_target` in [bindings.kotlin]; did you mean `kotlin_targets`?"
+    }
+
+    @Test
+    fun testUnknownCustomTypeKeys() {
+        val result = bindgen("[bindings.kotlin.custom_types.Ratio]\ntype_nam = \"Float\"")
+        result.exitCode shouldNotBe 0
+        result.output shouldContain
+            "unknown key `type_nam` in [bindings.kotlin.custom_types.Ratio]; did you mean `type_name`?"
+    }
+
+    @Test
+    fun testConverterWithoutPlaceholder() {
+        val result = bindgen(
+            "[bindings.kotlin.custom_types.Ratio]\n" + ratioConfig.replace("{}.toFloat()", "toFloat()")
+        )
+        result.exitCode shouldNotBe 0
+        result.output shouldContain "`bindings.kotlin.custom_types.Ratio.lift` must contain `{}`"
+    }
+
+    @Test
+    fun testUnusedCustomTypes() {
+        val misspelled = bindgen("[bindings.kotlin.custom_types.Raito]\n$ratioConfig")
+        misspelled.exitCode shouldBe 0
+        misspelled.output shouldContain
+            "`bindings.kotlin.custom_types.Raito` does not match any custom type in `config_validation`; did you mean `Ratio`?"
+
+        val notCustom = bindgen("[bindings.kotlin.custom_types.Progress]\n$ratioConfig")
+        notCustom.exitCode shouldBe 0
+        notCustom.output shouldContain
+            "`bindings.kotlin.custom_types.Progress` is ignored since `Progress` is not a custom type in `config_validation`"
+    }
+}
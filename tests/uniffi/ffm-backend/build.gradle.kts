@@ -0,0 +1,18 @@
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+uniffi {
+    generateFromLibrary {
+        jvmBackend = "ffm"
+    }
+}
+
+kotlin {
+    // The FFM API is stable since Java 22.
+    jvmToolchain(22)
+}
+
+tasks.withType<Test> {
+    jvmArgs("--enable-native-access=ALL-UNNAMED")
+}
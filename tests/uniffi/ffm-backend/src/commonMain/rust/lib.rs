@@ -0,0 +1,97 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(uniffi::Record)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+    pub label: Option<String>,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Visitor: Send + Sync {
+    fn visit(&self, index: u32, value: String) -> bool;
+}
+
+#[uniffi::export(with_foreign)]
+pub trait Scorer: Send + Sync {
+    fn score(&self, value: String) -> i64;
+}
+
+#[uniffi::export]
+fn scale(rectangle: Rectangle, factor: f64) -> Rectangle {
+    Rectangle {
+        width: rectangle.width * factor,
+        height: rectangle.height * factor,
+        label: rectangle.label,
+    }
+}
+
+#[uniffi::export]
+fn concat_bytes(first: Vec<u8>, second: Vec<u8>) -> Vec<u8> {
+    [first, second].concat()
+}
+
+/// Calls `visitor` for each value until it returns `false`, and returns the number of visited
+/// values.
+#[uniffi::export]
+fn visit_all(values: Vec<String>, visitor: Box<dyn Visitor>) -> u32 {
+    let mut visited = 0;
+    for (index, value) in values.into_iter().enumerate() {
+        visited += 1;
+        if !visitor.visit(index as u32, value) {
+            break;
+        }
+    }
+    visited
+}
+
+#[uniffi::export]
+fn total_score(values: Vec<String>, scorer: Arc<dyn Scorer>) -> i64 {
+    values.into_iter().map(|value| scorer.score(value)).sum()
+}
+
+#[derive(uniffi::Object)]
+pub struct LengthScorer;
+
+#[uniffi::export]
+impl LengthScorer {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+#[uniffi::export]
+impl Scorer for LengthScorer {
+    fn score(&self, value: String) -> i64 {
+        value.len() as i64
+    }
+}
+
+/// Completes after `delay_ms` milliseconds on another thread.
+#[uniffi::export]
+async fn delayed_sum(a: i64, b: i64, delay_ms: u64) -> i64 {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(delay_ms));
+        let _ = sender.send(a + b);
+    });
+    receiver.await.unwrap()
+}
+
+#[uniffi::export]
+async fn delayed_rectangle(width: f64, height: f64) -> Rectangle {
+    Rectangle {
+        width,
+        height,
+        label: Some(format!("{width}x{height}")),
+    }
+}
+
+uniffi::include_scaffolding!("ffm-backend");
@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import ffm_backend.*
+import io.kotest.matchers.shouldBe
+import kotlinx.coroutines.async
+import kotlinx.coroutines.awaitAll
+import kotlinx.coroutines.test.runTest
+import kotlin.test.Test
+
+class FfmBackendTest {
+    @Test
+    fun testStructsAndBuffers() {
+        scale(Rectangle(2.0, 3.0, "small"), 2.0) shouldBe Rectangle(4.0, 6.0, "small")
+        scale(Rectangle(1.0, 1.0, null), 0.5) shouldBe Rectangle(0.5, 0.5, null)
+        concatBytes(byteArrayOf(1, 2), byteArrayOf(3)).toList() shouldBe listOf<Byte>(1, 2, 3)
+    }
+
+    @Test
+    fun testCallbackInterfaces() {
+        val visited = mutableListOf<String>()
+        val count = visitAll(listOf("a", "b", "stop", "c"), object : Visitor {
+            override fun visit(index: UInt, value: String): Boolean {
+                visited += "$index:$value"
+                return value != "stop"
+            }
+        })
+        count shouldBe 3U
+        visited shouldBe listOf("0:a", "1:b", "2:stop")
+    }
+
+    @Test
+    fun testForeignTraits() {
+        val kotlinScorer = object : Scorer {
+            override fun score(value: String): Long = if (value == "kotlin") 100L else 1L
+        }
+        totalScore(listOf("kotlin", "rust"), kotlinScorer) shouldBe 101L
+        LengthScorer().use { rustScorer ->
+            totalScore(listOf("kotlin", "rust"), rustScorer) shouldBe 10L
+        }
+    }
+
+    @Test
+    fun testAsyncFunctions() = runTest {
+        delayedSum(1L, 2L, 10UL) shouldBe 3L
+        delayedRectangle(2.0, 3.0) shouldBe Rectangle(2.0, 3.0, "2x3")
+        val sums = (1L..10L).map { value ->
+            async { delayedSum(value, value, 5UL) }
+        }.awaitAll()
+        sums shouldBe (1L..10L).map { it * 2 }
+    }
+}
@@ -0,0 +1,68 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Enum)]
+pub enum Priority {
+    Low,
+    High,
+}
+
+#[derive(uniffi::Record)]
+pub struct SessionConfig {
+    pub name: String,
+    pub priority: Priority,
+}
+
+#[derive(uniffi::Record)]
+pub struct Diagnostics {
+    pub queries: u32,
+}
+
+#[derive(uniffi::Object)]
+pub struct Session {
+    config: SessionConfig,
+    queries: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Session {
+    fn query(&self, input: String) -> String {
+        *self.queries.lock().unwrap() += 1;
+        match self.config.priority {
+            Priority::Low => format!("{}: {input}", self.config.name),
+            Priority::High => format!("{}: {}", self.config.name, input.to_uppercase()),
+        }
+    }
+
+    fn debug_dump(&self) -> String {
+        format!(
+            "{} ({} queries)",
+            self.config.name,
+            self.queries.lock().unwrap()
+        )
+    }
+
+    fn debug_reset(&self) {
+        *self.queries.lock().unwrap() = 0;
+    }
+}
+
+#[uniffi::export]
+fn open_session(config: SessionConfig) -> Arc<Session> {
+    Arc::new(Session {
+        config,
+        queries: Mutex::new(0),
+    })
+}
+
+#[uniffi::export]
+fn diagnostics(session: Arc<Session>) -> Diagnostics {
+    Diagnostics {
+        queries: *session.queries.lock().unwrap(),
+    }
+}
+
+uniffi::include_scaffolding!("include-exclude");
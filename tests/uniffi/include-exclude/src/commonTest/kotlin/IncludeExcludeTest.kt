@@ -0,0 +1,22 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import include_exclude.*
+import io.kotest.matchers.shouldBe
+import kotlin.test.Test
+
+class IncludeExcludeTest {
+    @Test
+    fun testIncludedItems() {
+        openSession(SessionConfig("low", Priority.LOW)).use { session ->
+            session.query("hello") shouldBe "low: hello"
+        }
+        // Priority isn't included but is generated since SessionConfig uses it.
+        openSession(SessionConfig("high", Priority.HIGH)).use { session ->
+            session.query("hello") shouldBe "high: HELLO"
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import include_exclude.*
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.collections.shouldContain
+import io.kotest.matchers.collections.shouldNotContain
+import kotlin.test.Test
+
+class IncludeExcludeReflectionTest {
+    @Test
+    fun testTypesNotIncludedAreDropped() {
+        shouldThrow<ClassNotFoundException> {
+            Class.forName("include_exclude.Diagnostics")
+        }
+    }
+
+    @Test
+    fun testFunctionsNotIncludedAreDropped() {
+        val functions = Class.forName("include_exclude.Include_exclude_jvmKt").methods.map { it.name }
+        functions shouldContain "openSession"
+        functions shouldNotContain "diagnostics"
+    }
+
+    @Test
+    fun testExcludedMethodsAreDropped() {
+        val methods = Session::class.java.methods.map { it.name }
+        methods shouldContain "query"
+        methods shouldNotContain "debugDump"
+        methods shouldNotContain "debugReset"
+    }
+}
@@ -0,0 +1,90 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[derive(uniffi::Record)]
+pub struct Settings {
+    pub name: String,
+    #[uniffi(default = 30000)]
+    pub timeout_ms: u64,
+    #[uniffi(default = 3)]
+    pub retries: u32,
+    #[uniffi(default = false)]
+    pub verbose: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct Page {
+    pub index: u32,
+    #[uniffi(default = 20)]
+    pub size: u32,
+}
+
+#[derive(uniffi::Record)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum FetchError {
+    #[error("user {id} not found")]
+    NotFound { id: String },
+}
+
+#[derive(uniffi::Object)]
+pub struct Client {
+    settings: Settings,
+}
+
+#[uniffi::export]
+impl Client {
+    #[uniffi::constructor]
+    fn new(settings: Settings) -> Arc<Self> {
+        Arc::new(Self { settings })
+    }
+
+    #[uniffi::constructor]
+    fn with_name(name: String) -> Arc<Self> {
+        Self::new(Settings {
+            name,
+            timeout_ms: 30000,
+            retries: 3,
+            verbose: false,
+        })
+    }
+
+    fn describe(&self) -> String {
+        let Settings {
+            name,
+            timeout_ms,
+            retries,
+            verbose,
+        } = &self.settings;
+        format!("{name} (timeout: {timeout_ms}ms, retries: {retries}, verbose: {verbose})")
+    }
+
+    async fn fetch_user(&self, id: String) -> Result<User, FetchError> {
+        match id.as_str() {
+            "1" => Ok(User {
+                id,
+                name: format!("Alice from {}", self.settings.name),
+            }),
+            _ => Err(FetchError::NotFound { id }),
+        }
+    }
+}
+
+#[uniffi::export(default(greeting = "Hello"))]
+fn greet(name: String, greeting: String) -> String {
+    format!("{greeting}, {name}!")
+}
+
+#[uniffi::export]
+async fn ping() -> String {
+    "pong".to_owned()
+}
+
+uniffi::include_scaffolding!("java-interop");
@@ -0,0 +1,33 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import java_interop.*
+import kotlinx.coroutines.test.runTest
+import kotlin.test.Test
+
+class JavaInteropTest {
+    @Test
+    fun testDefaults() {
+        greet("Kotlin") shouldBe "Hello, Kotlin!"
+        greet("Kotlin", "Hi") shouldBe "Hi, Kotlin!"
+        Client(Settings("client", retries = 5u)).use { client ->
+            client.describe() shouldBe "client (timeout: 30000ms, retries: 5, verbose: false)"
+        }
+    }
+
+    @Test
+    fun testSuspendFunctions() = runTest {
+        ping() shouldBe "pong"
+        Client.withName("server").use { client ->
+            client.fetchUser("1") shouldBe User("1", "Alice from server")
+            shouldThrow<FetchException.NotFound> {
+                client.fetchUser("2")
+            }
+        }
+    }
+}
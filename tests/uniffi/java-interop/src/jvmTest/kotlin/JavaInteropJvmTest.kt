@@ -0,0 +1,51 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.types.shouldBeInstanceOf
+import java_interop.*
+import java.lang.reflect.Modifier
+import java.util.concurrent.ExecutionException
+import kotlin.test.Test
+
+class JavaInteropJvmTest {
+    @Test
+    fun testFutures() {
+        pingFuture().get() shouldBe "pong"
+        Client.withName("server").use { client ->
+            client.fetchUserFuture("1").get() shouldBe User("1", "Alice from server")
+            val exception = shouldThrow<ExecutionException> {
+                client.fetchUserFuture("2").get()
+            }
+            exception.cause.shouldBeInstanceOf<FetchException.NotFound>()
+        }
+    }
+
+    @Test
+    fun testJvmStaticConstructors() {
+        val withName = Client::class.java.getMethod("withName", String::class.java)
+        Modifier.isStatic(withName.modifiers) shouldBe true
+    }
+
+    @Test
+    fun testJvmOverloads() {
+        val greetOverloads = Class.forName("java_interop.Java_interop_jvmKt").methods
+            .filter { it.name == "greet" }
+            .map { it.parameterCount }
+        greetOverloads.sorted() shouldBe listOf(1, 2)
+        // Java can construct records passing only the fields without default values.
+        Settings::class.java.getConstructor(String::class.java).newInstance("client") shouldBe Settings("client")
+    }
+
+    @Test
+    fun testRecordBuilders() {
+        SettingsBuilder("client").retries(5u).verbose(true).build() shouldBe
+            Settings("client", retries = 5u, verbose = true)
+        SettingsBuilder("client").build() shouldBe Settings("client")
+        PageBuilder(2u).size(50u).build() shouldBe Page(2u, size = 50u)
+    }
+}
@@ -0,0 +1,106 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record)]
+pub struct Account {
+    pub id: u32,
+    pub owner: String,
+    pub balance: i64,
+    pub note: Option<String>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Transaction {
+    Deposit { amount: i64 },
+    Withdrawal { amount: i64 },
+    Close,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum BankError {
+    #[error("account {id} does not exist")]
+    UnknownAccount { id: u32 },
+    #[error("balance {balance} is lower than {amount}")]
+    InsufficientFunds { balance: i64, amount: i64 },
+}
+
+#[uniffi::export]
+fn echo_bytes(bytes: Vec<u8>) -> Vec<u8> {
+    bytes
+}
+
+#[uniffi::export]
+fn sum_all(values: Vec<i32>) -> i64 {
+    values.into_iter().map(i64::from).sum()
+}
+
+#[uniffi::export]
+fn flags() -> HashMap<String, bool> {
+    HashMap::from([("jni".to_owned(), true), ("jna".to_owned(), false)])
+}
+
+#[derive(uniffi::Object)]
+pub struct Bank {
+    accounts: Mutex<Vec<Account>>,
+}
+
+#[uniffi::export]
+impl Bank {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            accounts: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn open(&self, owner: String, note: Option<String>) -> u32 {
+        let mut accounts = self.accounts.lock().unwrap();
+        let id = accounts.len() as u32 + 1;
+        accounts.push(Account {
+            id,
+            owner,
+            balance: 0,
+            note,
+        });
+        id
+    }
+
+    fn apply(&self, id: u32, transaction: Transaction) -> Result<Account, BankError> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let index = accounts
+            .iter()
+            .position(|account| account.id == id)
+            .ok_or(BankError::UnknownAccount { id })?;
+        let account = &mut accounts[index];
+        match transaction {
+            Transaction::Deposit { amount } => account.balance += amount,
+            Transaction::Withdrawal { amount } if amount > account.balance => {
+                return Err(BankError::InsufficientFunds {
+                    balance: account.balance,
+                    amount,
+                });
+            }
+            Transaction::Withdrawal { amount } => account.balance -= amount,
+            Transaction::Close => {
+                let account = accounts.remove(index);
+                return Ok(account);
+            }
+        }
+        Ok(Account {
+            id: account.id,
+            owner: account.owner.clone(),
+            balance: account.balance,
+            note: account.note.clone(),
+        })
+    }
+
+    fn count(&self) -> u32 {
+        self.accounts.lock().unwrap().len() as u32
+    }
+}
+
+uniffi::include_scaffolding!("jni-backend");
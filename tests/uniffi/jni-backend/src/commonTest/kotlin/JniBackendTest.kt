@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import jni_backend.*
+import kotlin.test.Test
+
+class JniBackendTest {
+    @Test
+    fun testBuiltinTypes() {
+        echoBytes(byteArrayOf(0, 1, -1)).toList() shouldBe listOf<Byte>(0, 1, -1)
+        echoBytes(ByteArray(1 shl 16) { it.toByte() }).size shouldBe (1 shl 16)
+        sumAll(listOf(Int.MAX_VALUE, Int.MAX_VALUE, 2)) shouldBe 4294967296L
+        flags() shouldBe mapOf("jni" to true, "jna" to false)
+    }
+
+    @Test
+    fun testObjects() {
+        Bank().use { bank ->
+            val alice = bank.open("Alice", null)
+            val bob = bank.open("Bob", "savings")
+            bank.count() shouldBe 2U
+
+            bank.apply(alice, Transaction.Deposit(100L)) shouldBe Account(alice, "Alice", 100L, null)
+            bank.apply(alice, Transaction.Withdrawal(30L)).balance shouldBe 70L
+            bank.apply(bob, Transaction.Close) shouldBe Account(bob, "Bob", 0L, "savings")
+            bank.count() shouldBe 1U
+        }
+    }
+
+    @Test
+    fun testErrors() {
+        Bank().use { bank ->
+            val id = bank.open("Carol", null)
+            val insufficientFunds = shouldThrow<BankException.InsufficientFunds> {
+                bank.apply(id, Transaction.Withdrawal(1L))
+            }
+            insufficientFunds.balance shouldBe 0L
+            insufficientFunds.amount shouldBe 1L
+
+            shouldThrow<BankException.UnknownAccount> {
+                bank.apply(id + 1U, Transaction.Close)
+            }.id shouldBe id + 1U
+        }
+    }
+}
@@ -0,0 +1,9 @@
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+kotlin {
+    js {
+        nodejs()
+    }
+}
@@ -0,0 +1,99 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum DivisionError {
+    #[error("cannot divide {dividend} by zero")]
+    DivisionByZero { dividend: i64 },
+}
+
+#[uniffi::export]
+fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+#[uniffi::export]
+fn add_i64(a: i64, b: i64) -> i64 {
+    a.wrapping_add(b)
+}
+
+#[uniffi::export]
+fn add_u64(a: u64, b: u64) -> u64 {
+    a.wrapping_add(b)
+}
+
+#[uniffi::export]
+fn reverse_bytes(bytes: Vec<u8>) -> Vec<u8> {
+    bytes.into_iter().rev().collect()
+}
+
+#[uniffi::export]
+fn translate(point: Point, dx: i64, dy: i64) -> Point {
+    Point {
+        x: point.x + dx,
+        y: point.y + dy,
+    }
+}
+
+#[uniffi::export]
+fn area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Rectangle { width, height } => width * height,
+    }
+}
+
+#[uniffi::export]
+fn first_word(text: String) -> Option<String> {
+    text.split_whitespace().next().map(str::to_owned)
+}
+
+#[uniffi::export]
+fn divide(dividend: i64, divisor: i64) -> Result<i64, DivisionError> {
+    if divisor == 0 {
+        return Err(DivisionError::DivisionByZero { dividend });
+    }
+    Ok(dividend / divisor)
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: Mutex<i64>,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    fn new(initial: i64) -> Arc<Self> {
+        Arc::new(Self {
+            value: Mutex::new(initial),
+        })
+    }
+
+    fn increment(&self) -> i64 {
+        let mut value = self.value.lock().unwrap();
+        *value += 1;
+        *value
+    }
+
+    fn value(&self) -> i64 {
+        *self.value.lock().unwrap()
+    }
+}
+
+uniffi::include_scaffolding!("js-target");
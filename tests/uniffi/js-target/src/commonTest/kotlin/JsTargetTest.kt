@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.doubles.plusOrMinus
+import io.kotest.matchers.shouldBe
+import js_target.*
+import kotlin.math.PI
+import kotlin.test.Test
+
+class JsTargetTest {
+    @Test
+    fun testStrings() {
+        greet("Kotlin") shouldBe "Hello, Kotlin!"
+        greet("🦀") shouldBe "Hello, 🦀!"
+        firstWord("  hello world") shouldBe "hello"
+        firstWord("   ") shouldBe null
+    }
+
+    @Test
+    fun testIntegers() {
+        addI64(1L, 2L) shouldBe 3L
+        addI64(Long.MAX_VALUE, 1L) shouldBe Long.MIN_VALUE
+        addU64(ULong.MAX_VALUE - 1UL, 1UL) shouldBe ULong.MAX_VALUE
+    }
+
+    @Test
+    fun testByteArrays() {
+        reverseBytes(byteArrayOf(1, 2, 3)).toList() shouldBe listOf<Byte>(3, 2, 1)
+        reverseBytes(byteArrayOf()).size shouldBe 0
+    }
+
+    @Test
+    fun testRecordsAndEnums() {
+        translate(Point(1L, 2L), 10L, 20L) shouldBe Point(11L, 22L)
+        area(Shape.Rectangle(2.0, 3.0)) shouldBe 6.0
+        area(Shape.Circle(1.0)) shouldBe (PI plusOrMinus 1e-9)
+    }
+
+    @Test
+    fun testErrors() {
+        divide(7L, 2L) shouldBe 3L
+        val exception = shouldThrow<DivisionException.DivisionByZero> {
+            divide(7L, 0L)
+        }
+        exception.dividend shouldBe 7L
+    }
+
+    @Test
+    fun testObjects() {
+        Counter(41L).use { counter ->
+            counter.increment() shouldBe 42L
+            counter.value() shouldBe 42L
+        }
+    }
+}
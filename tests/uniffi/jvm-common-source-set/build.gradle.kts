@@ -0,0 +1,43 @@
+import org.jetbrains.kotlin.gradle.dsl.JvmTarget
+
+plugins {
+    id("uniffi-tests-from-library")
+    alias(libs.plugins.android.library)
+}
+
+uniffi {
+    generateFromLibrary {
+        jvmCommonSourceSet = "jvmCommon"
+    }
+}
+
+kotlin {
+    androidTarget {
+        compilerOptions {
+            jvmTarget = JvmTarget.JVM_17
+        }
+    }
+    applyDefaultHierarchyTemplate {
+        common {
+            group("jvmCommon") {
+                withJvm()
+                withAndroidTarget()
+            }
+        }
+    }
+}
+
+android {
+    namespace = "dev.gobley.uniffi.tests.uniffi.jvmcommonsourceset"
+    compileSdk = libs.versions.android.compileSdk.get().toInt()
+
+    defaultConfig {
+        minSdk = 24
+        ndk.abiFilters.add("arm64-v8a")
+    }
+
+    compileOptions {
+        sourceCompatibility = JavaVersion.VERSION_17
+        targetCompatibility = JavaVersion.VERSION_17
+    }
+}
@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record)]
+pub struct Sample {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Statistic {
+    Mean,
+    Max,
+    Count,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Progress: Send + Sync {
+    fn report(&self, done: u32, total: u32);
+}
+
+#[derive(uniffi::Object)]
+pub struct Collector {
+    samples: Mutex<Vec<Sample>>,
+}
+
+#[uniffi::export]
+impl Collector {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            samples: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn add(&self, sample: Sample) {
+        self.samples.lock().unwrap().push(sample);
+    }
+
+    fn compute(&self, statistic: Statistic, progress: Box<dyn Progress>) -> Vec<f64> {
+        let samples = self.samples.lock().unwrap();
+        let total = samples.len() as u32;
+        let mut results = Vec::new();
+        for (index, sample) in samples.iter().enumerate() {
+            results.push(match statistic {
+                Statistic::Mean => sample.values.iter().sum::<f64>() / sample.values.len() as f64,
+                Statistic::Max => sample.values.iter().copied().fold(f64::MIN, f64::max),
+                Statistic::Count => sample.values.len() as f64,
+            });
+            progress.report(index as u32 + 1, total);
+        }
+        results
+    }
+}
+
+#[uniffi::export]
+fn sample_names(samples: Vec<Sample>) -> Vec<String> {
+    samples.into_iter().map(|sample| sample.name).collect()
+}
+
+uniffi::include_scaffolding!("jvm-common-source-set");
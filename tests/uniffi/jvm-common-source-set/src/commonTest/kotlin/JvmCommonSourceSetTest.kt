@@ -0,0 +1,38 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import jvm_common_source_set.*
+import kotlin.test.Test
+
+class JvmCommonSourceSetTest {
+    @Test
+    fun testRecords() {
+        val samples = listOf(
+            Sample("first", listOf(1.0, 2.0)),
+            Sample("second", listOf()),
+        )
+        sampleNames(samples) shouldBe listOf("first", "second")
+    }
+
+    @Test
+    fun testObjectsAndCallbacks() {
+        val reports = mutableListOf<Pair<UInt, UInt>>()
+        val progress = object : Progress {
+            override fun report(done: UInt, total: UInt) {
+                reports += done to total
+            }
+        }
+        Collector().use { collector ->
+            collector.add(Sample("a", listOf(1.0, 2.0, 3.0)))
+            collector.add(Sample("b", listOf(4.0, 8.0)))
+            collector.compute(Statistic.MEAN, progress) shouldBe listOf(2.0, 6.0)
+            collector.compute(Statistic.MAX, progress) shouldBe listOf(3.0, 8.0)
+            collector.compute(Statistic.COUNT, progress) shouldBe listOf(3.0, 2.0)
+        }
+        reports shouldBe List(3) { listOf(1u to 2u, 2u to 2u) }.flatten()
+    }
+}
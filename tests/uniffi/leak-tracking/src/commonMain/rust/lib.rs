@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[derive(uniffi::Object)]
+pub struct Resource {
+    name: String,
+}
+
+#[uniffi::export]
+impl Resource {
+    #[uniffi::constructor]
+    fn new(name: String) -> Arc<Self> {
+        Arc::new(Self { name })
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn child(&self, name: String) -> Arc<Resource> {
+        Resource::new(format!("{}/{name}", self.name))
+    }
+}
+
+uniffi::include_scaffolding!("leak-tracking");
@@ -0,0 +1,47 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldContain
+import leak_tracking.*
+import kotlin.test.Test
+
+fun leakReportCount(label: String): Int {
+    val line = UniffiLeakReport.dump().lines().first { it.startsWith("$label: ") }
+    return line.substringAfter(": ").toInt()
+}
+
+class LeakTrackingTest {
+    @Test
+    fun testTrackedUntilClosed() {
+        val alive = leakReportCount("Still alive")
+        val resource = Resource("file")
+        leakReportCount("Still alive") shouldBe alive + 1
+        UniffiLeakReport.dump() shouldContain "Resource created at:"
+        resource.close()
+        leakReportCount("Still alive") shouldBe alive
+    }
+
+    @Test
+    fun testReturnedObjectsAreTracked() {
+        val alive = leakReportCount("Still alive")
+        Resource("dir").use { parent ->
+            parent.child("file").use { child ->
+                child.name() shouldBe "dir/file"
+                leakReportCount("Still alive") shouldBe alive + 2
+            }
+            leakReportCount("Still alive") shouldBe alive + 1
+        }
+        leakReportCount("Still alive") shouldBe alive
+    }
+
+    @Test
+    fun testClosedObjectsAreNotReportedAsCollected() {
+        val collected = leakReportCount("Garbage collected without being closed")
+        Resource("closed").use { it.name() shouldBe "closed" }
+        leakReportCount("Garbage collected without being closed") shouldBe collected
+    }
+}
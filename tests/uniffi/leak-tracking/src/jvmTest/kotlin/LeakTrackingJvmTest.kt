@@ -0,0 +1,39 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import leak_tracking.*
+import kotlin.test.Test
+
+class LeakTrackingJvmTest {
+    // Kept in a separate function so that no reference to the objects outlives the call.
+    private fun createUnclosedResources(count: Int) {
+        repeat(count) { Resource("leaked $it").name() }
+    }
+
+    @Test
+    fun testCollectedWithoutClose() {
+        UniffiLeakReport.clear()
+        val alive = leakReportCount("Still alive")
+        createUnclosedResources(10)
+        leakReportCount("Still alive") shouldBe alive + 10
+
+        // The cleaner frees the Rust objects on its own thread once they are collected.
+        val deadline = System.currentTimeMillis() + 10_000
+        while (leakReportCount("Garbage collected without being closed") < 10 &&
+            System.currentTimeMillis() < deadline
+        ) {
+            System.gc()
+            Thread.sleep(50)
+        }
+        leakReportCount("Garbage collected without being closed") shouldBe 10
+        leakReportCount("Still alive") shouldBe alive
+
+        UniffiLeakReport.clear()
+        leakReportCount("Garbage collected without being closed") shouldBe 0
+        leakReportCount("Still alive") shouldBe alive
+    }
+}
@@ -0,0 +1,29 @@
+import gobley.gradle.uniffi.tasks.BuildUniffiBindingsTask
+
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+val buildUniffiBindings = tasks.named<BuildUniffiBindingsTask>("buildUniffiBindings")
+val bindgen = buildUniffiBindings.flatMap { it.bindgen }
+val libraryFile = buildUniffiBindings.flatMap { it.source }
+val mergedConfig = buildUniffiBindings.flatMap { it.config }
+val libraryCrateName = buildUniffiBindings.flatMap { it.libraryCrateName }
+val packageRoot = layout.projectDirectory.asFile.path
+val gobleyLogRoot = rootProject.layout.projectDirectory.dir("crates/gobley-log").asFile.path
+
+// The tests run the bindgen the plugin installed against the library it built, to check the
+// bindings of gobley-log generated in library mode.
+tasks.named<Test>("jvmTest") {
+    dependsOn(buildUniffiBindings)
+    jvmArgumentProviders.add(CommandLineArgumentProvider {
+        listOf(
+            "-Dgobley.bindgen=${bindgen.get().asFile.path}",
+            "-Dgobley.library=${libraryFile.get().asFile.path}",
+            "-Dgobley.config=${mergedConfig.get().asFile.path}",
+            "-Dgobley.crateName=${libraryCrateName.get()}",
+            "-Dgobley.packageRoot=$packageRoot",
+            "-Dgobley.gobleyLogRoot=$gobleyLogRoot",
+        )
+    })
+}
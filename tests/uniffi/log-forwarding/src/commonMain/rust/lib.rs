@@ -0,0 +1,81 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use gobley_log::{LogLevel, LogRecord, LogSink};
+
+#[derive(uniffi::Enum)]
+pub enum Severity {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for Severity {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Severity::Error,
+            LogLevel::Warn => Severity::Warn,
+            LogLevel::Info => Severity::Info,
+            LogLevel::Debug => Severity::Debug,
+            LogLevel::Trace => Severity::Trace,
+        }
+    }
+}
+
+impl From<Severity> for LogLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Error => LogLevel::Error,
+            Severity::Warn => LogLevel::Warn,
+            Severity::Info => LogLevel::Info,
+            Severity::Debug => LogLevel::Debug,
+            Severity::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+/// Receives the records forwarded by `gobley-log`.
+#[uniffi::export(callback_interface)]
+pub trait RecordListener: Send + Sync {
+    fn on_record(&self, severity: Severity, target: String, message: String);
+}
+
+struct ListenerSink(Box<dyn RecordListener>);
+
+impl LogSink for ListenerSink {
+    fn log(&self, record: LogRecord) {
+        self.0
+            .on_record(record.level.into(), record.target, record.message);
+    }
+}
+
+#[uniffi::export]
+fn listen(listener: Box<dyn RecordListener>) {
+    gobley_log::set_log_sink(Box::new(ListenerSink(listener)));
+}
+
+#[uniffi::export]
+fn set_severity(severity: Option<Severity>) {
+    gobley_log::set_log_level(severity.map(Into::into));
+}
+
+#[uniffi::export]
+fn process(items: Vec<String>) -> u32 {
+    log::info!(target: "gobley_log_fixture", "processing {} items", items.len());
+    let mut processed = 0;
+    for item in &items {
+        if item.is_empty() {
+            tracing::warn!(target: "gobley_log_fixture", "skipping an empty item");
+            continue;
+        }
+        log::debug!(target: "gobley_log_fixture", "processed {item}");
+        processed += 1;
+    }
+    log::error!(target: "gobley_log_fixture", "done");
+    processed
+}
+
+uniffi::include_scaffolding!("log-forwarding");
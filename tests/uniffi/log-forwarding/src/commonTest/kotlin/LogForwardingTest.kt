@@ -0,0 +1,77 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import log_forwarding.*
+import kotlin.test.AfterTest
+import kotlin.test.Test
+
+class LogForwardingTest {
+    private class CollectingListener : RecordListener {
+        val records = mutableListOf<Pair<Severity, String>>()
+
+        override fun onRecord(severity: Severity, target: String, message: String) {
+            target shouldBe "gobley_log_fixture"
+            records += severity to message
+        }
+    }
+
+    @AfterTest
+    fun resetSeverity() {
+        setSeverity(Severity.INFO)
+    }
+
+    @Test
+    fun testLogAndTracingRecords() {
+        val listener = CollectingListener()
+        listen(listener)
+        setSeverity(Severity.INFO)
+        process(listOf("a", "", "b")) shouldBe 2u
+        listener.records shouldBe listOf(
+            Severity.INFO to "processing 3 items",
+            Severity.WARN to "skipping an empty item",
+            Severity.ERROR to "done",
+        )
+    }
+
+    @Test
+    fun testLevelFiltering() {
+        val listener = CollectingListener()
+        listen(listener)
+        setSeverity(Severity.DEBUG)
+        process(listOf("a")) shouldBe 1u
+        listener.records shouldBe listOf(
+            Severity.INFO to "processing 1 items",
+            Severity.DEBUG to "processed a",
+            Severity.ERROR to "done",
+        )
+
+        listener.records.clear()
+        setSeverity(Severity.ERROR)
+        process(listOf("a", "")) shouldBe 1u
+        listener.records shouldBe listOf(Severity.ERROR to "done")
+
+        listener.records.clear()
+        setSeverity(null)
+        process(listOf("a")) shouldBe 1u
+        listener.records shouldBe listOf()
+    }
+
+    @Test
+    fun testRecordsLoggedBySinkAreDropped() {
+        val messages = mutableListOf<String>()
+        listen(object : RecordListener {
+            override fun onRecord(severity: Severity, target: String, message: String) {
+                messages += message
+                // Logs "processing 1 items" and "done" again, which must not recurse.
+                process(listOf("nested"))
+            }
+        })
+        setSeverity(Severity.INFO)
+        process(listOf()) shouldBe 0u
+        messages shouldBe listOf("processing 0 items", "done")
+    }
+}
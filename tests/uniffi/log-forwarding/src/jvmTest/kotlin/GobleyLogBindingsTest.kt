@@ -0,0 +1,62 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldContain
+import io.kotest.matchers.string.shouldNotContain
+import java.io.File
+import kotlin.io.path.createTempDirectory
+import kotlin.test.Test
+
+class GobleyLogBindingsTest {
+    /** Generates the bindings of every crate in the library, including gobley-log. */
+    private fun generateAll(): File {
+        val outDir = createTempDirectory("log-forwarding").toFile()
+        val crateName = System.getProperty("gobley.crateName")
+        val exitCode = ProcessBuilder(
+            System.getProperty("gobley.bindgen"),
+            "--library",
+            "--crate-configs", "$crateName=${System.getProperty("gobley.config")}",
+            "--crate-paths", "$crateName=${System.getProperty("gobley.packageRoot")}",
+            "--crate-paths", "gobley_log=${System.getProperty("gobley.gobleyLogRoot")}",
+            "--out-dir", outDir.path,
+            System.getProperty("gobley.library"),
+        ).inheritIO().start().waitFor()
+        exitCode shouldBe 0
+        return outDir
+    }
+
+    @Test
+    fun testGobleyLogBindings() {
+        val outDir = generateAll()
+        val common = outDir.resolve("commonMain/kotlin/uniffi/gobley_log/gobley_log.common.kt")
+        common.readText() shouldContain "public interface LogSink"
+        common.readText() shouldContain "public enum class LogLevel"
+    }
+
+    @Test
+    fun testGobleyLogAdapters() {
+        val outDir = generateAll()
+        val common = outDir.resolve("commonMain/kotlin/uniffi/gobley_log/gobley_log.common.kt").readText()
+        common shouldContain "public object GobleyLog"
+        common shouldContain "public object PrintlnLogSink : LogSink"
+        common shouldNotContain "LogcatLogSink"
+        val jvm = outDir.resolve("jvmMain/kotlin/uniffi/gobley_log/gobley_log.jvm.kt").readText()
+        jvm shouldContain "public object Slf4jLogSink : LogSink"
+        jvm shouldContain "org.slf4j.LoggerFactory.getLogger(record.target)"
+        jvm shouldNotContain "LogcatLogSink"
+        val android = outDir.resolve("androidMain/kotlin/uniffi/gobley_log/gobley_log.android.kt").readText()
+        android shouldContain "public object LogcatLogSink : LogSink"
+        android shouldNotContain "Slf4jLogSink"
+    }
+
+    @Test
+    fun testOtherBindingsHaveNoAdapters() {
+        val outDir = generateAll()
+        val common = outDir.resolve("commonMain/kotlin/log_forwarding/log_forwarding.common.kt").readText()
+        common shouldNotContain "object GobleyLog"
+    }
+}
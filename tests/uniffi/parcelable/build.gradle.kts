@@ -0,0 +1,44 @@
+import org.jetbrains.kotlin.gradle.dsl.JvmTarget
+
+plugins {
+    id("uniffi-tests-from-library")
+    alias(libs.plugins.android.library)
+    id(libs.plugins.kotlin.parcelize.get().pluginId)
+}
+
+kotlin {
+    androidTarget {
+        compilerOptions {
+            jvmTarget = JvmTarget.JVM_17
+            freeCompilerArgs.addAll(
+                "-P",
+                "plugin:org.jetbrains.kotlin.parcelize:additionalAnnotation=parcelable.UniffiParcelize",
+            )
+        }
+    }
+    sourceSets {
+        androidInstrumentedTest {
+            dependencies {
+                implementation(libs.junit)
+                implementation(libs.androidx.test.runner)
+                implementation(libs.kotest.assertions.core)
+            }
+        }
+    }
+}
+
+android {
+    namespace = "dev.gobley.uniffi.tests.uniffi.parcelable"
+    compileSdk = libs.versions.android.compileSdk.get().toInt()
+
+    defaultConfig {
+        minSdk = 24
+        testInstrumentationRunner = "androidx.test.runner.AndroidJUnitRunner"
+        ndk.abiFilters.add("arm64-v8a")
+    }
+
+    compileOptions {
+        sourceCompatibility = JavaVersion.VERSION_17
+        targetCompatibility = JavaVersion.VERSION_17
+    }
+}
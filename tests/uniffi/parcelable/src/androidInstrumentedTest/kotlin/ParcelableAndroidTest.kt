@@ -0,0 +1,46 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import android.os.Bundle
+import android.os.Parcel
+import android.os.Parcelable
+import io.kotest.matchers.shouldBe
+import org.junit.Test
+import parcelable.*
+
+class ParcelableAndroidTest {
+    @Suppress("DEPRECATION")
+    private inline fun <reified T : Parcelable> roundTrip(value: T): T? {
+        val parcel = Parcel.obtain()
+        try {
+            val bundle = Bundle().apply { putParcelable("value", value) }
+            bundle.writeToParcel(parcel, 0)
+            parcel.setDataPosition(0)
+            val restored = parcel.readBundle(T::class.java.classLoader)!!
+            return restored.getParcelable("value")
+        } finally {
+            parcel.recycle()
+        }
+    }
+
+    @Test
+    fun testRecordRoundTrip() {
+        val contact = makeContact("Alice", Email("alice@example.com"), Coordinates(37.5, 127.0))
+        roundTrip(contact) shouldBe contact
+    }
+
+    @Test
+    fun testEnumRoundTrip() {
+        roundTrip(Priority.LOW) shouldBe Priority.LOW
+        roundTrip<Shape>(Shape.Point) shouldBe Shape.Point
+        roundTrip<Shape>(Shape.Rectangle(2.0, 3.0)) shouldBe Shape.Rectangle(2.0, 3.0)
+    }
+
+    @Test
+    fun testCustomTypeRoundTrip() {
+        roundTrip(Coordinates(1.5, -2.5)) shouldBe Coordinates(1.5, -2.5)
+    }
+}
@@ -0,0 +1,19 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+package parcelable
+
+@UniffiParcelize
+public data class Coordinates(public val latitude: Double, public val longitude: Double) : UniffiParcelable {
+    public fun format(): String = "$latitude,$longitude"
+
+    public companion object {
+        public fun parse(value: String): Coordinates {
+            val (latitude, longitude) = value.split(',').map { it.toDouble() }
+            return Coordinates(latitude, longitude)
+        }
+    }
+}
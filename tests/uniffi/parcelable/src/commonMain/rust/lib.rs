@@ -0,0 +1,121 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+pub struct Email(String);
+pub struct GeoPoint(String);
+pub struct Label(String);
+
+uniffi::custom_newtype!(Email, String);
+uniffi::custom_newtype!(GeoPoint, String);
+uniffi::custom_newtype!(Label, String);
+
+#[derive(uniffi::Enum)]
+pub enum Priority {
+    Low,
+    High,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    Point,
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+}
+
+#[derive(uniffi::Record)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct Contact {
+    pub name: String,
+    pub age: i32,
+    pub email: Email,
+    pub tags: Vec<String>,
+    pub address: Option<Address>,
+    pub priority: Priority,
+    pub location: GeoPoint,
+}
+
+// Unsigned integers aren't supported by Parcelize.
+#[derive(uniffi::Record)]
+pub struct Tally {
+    pub count: u32,
+}
+
+// The Kotlin type of `Label` is neither a value class nor marked as parcelable.
+#[derive(uniffi::Record)]
+pub struct Tagged {
+    pub label: Label,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Event {
+    Tick,
+    Counted { tally: Tally },
+}
+
+#[derive(uniffi::Object)]
+pub struct Handle;
+
+#[uniffi::export]
+impl Handle {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct Owned {
+    pub handle: Arc<Handle>,
+}
+
+#[uniffi::export]
+fn make_contact(name: String, email: Email, location: GeoPoint) -> Contact {
+    Contact {
+        name,
+        age: 30,
+        email,
+        tags: vec!["friend".to_owned()],
+        address: Some(Address {
+            street: "1 Main St".to_owned(),
+            city: "Springfield".to_owned(),
+        }),
+        priority: Priority::High,
+        location,
+    }
+}
+
+#[uniffi::export]
+fn area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Point => 0.0,
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Rectangle { width, height } => width * height,
+    }
+}
+
+#[uniffi::export]
+fn tally(count: u32) -> Tally {
+    Tally { count }
+}
+
+#[uniffi::export]
+fn tagged(label: Label) -> Tagged {
+    Tagged { label }
+}
+
+#[uniffi::export]
+fn owned() -> Owned {
+    Owned {
+        handle: Handle::new(),
+    }
+}
+
+uniffi::include_scaffolding!("parcelable");
@@ -0,0 +1,39 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.types.shouldBeInstanceOf
+import io.kotest.matchers.types.shouldNotBeInstanceOf
+import parcelable.*
+import kotlin.test.Test
+
+class ParcelableTest {
+    @Test
+    fun testParcelableRecords() {
+        val contact = makeContact("Alice", Email("alice@example.com"), Coordinates(37.5, 127.0))
+        contact.email shouldBe Email("alice@example.com")
+        contact.location shouldBe Coordinates(37.5, 127.0)
+        contact.address shouldBe Address("1 Main St", "Springfield")
+        contact.shouldBeInstanceOf<UniffiParcelable>()
+        contact.address.shouldBeInstanceOf<UniffiParcelable>()
+    }
+
+    @Test
+    fun testParcelableEnums() {
+        Priority.HIGH.shouldBeInstanceOf<UniffiParcelable>()
+        Shape.Point.shouldBeInstanceOf<UniffiParcelable>()
+        Shape.Circle(1.0).shouldBeInstanceOf<UniffiParcelable>()
+        area(Shape.Rectangle(2.0, 3.0)) shouldBe 6.0
+    }
+
+    @Test
+    fun testUnsupportedTypesAreNotParcelable() {
+        tally(3u).shouldNotBeInstanceOf<UniffiParcelable>()
+        tagged("label").shouldNotBeInstanceOf<UniffiParcelable>()
+        Event.Tick.shouldNotBeInstanceOf<UniffiParcelable>()
+        owned().use { it.shouldNotBeInstanceOf<UniffiParcelable>() }
+    }
+}
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+pub struct Url(String);
+
+uniffi::custom_newtype!(Url, String);
+
+#[derive(uniffi::Record)]
+pub struct Link {
+    pub title: String,
+    pub url: Url,
+}
+
+#[uniffi::export]
+fn host(url: Url) -> Option<String> {
+    let (_, rest) = url.0.split_once("://")?;
+    let host = rest.split(['/', ':', '?', '#']).next()?;
+    (!host.is_empty()).then(|| host.to_owned())
+}
+
+#[uniffi::export]
+fn join(base: Url, path: String) -> Url {
+    Url(format!(
+        "{}/{}",
+        base.0.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ))
+}
+
+#[uniffi::export]
+fn make_link(title: String, url: Url) -> Link {
+    Link { title, url }
+}
+
+uniffi::include_scaffolding!("platform-custom-types");
@@ -0,0 +1,32 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import platform_custom_types.*
+import kotlin.test.Test
+
+/** Creates the platform-specific [Url] from [value]. */
+expect fun url(value: String): Url
+
+class PlatformCustomTypesTest {
+    @Test
+    fun testCustomTypeArguments() {
+        host(url("https://example.com/docs")) shouldBe "example.com"
+        host(url("https://example.com:8080")) shouldBe "example.com"
+        host(url("example.com")) shouldBe null
+    }
+
+    @Test
+    fun testCustomTypeReturnValues() {
+        join(url("https://example.com/"), "/docs") shouldBe url("https://example.com/docs")
+    }
+
+    @Test
+    fun testCustomTypeFields() {
+        val link = makeLink("Docs", url("https://example.com/docs"))
+        link shouldBe Link("Docs", url("https://example.com/docs"))
+    }
+}
@@ -0,0 +1,19 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import platform_custom_types.*
+import kotlin.test.Test
+
+actual fun url(value: String): Url = value
+
+class PlatformCustomTypesNativeTest {
+    @Test
+    fun testNativeType() {
+        val joined: String = join("https://example.com", "docs")
+        joined shouldBe "https://example.com/docs"
+    }
+}
@@ -0,0 +1,95 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, uniffi::Record)]
+pub struct UserRecord {
+    pub user_id: u64,
+    pub display_name: String,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(uniffi::Enum)]
+pub enum Mode {
+    Fast,
+    Careful,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum FetchError {
+    #[error("user {user_id} not found")]
+    NotFound { user_id: u64 },
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Listener: Send + Sync {
+    fn on_user(&self, user: UserRecord);
+}
+
+#[derive(uniffi::Object)]
+pub struct Context {
+    users: Mutex<HashMap<u64, UserRecord>>,
+}
+
+#[uniffi::export]
+impl Context {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            users: Mutex::new(HashMap::new()),
+        })
+    }
+
+    #[uniffi::constructor]
+    fn with_users(users: Vec<UserRecord>) -> Arc<Self> {
+        let users = users.into_iter().map(|user| (user.user_id, user)).collect();
+        Arc::new(Self {
+            users: Mutex::new(users),
+        })
+    }
+
+    fn insert_record(&self, user: UserRecord) {
+        self.users.lock().unwrap().insert(user.user_id, user);
+    }
+
+    fn lookup_record(&self, user_id: u64, mode: Mode) -> Result<UserRecord, FetchError> {
+        let users = self.users.lock().unwrap();
+        let user = match mode {
+            Mode::Fast => users.get(&user_id).cloned(),
+            Mode::Careful => users.values().find(|user| user.user_id == user_id).cloned(),
+        };
+        user.ok_or(FetchError::NotFound { user_id })
+    }
+
+    fn visit_all(&self, listener: Box<dyn Listener>) {
+        let mut users = self
+            .users
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        users.sort_by_key(|user| user.user_id);
+        for user in users {
+            listener.on_user(user);
+        }
+    }
+}
+
+#[uniffi::export]
+fn shape_area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Square { side } => side * side,
+    }
+}
+
+uniffi::include_scaffolding!("rename");
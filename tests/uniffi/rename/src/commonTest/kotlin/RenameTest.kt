@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.doubles.plusOrMinus
+import io.kotest.matchers.shouldBe
+import rename.*
+import kotlin.math.PI
+import kotlin.test.Test
+
+class RenameTest {
+    @Test
+    fun testRenamedObject() {
+        val alice = Profile(id = 1uL, displayName = "Alice")
+        val bob = Profile(id = 2uL, displayName = "Bob")
+        UserDirectory.of(listOf(alice)).use { directory ->
+            directory.add(bob)
+            directory.find(1uL, Mode.FAST) shouldBe alice
+            directory.find(2uL, Mode.Thorough) shouldBe bob
+        }
+    }
+
+    @Test
+    fun testRenamedError() {
+        UserDirectory().use { directory ->
+            val exception = shouldThrow<LookupException.Missing> {
+                directory.find(42uL, Mode.FAST)
+            }
+            exception.userId shouldBe 42uL
+        }
+    }
+
+    @Test
+    fun testRenamedCallbackInterface() {
+        val names = mutableListOf<String>()
+        val observer = object : ProfileObserver {
+            override fun onProfile(user: Profile) {
+                names += user.displayName
+            }
+        }
+        UserDirectory.of(listOf(Profile(2uL, "Bob"), Profile(1uL, "Alice"))).use { directory ->
+            directory.visitAll(observer)
+        }
+        names shouldBe listOf("Alice", "Bob")
+    }
+
+    @Test
+    fun testRenamedVariants() {
+        areaOf(Shape.Round(r = 1.0)) shouldBe (PI plusOrMinus 1e-9)
+        areaOf(Shape.Square(side = 2.0)) shouldBe 4.0
+    }
+}
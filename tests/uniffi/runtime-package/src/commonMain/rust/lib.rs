@@ -0,0 +1,86 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record)]
+pub struct Entry {
+    pub key: String,
+    pub value: i32,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum RegistryError {
+    #[error("key {0} already exists")]
+    Duplicate(String),
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Listener: Send + Sync {
+    fn on_added(&self, entry: Entry);
+}
+
+#[derive(uniffi::Object)]
+pub struct Registry {
+    entries: Mutex<HashMap<String, Entry>>,
+    listeners: Mutex<Vec<Box<dyn Listener>>>,
+}
+
+#[uniffi::export]
+impl Registry {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            entries: Mutex::new(HashMap::new()),
+            listeners: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn add_listener(&self, listener: Box<dyn Listener>) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    fn add(&self, entry: Entry) -> Result<(), RegistryError> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.contains_key(&entry.key) {
+            return Err(RegistryError::Duplicate(entry.key));
+        }
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener.on_added(Entry {
+                key: entry.key.clone(),
+                value: entry.value,
+                tags: entry.tags.clone(),
+            });
+        }
+        entries.insert(entry.key.clone(), entry);
+        Ok(())
+    }
+
+    fn value(&self, key: String) -> Option<i32> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(&key)
+            .map(|entry| entry.value)
+    }
+
+    fn total(&self) -> i64 {
+        let entries = self.entries.lock().unwrap();
+        entries.values().map(|entry| i64::from(entry.value)).sum()
+    }
+}
+
+#[uniffi::export]
+fn registry_with(entries: Vec<Entry>) -> Result<Arc<Registry>, RegistryError> {
+    let registry = Registry::new();
+    for entry in entries {
+        registry.add(entry)?;
+    }
+    Ok(registry)
+}
+
+uniffi::include_scaffolding!("runtime-package");
@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.types.shouldBeInstanceOf
+import runtime_package.*
+import runtime_package.uniffi.runtime.Disposable
+import kotlin.test.Test
+
+class RuntimePackageTest {
+    @Test
+    fun testObjectsUseTheSharedHelpers() {
+        val registry = Registry()
+        registry.shouldBeInstanceOf<Disposable>()
+        registry.use {
+            it.add(Entry("a", 1, listOf("x")))
+            it.add(Entry("b", 2, listOf()))
+            it.value("a") shouldBe 1
+            it.value("c") shouldBe null
+            it.total() shouldBe 3L
+        }
+    }
+
+    @Test
+    fun testCallbackInterfaces() {
+        val added = mutableListOf<Entry>()
+        registryWith(listOf()).use { registry ->
+            registry.addListener(object : Listener {
+                override fun onAdded(entry: Entry) {
+                    added += entry
+                }
+            })
+            registry.add(Entry("a", 1, listOf("x", "y")))
+            registry.add(Entry("b", 2, listOf()))
+        }
+        added shouldBe listOf(Entry("a", 1, listOf("x", "y")), Entry("b", 2, listOf()))
+    }
+
+    @Test
+    fun testErrors() {
+        val exception = shouldThrow<RegistryException.Duplicate> {
+            registryWith(listOf(Entry("a", 1, listOf()), Entry("a", 2, listOf())))
+        }
+        exception.message shouldBe "key a already exists"
+    }
+}
@@ -0,0 +1,103 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::backtrace::Backtrace;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Once, PoisonError};
+
+static PANIC_BACKTRACE: Mutex<Option<String>> = Mutex::new(None);
+static PANIC_IN_TAKE_PANIC_BACKTRACE: AtomicBool = AtomicBool::new(false);
+
+#[uniffi::export]
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // Capture regardless of `RUST_BACKTRACE`, so the tests don't depend on the environment.
+            let backtrace = Backtrace::force_capture().to_string();
+            *PANIC_BACKTRACE
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(backtrace);
+            default_hook(info);
+        }));
+    });
+}
+
+// Set as `panic_backtrace_function` in `uniffi.toml`.
+#[uniffi::export]
+fn take_panic_backtrace() -> Option<String> {
+    if PANIC_IN_TAKE_PANIC_BACKTRACE.load(Ordering::SeqCst) {
+        panic!("cannot take the panic backtrace");
+    }
+    PANIC_BACKTRACE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+}
+
+// Makes `take_panic_backtrace` panic, to check that the panic doesn't look up its own backtrace.
+#[uniffi::export]
+fn set_panic_in_take_panic_backtrace(enabled: bool) {
+    PANIC_IN_TAKE_PANIC_BACKTRACE.store(enabled, Ordering::SeqCst);
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum MathError {
+    #[error("{value} is negative")]
+    Negative { value: i32 },
+}
+
+#[uniffi::export]
+fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("cannot divide {a} by zero");
+    }
+    a / b
+}
+
+#[uniffi::export]
+fn checked_sqrt(value: i32) -> Result<i32, MathError> {
+    if value < 0 {
+        return Err(MathError::Negative { value });
+    }
+    if value > 1_000_000 {
+        panic!("{value} is too large");
+    }
+    Ok((value as f64).sqrt() as i32)
+}
+
+#[uniffi::export]
+async fn divide_async(a: i32, b: i32) -> i32 {
+    divide(a, b)
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: AtomicU32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            value: AtomicU32::new(0),
+        })
+    }
+
+    fn decrement(&self) -> u32 {
+        let value = self.value.load(Ordering::SeqCst);
+        let value = value.checked_sub(1).expect("counter underflow");
+        self.value.store(value, Ordering::SeqCst);
+        value
+    }
+
+    fn increment(&self) -> u32 {
+        self.value.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+uniffi::include_scaffolding!("rust-panics");
@@ -0,0 +1,90 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.nulls.shouldNotBeNull
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldNotBeBlank
+import io.kotest.matchers.types.shouldBeInstanceOf
+import kotlinx.coroutines.test.runTest
+import rust_panics.*
+import kotlin.test.Test
+
+class RustPanicsTest {
+    init {
+        installPanicHook()
+    }
+
+    @Test
+    fun testPanicMessage() {
+        divide(6, 3) shouldBe 2
+        val exception = shouldThrow<RustPanicException> {
+            divide(1, 0)
+        }
+        exception.message shouldBe "cannot divide 1 by zero"
+        exception.shouldBeInstanceOf<InternalException>()
+    }
+
+    @Test
+    fun testPanicBacktrace() {
+        val exception = shouldThrow<RustPanicException> {
+            divide(1, 0)
+        }
+        exception.rustBacktrace.shouldNotBeNull().shouldNotBeBlank()
+        // The backtrace is taken by the exception, so the next panic gets its own.
+        takePanicBacktrace() shouldBe null
+    }
+
+    @Test
+    fun testPanicInPanicBacktraceFunction() {
+        setPanicInTakePanicBacktrace(true)
+        try {
+            val exception = shouldThrow<RustPanicException> {
+                divide(1, 0)
+            }
+            exception.message shouldBe "cannot divide 1 by zero"
+            exception.rustBacktrace shouldBe null
+        } finally {
+            setPanicInTakePanicBacktrace(false)
+            // Drop the backtrace of the panic in `takePanicBacktrace`.
+            takePanicBacktrace()
+        }
+    }
+
+    @Test
+    fun testPanicsAreNotErrors() {
+        checkedSqrt(16) shouldBe 4
+        shouldThrow<MathException.Negative> {
+            checkedSqrt(-1)
+        }
+        shouldThrow<RustPanicException> {
+            checkedSqrt(2_000_000)
+        }.message shouldBe "2000000 is too large"
+    }
+
+    @Test
+    fun testPanicInMethod() {
+        Counter().use { counter ->
+            shouldThrow<RustPanicException> {
+                counter.decrement()
+            }.message shouldBe "counter underflow"
+            // The object is still usable after the panic.
+            counter.increment() shouldBe 1u
+            counter.decrement() shouldBe 0u
+        }
+    }
+
+    @Test
+    fun testPanicInAsyncFunction() = runTest {
+        divideAsync(6, 2) shouldBe 3
+        shouldThrow<RustPanicException> {
+            divideAsync(2, 0)
+        }.apply {
+            message shouldBe "cannot divide 2 by zero"
+            rustBacktrace.shouldNotBeNull()
+        }
+    }
+}
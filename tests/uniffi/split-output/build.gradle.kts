@@ -0,0 +1,38 @@
+import gobley.gradle.uniffi.tasks.BuildUniffiBindingsTask
+
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+val buildUniffiBindings = tasks.named<BuildUniffiBindingsTask>("buildUniffiBindings")
+val bindingsDirectory = buildUniffiBindings.flatMap { it.outputDirectory }
+
+// The tests only exercise the bindings at runtime, so check the layout of the generated files here.
+val checkSplitOutputLayout by tasks.registering {
+    dependsOn(buildUniffiBindings)
+    doLast {
+        for (target in listOf("common", "jvm")) {
+            val packageDirectory = bindingsDirectory.get()
+                .dir("${target}Main/kotlin/split_output").asFile
+            val mainFile = packageDirectory.resolve("split_output.$target.kt")
+            val splitDirectory = packageDirectory.resolve("split_output")
+            check(mainFile.isFile) { "$mainFile was not generated" }
+            for (name in listOf("Item", "Category", "Change", "Inventory", "UniffiHelpers")) {
+                val file = splitDirectory.resolve("$name.$target.kt")
+                check(file.isFile) { "$file was not generated" }
+            }
+            val main = mainFile.readText()
+            check("class Item" !in main) { "Item was not moved out of $mainFile" }
+            if (target != "common") {
+                val helpers = splitDirectory.resolve("UniffiHelpers.$target.kt").readText()
+                check("object FfiConverterString" in helpers && "object FfiConverterString" !in main) {
+                    "the shared helpers were not moved into UniffiHelpers.$target.kt"
+                }
+            }
+        }
+    }
+}
+
+tasks.withType<Test>().configureEach {
+    dependsOn(checkSplitOutputLayout)
+}
@@ -0,0 +1,69 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Record)]
+pub struct Item {
+    pub name: String,
+    pub quantity: u32,
+    pub category: Category,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Category {
+    Food,
+    Tool,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Change {
+    Added { item: Item },
+    Removed { name: String },
+}
+
+#[derive(uniffi::Object)]
+pub struct Inventory {
+    items: Mutex<Vec<Item>>,
+}
+
+#[uniffi::export]
+impl Inventory {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            items: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn apply(&self, change: Change) {
+        let mut items = self.items.lock().unwrap();
+        match change {
+            Change::Added { item } => items.push(item),
+            Change::Removed { name } => items.retain(|item| item.name != name),
+        }
+    }
+
+    fn count(&self, category: Category) -> u32 {
+        let items = self.items.lock().unwrap();
+        items
+            .iter()
+            .filter(|item| {
+                std::mem::discriminant(&item.category) == std::mem::discriminant(&category)
+            })
+            .map(|item| item.quantity)
+            .sum()
+    }
+}
+
+#[uniffi::export]
+fn describe(item: Item) -> String {
+    let category = match item.category {
+        Category::Food => "food",
+        Category::Tool => "tool",
+    };
+    format!("{} x{} ({category})", item.name, item.quantity)
+}
+
+uniffi::include_scaffolding!("split-output");
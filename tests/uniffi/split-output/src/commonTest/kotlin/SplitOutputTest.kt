@@ -0,0 +1,30 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import split_output.*
+import kotlin.test.Test
+
+class SplitOutputTest {
+    @Test
+    fun testTypesFromSeparateFiles() {
+        describe(Item("apple", 3U, Category.FOOD)) shouldBe "apple x3 (food)"
+        describe(Item("hammer", 1U, Category.TOOL)) shouldBe "hammer x1 (tool)"
+    }
+
+    @Test
+    fun testObjects() {
+        Inventory().use { inventory ->
+            inventory.apply(Change.Added(Item("apple", 3U, Category.FOOD)))
+            inventory.apply(Change.Added(Item("pear", 2U, Category.FOOD)))
+            inventory.apply(Change.Added(Item("hammer", 1U, Category.TOOL)))
+            inventory.count(Category.FOOD) shouldBe 5U
+            inventory.apply(Change.Removed("apple"))
+            inventory.count(Category.FOOD) shouldBe 2U
+            inventory.count(Category.TOOL) shouldBe 1U
+        }
+    }
+}
@@ -0,0 +1,66 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum ParseError {
+    #[error("not a number: {input}")]
+    NotANumber { input: String },
+}
+
+#[derive(uniffi::Object)]
+pub struct Database {
+    rows: Mutex<Vec<String>>,
+}
+
+#[uniffi::export]
+impl Database {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            rows: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn insert(&self, row: String) {
+        self.rows.lock().unwrap().push(row);
+    }
+
+    fn query(&self, prefix: String) -> Vec<String> {
+        let rows = self.rows.lock().unwrap();
+        rows.iter()
+            .filter(|row| row.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    async fn count(&self) -> u32 {
+        self.rows.lock().unwrap().len() as u32
+    }
+}
+
+/// FNV-1a, standing in for a slow hash function.
+#[uniffi::export]
+fn hash_bytes(data: Vec<u8>) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[uniffi::export]
+fn parse_number(input: String) -> Result<i64, ParseError> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::NotANumber { input })
+}
+
+/// Identifies the thread calling into Rust.
+#[uniffi::export]
+fn current_thread() -> String {
+    format!("{:?}", std::thread::current().id())
+}
+
+uniffi::include_scaffolding!("suspend-wrappers");
@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.shouldNotBe
+import kotlinx.coroutines.test.StandardTestDispatcher
+import kotlinx.coroutines.test.runTest
+import suspend_wrappers.*
+import kotlin.test.Test
+
+class SuspendWrappersTest {
+    @Test
+    fun testTopLevelFunctions() = runTest {
+        val data = "abc".encodeToByteArray()
+        checksumAsync(data) shouldBe checksum(data)
+        checksumAsync(data) shouldBe 16654208175385433931uL
+        parseNumberAsync(" 42 ") shouldBe 42L
+    }
+
+    @Test
+    fun testErrors() = runTest {
+        val exception = shouldThrow<ParseException.NotANumber> {
+            parseNumberAsync("forty-two")
+        }
+        exception.input shouldBe "forty-two"
+    }
+
+    @Test
+    fun testMethods() = runTest {
+        Database().use { database ->
+            database.insertAsync("apple")
+            database.insertAsync("avocado")
+            database.insertAsync("banana")
+            database.queryAsync("a") shouldBe listOf("apple", "avocado")
+            // Async methods are called as is.
+            database.count() shouldBe 3u
+        }
+    }
+
+    @Test
+    fun testBlockingDispatcher() = runTest {
+        // The wrappers leave the test thread for the blocking dispatcher.
+        currentThreadAsync() shouldNotBe currentThread()
+
+        UniffiAsyncDispatcher.blockingContext = StandardTestDispatcher(testScheduler)
+        try {
+            currentThreadAsync() shouldBe currentThread()
+        } finally {
+            UniffiAsyncDispatcher.blockingContext = null
+        }
+    }
+}
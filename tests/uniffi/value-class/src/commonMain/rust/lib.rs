@@ -0,0 +1,46 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+pub struct UserId(String);
+pub struct OrderId(String);
+pub struct Quantity(u32);
+
+uniffi::custom_newtype!(UserId, String);
+uniffi::custom_newtype!(OrderId, String);
+uniffi::custom_newtype!(Quantity, u32);
+
+#[derive(uniffi::Record)]
+pub struct Order {
+    pub id: OrderId,
+    pub user: UserId,
+    pub quantity: Quantity,
+}
+
+#[uniffi::export]
+fn place_order(user: UserId, quantity: Quantity) -> Order {
+    Order {
+        id: OrderId(format!("{}-{}", user.0, quantity.0)),
+        user,
+        quantity,
+    }
+}
+
+#[uniffi::export]
+fn total_quantity(orders: HashMap<String, Quantity>) -> Quantity {
+    Quantity(orders.values().map(|quantity| quantity.0).sum())
+}
+
+#[uniffi::export]
+fn order_ids(orders: Vec<Order>) -> Vec<OrderId> {
+    orders.into_iter().map(|order| order.id).collect()
+}
+
+#[uniffi::export]
+fn user_or_guest(user: Option<UserId>) -> UserId {
+    user.unwrap_or_else(|| UserId("guest".to_owned()))
+}
+
+uniffi::include_scaffolding!("value-class");
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import value_class.*
+import kotlin.test.Test
+
+class ValueClassTest {
+    @Test
+    fun testValueClassArgumentsAndFields() {
+        val order = placeOrder(UserId("alice"), Quantity(3u))
+        order shouldBe Order(OrderId("alice-3"), UserId("alice"), Quantity(3u))
+        order.id.value shouldBe "alice-3"
+        order.quantity.value shouldBe 3u
+    }
+
+    @Test
+    fun testValueClassesInCollections() {
+        val orders = listOf(
+            placeOrder(UserId("alice"), Quantity(1u)),
+            placeOrder(UserId("bob"), Quantity(2u)),
+        )
+        orderIds(orders) shouldBe listOf(OrderId("alice-1"), OrderId("bob-2"))
+        totalQuantity(mapOf("a" to Quantity(1u), "b" to Quantity(5u))) shouldBe Quantity(6u)
+    }
+
+    @Test
+    fun testOptionalValueClasses() {
+        userOrGuest(UserId("alice")) shouldBe UserId("alice")
+        userOrGuest(null) shouldBe UserId("guest")
+    }
+}
@@ -0,0 +1,13 @@
+plugins {
+    id("uniffi-tests-from-library")
+}
+
+kotlin {
+    sourceSets {
+        jvmTest {
+            dependencies {
+                implementation(kotlin("reflect"))
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[derive(uniffi::Enum)]
+pub enum Role {
+    Guest,
+    Member,
+    Admin,
+}
+
+#[derive(uniffi::Record)]
+pub struct Token {
+    pub user: String,
+    pub role: Role,
+}
+
+#[derive(uniffi::Object)]
+pub struct Session {
+    token: Token,
+}
+
+#[uniffi::export]
+impl Session {
+    fn user(&self) -> String {
+        self.token.user.clone()
+    }
+
+    fn is_admin(&self) -> bool {
+        matches!(self.token.role, Role::Admin)
+    }
+}
+
+#[uniffi::export]
+fn open_session(user: String) -> Arc<Session> {
+    let token = issue_token(user);
+    Arc::new(Session { token })
+}
+
+#[uniffi::export]
+fn issue_token(user: String) -> Token {
+    let role = match user.as_str() {
+        "root" => Role::Admin,
+        "" => Role::Guest,
+        _ => Role::Member,
+    };
+    Token { user, role }
+}
+
+uniffi::include_scaffolding!("visibility");
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import visibility.*
+import kotlin.test.Test
+
+class VisibilityTest {
+    @Test
+    fun testPublicDeclarations() {
+        openSession("root").use { session ->
+            session.user() shouldBe "root"
+            session.isAdmin() shouldBe true
+        }
+        openSession("alice").use { session ->
+            session.isAdmin() shouldBe false
+        }
+    }
+
+    @Test
+    fun testInternalDeclarations() {
+        // Internal declarations are still visible inside the module.
+        issueToken("alice") shouldBe Token("alice", Role.MEMBER)
+        issueToken("") shouldBe Token("", Role.GUEST)
+    }
+
+    @Test
+    fun testFacade() {
+        roleOf("root") shouldBe "admin"
+        roleOf("alice") shouldBe "member"
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.matchers.shouldBe
+import visibility.*
+import kotlin.reflect.KVisibility
+import kotlin.test.Test
+
+class VisibilityReflectionTest {
+    @Test
+    fun testTypeVisibility() {
+        Session::class.visibility shouldBe KVisibility.PUBLIC
+        SessionInterface::class.visibility shouldBe KVisibility.PUBLIC
+        Token::class.visibility shouldBe KVisibility.INTERNAL
+        Role::class.visibility shouldBe KVisibility.INTERNAL
+    }
+
+    @Test
+    fun testFunctionVisibility() {
+        ::openSession.visibility shouldBe KVisibility.PUBLIC
+        ::issueToken.visibility shouldBe KVisibility.INTERNAL
+        Session::user.visibility shouldBe KVisibility.PUBLIC
+    }
+
+    @Test
+    fun testHelperVisibility() {
+        // The helpers stay public since the public Session depends on them.
+        Disposable::class.visibility shouldBe KVisibility.PUBLIC
+    }
+}
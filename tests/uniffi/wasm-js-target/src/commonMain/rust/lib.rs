@@ -0,0 +1,114 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+#[derive(uniffi::Record)]
+pub struct Document {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub size: u64,
+    pub checksum: Option<u32>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Level {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum StoreError {
+    #[error("no document named {title}")]
+    NotFound { title: String },
+}
+
+#[uniffi::export]
+fn checksum(bytes: Vec<u8>) -> u32 {
+    bytes.iter().fold(0u32, |sum, byte| {
+        sum.wrapping_mul(31).wrapping_add(*byte as u32)
+    })
+}
+
+#[uniffi::export]
+fn repeat_bytes(byte: u8, count: u32) -> Vec<u8> {
+    vec![byte; count as usize]
+}
+
+#[uniffi::export]
+fn count_words(text: String) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_owned()).or_default() += 1;
+    }
+    counts
+}
+
+#[uniffi::export]
+fn multiply(a: i64, b: i64) -> i64 {
+    a.wrapping_mul(b)
+}
+
+#[uniffi::export]
+fn half(value: f32) -> f32 {
+    value / 2.0
+}
+
+#[uniffi::export]
+fn next_level(level: Level) -> Level {
+    match level {
+        Level::Low => Level::Medium,
+        Level::Medium | Level::High => Level::High,
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct DocumentStore {
+    documents: RwLock<Vec<Document>>,
+}
+
+#[uniffi::export]
+impl DocumentStore {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            documents: RwLock::new(Vec::new()),
+        })
+    }
+
+    fn add(&self, title: String, tags: Vec<String>, contents: Vec<u8>) {
+        self.documents.write().unwrap().push(Document {
+            title,
+            tags,
+            size: contents.len() as u64,
+            checksum: (!contents.is_empty()).then(|| checksum(contents)),
+        });
+    }
+
+    fn get(&self, title: String) -> Result<Document, StoreError> {
+        let documents = self.documents.read().unwrap();
+        let document = documents
+            .iter()
+            .find(|document| document.title == title)
+            .ok_or(StoreError::NotFound { title })?;
+        Ok(Document {
+            title: document.title.clone(),
+            tags: document.tags.clone(),
+            size: document.size,
+            checksum: document.checksum,
+        })
+    }
+
+    fn titles(&self) -> Vec<String> {
+        let documents = self.documents.read().unwrap();
+        documents
+            .iter()
+            .map(|document| document.title.clone())
+            .collect()
+    }
+}
+
+uniffi::include_scaffolding!("wasm-js-target");
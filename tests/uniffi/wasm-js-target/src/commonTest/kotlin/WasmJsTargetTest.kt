@@ -0,0 +1,51 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.shouldBe
+import wasm_js_target.*
+import kotlin.test.Test
+
+class WasmJsTargetTest {
+    @Test
+    fun testByteArrays() {
+        checksum(byteArrayOf()) shouldBe 0U
+        checksum(byteArrayOf(1, 2, 3)) shouldBe 1026U
+        val bytes = repeatBytes(0xABU, 100_000U)
+        bytes.size shouldBe 100_000
+        bytes.all { it == 0xAB.toByte() } shouldBe true
+    }
+
+    @Test
+    fun testPrimitives() {
+        multiply(3_000_000_000L, 3L) shouldBe 9_000_000_000L
+        multiply(-4L, 5L) shouldBe -20L
+        half(5.0f) shouldBe 2.5f
+        nextLevel(Level.LOW) shouldBe Level.MEDIUM
+        nextLevel(Level.HIGH) shouldBe Level.HIGH
+    }
+
+    @Test
+    fun testMaps() {
+        countWords("a b a c a") shouldBe mapOf("a" to 3U, "b" to 1U, "c" to 1U)
+        countWords("") shouldBe emptyMap()
+    }
+
+    @Test
+    fun testObjects() {
+        DocumentStore().use { store ->
+            store.add("notes", listOf("work", "draft"), byteArrayOf(1, 2, 3))
+            store.add("empty", listOf(), byteArrayOf())
+            store.titles() shouldBe listOf("notes", "empty")
+            store.get("notes") shouldBe Document("notes", listOf("work", "draft"), 3UL, 1026U)
+            store.get("empty") shouldBe Document("empty", listOf(), 0UL, null)
+            val exception = shouldThrow<StoreException.NotFound> {
+                store.get("missing")
+            }
+            exception.title shouldBe "missing"
+        }
+    }
+}